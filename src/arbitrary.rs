@@ -0,0 +1,207 @@
+extern crate arbitrary;
+
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+use std::hash::{BuildHasher, Hash};
+
+use self::arbitrary::{size_hint, Arbitrary, Result, Unstructured};
+
+use super::NonEmpty;
+
+/////////////////////////////////////////////////////////////////////////
+// Arbitrary values for fuzzing, never empty
+/////////////////////////////////////////////////////////////////////////
+
+/// Generates the first element directly and the rest like `Vec<T>` does,
+/// so no input is rejected.
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for NonEmpty<Vec<T>> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let head = T::arbitrary(u)?;
+        let tail = Vec::<T>::arbitrary(u)?;
+        Ok(NonEmpty::from_head_tail(head, tail))
+    }
+
+    fn arbitrary_take_rest(mut u: Unstructured<'a>) -> Result<Self> {
+        let head = T::arbitrary(&mut u)?;
+        let tail = Vec::<T>::arbitrary_take_rest(u)?;
+        Ok(NonEmpty::from_head_tail(head, tail))
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        size_hint::and(T::size_hint(depth), Vec::<T>::size_hint(depth))
+    }
+}
+
+macro_rules! from_vec_impls {
+    ($($t:ident $(: $bound:ident)*),*) => ($(
+        /// Generated like `NonEmpty<Vec<T>>`.
+        impl<'a, T: Arbitrary<'a> $(+ $bound)*> Arbitrary<'a> for NonEmpty<$t<T>> {
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                NonEmpty::<Vec<T>>::arbitrary(u).map(|v| NonEmpty { inner: v.inner.into_iter().collect() })
+            }
+
+            fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+                NonEmpty::<Vec<T>>::arbitrary_take_rest(u).map(|v| NonEmpty { inner: v.inner.into_iter().collect() })
+            }
+
+            #[inline]
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                NonEmpty::<Vec<T>>::size_hint(depth)
+            }
+        }
+    )*)
+}
+
+from_vec_impls! { VecDeque, LinkedList, BinaryHeap: Ord, BTreeSet: Ord }
+
+impl<'a, T, S> Arbitrary<'a> for NonEmpty<HashSet<T, S>>
+    where T: Arbitrary<'a> + Eq + Hash,
+          S: BuildHasher + Default,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        NonEmpty::<Vec<T>>::arbitrary(u).map(|v| NonEmpty { inner: v.inner.into_iter().collect() })
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        NonEmpty::<Vec<T>>::arbitrary_take_rest(u).map(|v| NonEmpty { inner: v.inner.into_iter().collect() })
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        NonEmpty::<Vec<T>>::size_hint(depth)
+    }
+}
+
+impl<'a, K, V> Arbitrary<'a> for NonEmpty<BTreeMap<K, V>>
+    where K: Arbitrary<'a> + Ord,
+          V: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        NonEmpty::<Vec<(K, V)>>::arbitrary(u).map(NonEmpty::from)
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        NonEmpty::<Vec<(K, V)>>::arbitrary_take_rest(u).map(NonEmpty::from)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        NonEmpty::<Vec<(K, V)>>::size_hint(depth)
+    }
+}
+
+impl<'a, K, V, S> Arbitrary<'a> for NonEmpty<HashMap<K, V, S>>
+    where K: Arbitrary<'a> + Eq + Hash,
+          V: Arbitrary<'a>,
+          S: BuildHasher + Default,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        NonEmpty::<Vec<(K, V)>>::arbitrary(u).map(NonEmpty::from)
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        NonEmpty::<Vec<(K, V)>>::arbitrary_take_rest(u).map(NonEmpty::from)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        NonEmpty::<Vec<(K, V)>>::size_hint(depth)
+    }
+}
+
+/// Generates the first `char` directly and the rest like `String` does,
+/// so no input is rejected.
+impl<'a> Arbitrary<'a> for NonEmpty<String> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut inner = char::arbitrary(u)?.to_string();
+        inner.push_str(<&str>::arbitrary(u)?);
+        Ok(NonEmpty { inner })
+    }
+
+    fn arbitrary_take_rest(mut u: Unstructured<'a>) -> Result<Self> {
+        let mut inner = char::arbitrary(&mut u)?.to_string();
+        inner.push_str(<&str>::arbitrary_take_rest(u)?);
+        Ok(NonEmpty { inner })
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        size_hint::and(char::size_hint(depth), String::size_hint(depth))
+    }
+}
+
+macro_rules! num_impls {
+    ($($t:ident)*) => ($(
+        /// A generated zero is replaced by `MAX`, like `arbitrary` does for `NonZero` integers.
+        impl<'a> Arbitrary<'a> for NonEmpty<$t> {
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                let inner = $t::arbitrary(u)?;
+                if inner == 0 as $t {
+                    return Ok(NonEmpty { inner: $t::MAX })
+                }
+                Ok(NonEmpty { inner })
+            }
+
+            #[inline]
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                $t::size_hint(depth)
+            }
+        }
+    )*)
+}
+
+num_impls! { i8 i16 i32 i64 isize u8 u16 u32 u64 usize f32 f64 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashSet};
+
+    use super::arbitrary::{Arbitrary, Unstructured};
+    use super::super::{IsEmpty, NonEmpty, StringNE, VecNE, f64NE, u8NE};
+
+    /// Deterministic pseudo-random entropy.
+    fn entropy(len: usize) -> Vec<u8> {
+        let mut x: u32 = 0x2545_f491;
+        (0..len).map(|_| {
+            x ^= x << 13;
+            x ^= x >> 17;
+            x ^= x << 5;
+            x as u8
+        }).collect()
+    }
+
+    fn generate<T>(n: usize) -> Vec<NonEmpty<T>>
+        where for<'a> NonEmpty<T>: Arbitrary<'a>,
+    {
+        let data = entropy(4096);
+        let mut u = Unstructured::new(&data);
+        // keep going after the entropy ran out, the values must stay non-empty
+        (0..n).map(|_| NonEmpty::<T>::arbitrary(&mut u).unwrap()).collect()
+    }
+
+    #[test]
+    fn never_empty() {
+        assert!(generate::<Vec<u8>>(300).iter().all(|v| !v.is_empty()));
+        assert!(generate::<String>(300).iter().all(|v| !v.is_empty()));
+        assert!(generate::<HashSet<u16>>(300).iter().all(|v| !v.is_empty()));
+        assert!(generate::<BTreeMap<u8, bool>>(300).iter().all(|v| !v.is_empty()));
+        assert!(generate::<u8>(300).iter().all(|v| !v.is_empty()));
+        assert!(generate::<f64>(300).iter().all(|v| !v.is_empty()));
+    }
+
+    #[test]
+    fn take_rest() {
+        let v = VecNE::<u8>::arbitrary_take_rest(Unstructured::new(&[])).unwrap();
+        assert_eq!(1, v.len());
+        let s = StringNE::arbitrary_take_rest(Unstructured::new(&[])).unwrap();
+        assert_eq!(1, s.chars().count());
+    }
+
+    #[test]
+    fn size_hint() {
+        assert!(VecNE::<u8>::size_hint(0).0 > 0);
+        assert!(StringNE::size_hint(0).0 > 0);
+        assert_eq!((1, Some(1)), u8NE::size_hint(0));
+        assert_eq!((8, Some(8)), f64NE::size_hint(0));
+    }
+}
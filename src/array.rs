@@ -0,0 +1,88 @@
+use super::{IsEmpty, NonEmpty};
+
+impl<T, const N: usize> IsEmpty for [T; N] {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        N == 0
+    }
+}
+
+impl<T, const N: usize> From<NonEmpty<[T; N]>> for [T; N] {
+    #[inline]
+    fn from(v: NonEmpty<[T; N]>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl<T, const N: usize> NonEmpty<[T; N]> {
+    const NOT_EMPTY: () = assert!(N >= 1, "NonEmpty array must have at least one element");
+
+    /// Wraps an array with at least one element, checked at compile time.
+    ///
+    /// # Examples
+    /// ```
+    /// use non_empty::NonEmpty;
+    ///
+    /// let a = NonEmpty::from_array([1, 2, 3]);
+    /// assert_eq!(1, *a.first());
+    /// ```
+    ///
+    /// An empty array doesn't compile:
+    /// ```compile_fail
+    /// use non_empty::NonEmpty;
+    ///
+    /// let a = NonEmpty::from_array([0u8; 0]);
+    /// ```
+    #[inline]
+    pub fn from_array(arr: [T; N]) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::NOT_EMPTY;
+        NonEmpty { inner: arr }
+    }
+
+    /// Returns the first element.
+    #[inline]
+    pub fn first(&self) -> &T {
+        &self.inner[0]
+    }
+
+    /// Returns the last element.
+    #[inline]
+    pub fn last(&self) -> &T {
+        &self.inner[N - 1]
+    }
+
+    /// Maps each element, like `array::map`.
+    #[inline]
+    pub fn map<U, F>(self, f: F) -> NonEmpty<[U; N]>
+        where F: FnMut(T) -> U,
+    {
+        NonEmpty { inner: self.inner.map(f) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{NonEmpty, TryNonEmpty};
+
+    #[test]
+    fn from_array() {
+        let a = NonEmpty::from_array([7]);
+        assert_eq!(7, *a.first());
+        assert_eq!(7, *a.last());
+        let a = a.map(|v| v.to_string());
+        assert_eq!("7", a.first());
+
+        let a = NonEmpty::from_array(["a", "bb", "ccc"]);
+        assert_eq!("a", *a.first());
+        assert_eq!("ccc", *a.last());
+        let a: [usize; 3] = a.map(str::len).into();
+        assert_eq!([1, 2, 3], a);
+    }
+
+    #[test]
+    fn empty_rejection() {
+        assert!([0u8; 0].try_non_empty().is_none());
+        assert!([0u8; 1].try_non_empty().is_some());
+    }
+}
@@ -0,0 +1,131 @@
+extern crate arrayvec;
+
+use self::arrayvec::{ArrayString, ArrayVec, CapacityError};
+
+use super::{IsEmpty, NonEmpty, WouldBeEmpty};
+
+/// Non-empty `ArrayVec`.
+pub type ArrayVecNE<T, const N: usize> = NonEmpty<ArrayVec<T, N>>;
+
+/// Non-empty `ArrayString`.
+pub type ArrayStringNE<const N: usize> = NonEmpty<ArrayString<N>>;
+
+impl<T, const N: usize> IsEmpty for ArrayVec<T, N> {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        ArrayVec::is_empty(self)
+    }
+}
+
+impl<const N: usize> IsEmpty for ArrayString<N> {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        ArrayString::is_empty(self)
+    }
+}
+
+impl<T, const N: usize> From<NonEmpty<ArrayVec<T, N>>> for ArrayVec<T, N> {
+    #[inline]
+    fn from(v: NonEmpty<ArrayVec<T, N>>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl<const N: usize> From<NonEmpty<ArrayString<N>>> for ArrayString<N> {
+    #[inline]
+    fn from(v: NonEmpty<ArrayString<N>>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl<T, const N: usize> NonEmpty<ArrayVec<T, N>> {
+    /// Appends an element, like `ArrayVec::try_push`.
+    ///
+    /// Returns an error with the element if the vector is full.
+    #[inline]
+    pub fn try_push(&mut self, element: T) -> Result<(), CapacityError<T>> {
+        self.inner.try_push(element)
+    }
+
+    /// Removes and returns the last element.
+    ///
+    /// Returns `Err(WouldBeEmpty)` and leaves the vector untouched
+    /// if it's the only element.
+    pub fn pop(&mut self) -> Result<T, WouldBeEmpty> {
+        if self.inner.len() == 1 {
+            return Err(WouldBeEmpty)
+        }
+        Ok(self.inner.pop().expect("NonEmpty is never empty"))
+    }
+}
+
+impl<const N: usize> NonEmpty<ArrayString<N>> {
+    /// Appends a char, like `ArrayString::try_push`.
+    ///
+    /// Returns an error with the char if the string is full.
+    #[inline]
+    pub fn try_push(&mut self, c: char) -> Result<(), CapacityError<char>> {
+        self.inner.try_push(c)
+    }
+
+    /// Appends a string slice, like `ArrayString::try_push_str`.
+    ///
+    /// Returns an error with the slice if it doesn't fit.
+    #[inline]
+    pub fn try_push_str<'a>(&mut self, s: &'a str) -> Result<(), CapacityError<&'a str>> {
+        self.inner.try_push_str(s)
+    }
+
+    /// Removes and returns the last char.
+    ///
+    /// Returns `Err(WouldBeEmpty)` and leaves the string untouched
+    /// if it's the only char.
+    pub fn pop(&mut self) -> Result<char, WouldBeEmpty> {
+        if self.inner.chars().nth(1).is_none() {
+            return Err(WouldBeEmpty)
+        }
+        Ok(self.inner.pop().expect("NonEmpty is never empty"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::arrayvec::{ArrayString, ArrayVec};
+    use super::{ArrayStringNE, ArrayVecNE};
+    use super::super::{TryNonEmpty, WouldBeEmpty};
+
+    #[test]
+    fn empty_rejection() {
+        assert!(ArrayVec::<u8, 4>::new().try_non_empty().is_none());
+        assert!(ArrayString::<4>::new().try_non_empty().is_none());
+        assert!(ArrayString::<4>::from("a").unwrap().try_non_empty().is_some());
+    }
+
+    #[test]
+    fn array_vec() {
+        let mut v: ArrayVecNE<u8, 2> = vec![1].into_iter().collect::<ArrayVec<_, 2>>().try_non_empty().unwrap();
+        assert_eq!(Ok(()), v.try_push(2));
+        assert_eq!(3, v.try_push(3).unwrap_err().element());
+        assert_eq!(2, v.len());
+
+        assert_eq!(Ok(2), v.pop());
+        assert_eq!(Err(WouldBeEmpty), v.pop());
+        assert_eq!(&[1], &v[..]);
+    }
+
+    #[test]
+    fn array_string() {
+        let mut s: ArrayStringNE<4> = ArrayString::from("é").unwrap().try_non_empty().unwrap();
+        assert_eq!(Err(WouldBeEmpty), s.pop());
+        assert_eq!(Ok(()), s.try_push('a'));
+        assert_eq!("bc", s.try_push_str("bc").unwrap_err().element());
+        assert_eq!(Ok(()), s.try_push('x'));
+        assert_eq!('y', s.try_push('y').unwrap_err().element());
+        assert_eq!("éax", &*s);
+
+        assert_eq!(Ok('x'), s.pop());
+        assert_eq!(Ok('a'), s.pop());
+        assert_eq!(Err(WouldBeEmpty), s.pop());
+        assert_eq!("é", &*s);
+    }
+}
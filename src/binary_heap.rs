@@ -0,0 +1,92 @@
+use std::collections::BinaryHeap;
+
+use super::{NonEmpty, WouldBeEmpty};
+
+impl<T: Ord> NonEmpty<BinaryHeap<T>> {
+    /// Returns the greatest element.
+    pub fn peek(&self) -> &T {
+        self.inner.peek().expect("NonEmpty is never empty")
+    }
+
+    /// Pushes an element onto the heap.
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        self.inner.push(value)
+    }
+
+    /// Removes and returns the greatest element.
+    ///
+    /// Returns `Err(WouldBeEmpty)` and leaves the heap untouched
+    /// if it's the only element.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::BinaryHeap;
+    /// use non_empty::{TryNonEmpty, WouldBeEmpty};
+    ///
+    /// let mut heap = BinaryHeap::from(vec![1, 3]).try_non_empty().unwrap();
+    /// assert_eq!(Ok(3), heap.pop());
+    /// assert_eq!(Err(WouldBeEmpty), heap.pop());
+    /// assert_eq!(1, *heap.peek());
+    /// ```
+    pub fn pop(&mut self) -> Result<T, WouldBeEmpty> {
+        if self.inner.len() == 1 {
+            return Err(WouldBeEmpty)
+        }
+        Ok(self.inner.pop().expect("NonEmpty is never empty"))
+    }
+
+    /// Consumes the heap and returns a vector in sorted, ascending order.
+    pub fn into_sorted_vec_ne(self) -> NonEmpty<Vec<T>> {
+        NonEmpty { inner: self.inner.into_sorted_vec() }
+    }
+
+    /// Consumes the heap and returns a vector in arbitrary order.
+    pub fn into_vec_ne(self) -> NonEmpty<Vec<T>> {
+        NonEmpty { inner: self.inner.into_vec() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BinaryHeap;
+
+    use super::super::{BinaryHeapNE, TryNonEmpty, WouldBeEmpty};
+
+    fn heap(v: Vec<i32>) -> BinaryHeapNE<i32> {
+        BinaryHeap::from(v).try_non_empty().unwrap()
+    }
+
+    #[test]
+    fn ordering() {
+        let mut h = heap(vec![2]);
+        assert_eq!(2, *h.peek());
+        h.push(5);
+        h.push(1);
+        h.push(4);
+        assert_eq!(5, *h.peek());
+        assert_eq!(Ok(5), h.pop());
+        assert_eq!(Ok(4), h.pop());
+        assert_eq!(Ok(2), h.pop());
+        assert_eq!(Err(WouldBeEmpty), h.pop());
+        assert_eq!(1, *h.peek());
+    }
+
+    #[test]
+    fn single_element() {
+        let mut h = heap(vec![7]);
+        assert_eq!(Err(WouldBeEmpty), h.pop());
+        assert_eq!(7, *h.peek());
+        assert_eq!(1, h.len());
+    }
+
+    #[test]
+    fn into_vec() {
+        let h = heap(vec![3, 1, 4, 1, 5]);
+        assert_eq!(vec![1, 1, 3, 4, 5], *h.clone().into_sorted_vec_ne());
+
+        let mut v = h.into_vec_ne().into_inner();
+        v.sort();
+        assert_eq!(vec![1, 1, 3, 4, 5], v);
+    }
+}
@@ -0,0 +1,181 @@
+use std::collections::btree_map::{BTreeMap, Keys, Values};
+
+use super::{BTreeMapEntry, NonEmpty, NonEmptyIter, WouldBeEmpty};
+
+impl<K: Ord, V> NonEmpty<BTreeMap<K, V>> {
+    /// Returns the entry with the smallest key.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use non_empty::TryNonEmpty;
+    ///
+    /// let mut m = BTreeMap::new();
+    /// m.insert(2, "b");
+    /// m.insert(1, "a");
+    /// let m = m.try_non_empty().unwrap();
+    ///
+    /// assert_eq!((&1, &"a"), m.first_key_value());
+    /// assert_eq!(&2, m.last_key());
+    /// ```
+    pub fn first_key_value(&self) -> (&K, &V) {
+        self.inner.first_key_value().expect("NonEmpty is never empty")
+    }
+
+    /// Returns the entry with the largest key.
+    pub fn last_key_value(&self) -> (&K, &V) {
+        self.inner.last_key_value().expect("NonEmpty is never empty")
+    }
+
+    /// Returns the smallest key.
+    pub fn first_key(&self) -> &K {
+        self.first_key_value().0
+    }
+
+    /// Returns the largest key.
+    pub fn last_key(&self) -> &K {
+        self.last_key_value().0
+    }
+
+    /// Consumes the map and returns the entry with the smallest key.
+    pub fn into_first(self) -> (K, V) {
+        self.inner.into_iter().next().expect("NonEmpty is never empty")
+    }
+
+    /// Returns an iterator over the keys in sorted order, guaranteed to yield at least one item.
+    pub fn keys_ne(&self) -> NonEmptyIter<Keys<'_, K, V>> {
+        NonEmptyIter::new_unchecked(self.inner.keys())
+    }
+
+    /// Returns an iterator over the values in key order, guaranteed to yield at least one item.
+    pub fn values_ne(&self) -> NonEmptyIter<Values<'_, K, V>> {
+        NonEmptyIter::new_unchecked(self.inner.values())
+    }
+
+    /// Consumes the map and returns its keys in sorted order.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use non_empty::TryNonEmpty;
+    ///
+    /// let mut m = BTreeMap::new();
+    /// m.insert(2, "b");
+    /// m.insert(1, "a");
+    /// let m = m.try_non_empty().unwrap();
+    ///
+    /// assert_eq!(vec![1, 2], *m.clone().into_keys_ne());
+    /// assert_eq!(vec!["a", "b"], *m.into_values_ne());
+    /// ```
+    pub fn into_keys_ne(self) -> NonEmpty<Vec<K>> {
+        NonEmpty { inner: self.inner.into_keys().collect() }
+    }
+
+    /// Consumes the map and returns its values in key order.
+    pub fn into_values_ne(self) -> NonEmpty<Vec<V>> {
+        NonEmpty { inner: self.inner.into_values().collect() }
+    }
+
+    /// Gets the entry of a key, like `BTreeMap::entry`.
+    ///
+    /// Removing the only entry through the returned entry fails with `WouldBeEmpty`.
+    pub fn entry(&mut self, key: K) -> BTreeMapEntry<'_, K, V> {
+        let len = self.inner.len();
+        BTreeMapEntry::new(self.inner.entry(key), len)
+    }
+
+    /// Removes and returns the entry with the smallest key.
+    ///
+    /// Returns `Err(WouldBeEmpty)` and leaves the map untouched
+    /// if it's the only entry.
+    pub fn pop_first(&mut self) -> Result<(K, V), WouldBeEmpty> {
+        if self.inner.len() == 1 {
+            return Err(WouldBeEmpty)
+        }
+        Ok(self.inner.pop_first().expect("NonEmpty is never empty"))
+    }
+
+    /// Removes and returns the entry with the largest key.
+    ///
+    /// Returns `Err(WouldBeEmpty)` and leaves the map untouched
+    /// if it's the only entry.
+    pub fn pop_last(&mut self) -> Result<(K, V), WouldBeEmpty> {
+        if self.inner.len() == 1 {
+            return Err(WouldBeEmpty)
+        }
+        Ok(self.inner.pop_last().expect("NonEmpty is never empty"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::super::{BTreeMapEntry, BTreeMapNE, TryNonEmpty, WouldBeEmpty};
+
+    fn map(v: &[(i32, char)]) -> BTreeMapNE<i32, char> {
+        v.iter().cloned().collect::<BTreeMap<_, _>>().try_non_empty().unwrap()
+    }
+
+    #[test]
+    fn single_entry() {
+        let m = map(&[(1, 'a')]);
+        assert_eq!((&1, &'a'), m.first_key_value());
+        assert_eq!((&1, &'a'), m.last_key_value());
+        assert_eq!(&1, m.first_key());
+        assert_eq!(&1, m.last_key());
+        assert_eq!((1, 'a'), m.into_first());
+    }
+
+    #[test]
+    fn ordering() {
+        let m = map(&[(3, 'c'), (1, 'a'), (2, 'b')]);
+        assert_eq!((&1, &'a'), m.first_key_value());
+        assert_eq!((&3, &'c'), m.last_key_value());
+        assert_eq!(&1, m.first_key());
+        assert_eq!(&3, m.last_key());
+        assert_eq!((1, 'a'), m.into_first());
+    }
+
+    #[test]
+    fn keys_and_values() {
+        let m = map(&[(3, 'c'), (1, 'a'), (2, 'b')]);
+        assert_eq!(1, *m.keys_ne().first());
+        assert_eq!('c', *m.values_ne().last());
+        assert_eq!(vec![1, 2, 3], *m.clone().into_keys_ne());
+        assert_eq!(vec!['a', 'b', 'c'], *m.into_values_ne());
+
+        let m = map(&[(1, 'a')]);
+        assert_eq!(vec![1], *m.clone().into_keys_ne());
+        assert_eq!(vec!['a'], *m.into_values_ne());
+    }
+
+    #[test]
+    fn pop() {
+        let mut m = map(&[(3, 'c'), (1, 'a'), (2, 'b')]);
+        assert_eq!(Ok((1, 'a')), m.pop_first());
+        assert_eq!(Ok((3, 'c')), m.pop_last());
+        assert_eq!(Err(WouldBeEmpty), m.pop_first());
+        assert_eq!(Err(WouldBeEmpty), m.pop_last());
+        assert_eq!((&2, &'b'), m.first_key_value());
+    }
+
+    #[test]
+    fn entry() {
+        let mut m = map(&[(1, 'a')]);
+        m.entry(2).or_insert('b');
+        m.entry(1).and_modify(|v| *v = 'x');
+        assert_eq!((&1, &'x'), m.first_key_value());
+
+        if let BTreeMapEntry::Occupied(e) = m.entry(1) {
+            assert_eq!(Ok('x'), e.remove());
+        }
+        // only (2, 'b') is left
+        if let BTreeMapEntry::Occupied(e) = m.entry(2) {
+            assert_eq!('b', *e.get());
+            assert_eq!(Err(WouldBeEmpty), e.remove());
+        }
+        assert_eq!((&2, &'b'), m.first_key_value());
+        assert!(matches!(m.entry(3), BTreeMapEntry::Vacant(_)));
+    }
+}
@@ -0,0 +1,124 @@
+use std::borrow::Borrow;
+use std::collections::BTreeSet;
+
+use super::{NonEmpty, WouldBeEmpty};
+
+impl<T: Ord> NonEmpty<BTreeSet<T>> {
+    /// Returns the smallest element.
+    pub fn first(&self) -> &T {
+        self.inner.first().expect("NonEmpty is never empty")
+    }
+
+    /// Returns the largest element.
+    pub fn last(&self) -> &T {
+        self.inner.last().expect("NonEmpty is never empty")
+    }
+
+    /// Adds a value to the set, like `BTreeSet::insert`.
+    ///
+    /// Returns whether the value was newly inserted.
+    #[inline]
+    pub fn insert(&mut self, value: T) -> bool {
+        self.inner.insert(value)
+    }
+
+    /// Removes a value from the set, like `BTreeSet::remove`.
+    ///
+    /// Returns `Err(WouldBeEmpty)` and leaves the set untouched
+    /// if `value` is the only element.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use non_empty::{TryNonEmpty, WouldBeEmpty};
+    ///
+    /// let mut s = vec![1, 2].into_iter().collect::<BTreeSet<_>>().try_non_empty().unwrap();
+    /// assert_eq!(Ok(true), s.remove(&1));
+    /// assert_eq!(Ok(false), s.remove(&1));
+    /// assert_eq!(Err(WouldBeEmpty), s.remove(&2));
+    /// ```
+    pub fn remove<Q>(&mut self, value: &Q) -> Result<bool, WouldBeEmpty>
+        where T: Borrow<Q>,
+              Q: Ord + ?Sized,
+    {
+        if self.inner.len() == 1 && self.inner.contains(value) {
+            return Err(WouldBeEmpty)
+        }
+        Ok(self.inner.remove(value))
+    }
+
+    /// Removes and returns the smallest element.
+    ///
+    /// Returns `Err(WouldBeEmpty)` and leaves the set untouched
+    /// if it's the only element.
+    pub fn pop_first(&mut self) -> Result<T, WouldBeEmpty> {
+        if self.inner.len() == 1 {
+            return Err(WouldBeEmpty)
+        }
+        Ok(self.inner.pop_first().expect("NonEmpty is never empty"))
+    }
+
+    /// Removes and returns the largest element.
+    ///
+    /// Returns `Err(WouldBeEmpty)` and leaves the set untouched
+    /// if it's the only element.
+    pub fn pop_last(&mut self) -> Result<T, WouldBeEmpty> {
+        if self.inner.len() == 1 {
+            return Err(WouldBeEmpty)
+        }
+        Ok(self.inner.pop_last().expect("NonEmpty is never empty"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::super::{BTreeSetNE, TryNonEmpty, WouldBeEmpty};
+
+    fn set(v: &[i32]) -> BTreeSetNE<i32> {
+        v.iter().cloned().collect::<BTreeSet<_>>().try_non_empty().unwrap()
+    }
+
+    #[test]
+    fn first_and_last() {
+        let s = set(&[5]);
+        assert_eq!(5, *s.first());
+        assert_eq!(5, *s.last());
+
+        let s = set(&[5, -1, 3]);
+        assert_eq!(-1, *s.first());
+        assert_eq!(5, *s.last());
+    }
+
+    #[test]
+    fn insert() {
+        let mut s = set(&[1]);
+        assert!(!s.insert(1));
+        assert_eq!(1, s.len());
+        assert!(s.insert(0));
+        assert_eq!(0, *s.first());
+    }
+
+    #[test]
+    fn remove() {
+        let mut s = set(&[1]);
+        assert_eq!(Ok(false), s.remove(&2));
+        assert_eq!(Err(WouldBeEmpty), s.remove(&1));
+        assert_eq!(1, s.len());
+
+        let mut s = set(&[1, 2]);
+        assert_eq!(Ok(true), s.remove(&1));
+        assert_eq!(Err(WouldBeEmpty), s.remove(&2));
+    }
+
+    #[test]
+    fn pop() {
+        let mut s = set(&[1, 2, 3]);
+        assert_eq!(Ok(1), s.pop_first());
+        assert_eq!(Ok(3), s.pop_last());
+        assert_eq!(Err(WouldBeEmpty), s.pop_first());
+        assert_eq!(Err(WouldBeEmpty), s.pop_last());
+        assert_eq!(2, *s.first());
+    }
+}
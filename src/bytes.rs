@@ -0,0 +1,97 @@
+extern crate bytes;
+
+use self::bytes::{Bytes, BytesMut};
+
+use super::{IsEmpty, NonEmpty};
+
+/// Non-empty `Bytes`.
+pub type BytesNE = NonEmpty<Bytes>;
+
+/// Non-empty `BytesMut`.
+pub type BytesMutNE = NonEmpty<BytesMut>;
+
+impl IsEmpty for Bytes {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        Bytes::is_empty(self)
+    }
+}
+
+impl IsEmpty for BytesMut {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        BytesMut::is_empty(self)
+    }
+}
+
+impl From<NonEmpty<Bytes>> for Bytes {
+    #[inline]
+    fn from(v: NonEmpty<Bytes>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl From<NonEmpty<BytesMut>> for BytesMut {
+    #[inline]
+    fn from(v: NonEmpty<BytesMut>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl From<NonEmpty<Vec<u8>>> for NonEmpty<Bytes> {
+    #[inline]
+    fn from(v: NonEmpty<Vec<u8>>) -> Self {
+        NonEmpty { inner: Bytes::from(v.inner) }
+    }
+}
+
+impl From<NonEmpty<Bytes>> for NonEmpty<Vec<u8>> {
+    #[inline]
+    fn from(v: NonEmpty<Bytes>) -> Self {
+        NonEmpty { inner: Vec::from(v.inner) }
+    }
+}
+
+impl From<NonEmpty<BytesMut>> for NonEmpty<Bytes> {
+    #[inline]
+    fn from(v: NonEmpty<BytesMut>) -> Self {
+        NonEmpty { inner: v.inner.freeze() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::bytes::{Bytes, BytesMut};
+    use super::{BytesMutNE, BytesNE};
+    use super::super::{NonEmpty, TryNonEmpty, VecNE};
+
+    #[test]
+    fn empty_rejection() {
+        assert!(Bytes::new().try_non_empty().is_none());
+        assert!(BytesMut::new().try_non_empty().is_none());
+        assert!(Bytes::from_static(b"a").try_non_empty().is_some());
+    }
+
+    #[test]
+    fn slicing_leaves_the_wrapper() {
+        let b: BytesNE = Bytes::from_static(b"frame").try_non_empty().unwrap();
+        assert_eq!(Bytes::from_static(b"fr"), b.slice(..2));
+        // an empty slice is a plain Bytes, it must be validated again
+        assert!(b.slice(2..2).try_non_empty().is_none());
+    }
+
+    #[test]
+    fn conversions() {
+        let v: VecNE<u8> = vec![1, 2, 3].try_non_empty().unwrap();
+        let b: BytesNE = v.into();
+        assert_eq!(&[1, 2, 3][..], &b[..]);
+
+        let v: VecNE<u8> = b.clone().into();
+        assert_eq!(vec![1, 2, 3], *v);
+
+        let m: BytesMutNE = BytesMut::from(&b"abc"[..]).try_non_empty().unwrap();
+        let b = NonEmpty::<Bytes>::from(m);
+        let inner: Bytes = b.into();
+        assert_eq!(Bytes::from_static(b"abc"), inner);
+    }
+}
@@ -0,0 +1,86 @@
+use std::ffi::{CString, IntoStringError, NulError};
+
+use super::NonEmpty;
+
+impl NonEmpty<String> {
+    /// Converts into a `NonEmpty<CString>`, like `CString::new`.
+    ///
+    /// # Errors
+    /// Returns the `NulError` of `CString::new` if the string contains
+    /// a NUL byte, the original bytes can be recovered with `NulError::into_vec`.
+    ///
+    /// # Examples
+    /// ```
+    /// use non_empty::{StringNE, TryNonEmpty};
+    ///
+    /// let s: StringNE = "hello".to_string().try_non_empty().unwrap();
+    /// let c = s.into_c_string_ne().unwrap();
+    /// assert_eq!(b"hello", c.as_bytes());
+    /// ```
+    pub fn into_c_string_ne(self) -> Result<NonEmpty<CString>, NulError> {
+        CString::new(self.inner).map(|inner| NonEmpty { inner })
+    }
+}
+
+impl NonEmpty<&str> {
+    /// Copies into a `NonEmpty<CString>`, like `CString::new`.
+    ///
+    /// # Errors
+    /// Returns the `NulError` of `CString::new` if the string contains a NUL byte.
+    pub fn into_c_string_ne(self) -> Result<NonEmpty<CString>, NulError> {
+        CString::new(self.inner).map(|inner| NonEmpty { inner })
+    }
+}
+
+impl NonEmpty<CString> {
+    /// Converts into a `NonEmpty<String>` if the content is valid UTF-8,
+    /// like `CString::into_string`.
+    ///
+    /// # Errors
+    /// Returns the `IntoStringError` of `CString::into_string`, the original
+    /// C string can be recovered with `IntoStringError::into_cstring`.
+    pub fn into_string_ne(self) -> Result<NonEmpty<String>, IntoStringError> {
+        self.inner.into_string().map(|inner| NonEmpty { inner })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::{CStr, CString};
+
+    use super::super::{CStringNE, StringNE, TryNonEmpty};
+
+    #[test]
+    fn empty() {
+        assert!(CString::default().try_non_empty().is_none());
+        assert!(<&CStr>::default().try_non_empty().is_none());
+    }
+
+    #[test]
+    fn round_trip() {
+        let s: StringNE = "héllo".to_string().try_non_empty().unwrap();
+        let c: CStringNE = s.clone().into_c_string_ne().unwrap();
+        assert_eq!("héllo".as_bytes(), c.as_bytes());
+        assert_eq!(s, c.into_string_ne().unwrap());
+
+        let c = "a".try_non_empty().unwrap().into_c_string_ne().unwrap();
+        assert_eq!(b"a\0", c.as_bytes_with_nul());
+    }
+
+    #[test]
+    fn interior_nul() {
+        let s: StringNE = "a\0b".to_string().try_non_empty().unwrap();
+        let err = s.into_c_string_ne().unwrap_err();
+        assert_eq!(1, err.nul_position());
+        assert_eq!(b"a\0b".to_vec(), err.into_vec());
+
+        assert!("\0".try_non_empty().unwrap().into_c_string_ne().is_err());
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let c: CStringNE = CString::new(vec![0xff]).unwrap().try_non_empty().unwrap();
+        let err = c.into_string_ne().unwrap_err();
+        assert_eq!(vec![0xff], err.into_cstring().into_bytes());
+    }
+}
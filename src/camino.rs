@@ -0,0 +1,212 @@
+extern crate camino;
+
+use std::path::{Path, PathBuf};
+
+use self::camino::{Utf8Path, Utf8PathBuf};
+
+use super::{IsEmpty, NonEmpty};
+
+/// Non-empty `Utf8Path`, borrow one with `NonEmpty::from_ref`
+pub type Utf8PathNE = NonEmpty<Utf8Path>;
+
+/// Non-empty `Utf8PathBuf`
+pub type Utf8PathBufNE = NonEmpty<Utf8PathBuf>;
+
+impl IsEmpty for Utf8PathBuf {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.as_str().is_empty()
+    }
+}
+
+impl IsEmpty for Utf8Path {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.as_str().is_empty()
+    }
+}
+
+impl IsEmpty for &Utf8Path {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.as_str().is_empty()
+    }
+}
+
+impl From<NonEmpty<Utf8PathBuf>> for Utf8PathBuf {
+    #[inline]
+    fn from(v: NonEmpty<Utf8PathBuf>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl<'a> From<NonEmpty<&'a Utf8Path>> for &'a Utf8Path {
+    #[inline]
+    fn from(v: NonEmpty<&'a Utf8Path>) -> Self {
+        v.into_inner()
+    }
+}
+
+macro_rules! as_ref_impls {
+    ($($t:ty),*) => {
+        $(
+            impl AsRef<Utf8Path> for NonEmpty<$t> {
+                #[inline]
+                fn as_ref(&self) -> &Utf8Path {
+                    self.inner.as_ref()
+                }
+            }
+
+            impl AsRef<Path> for NonEmpty<$t> {
+                #[inline]
+                fn as_ref(&self) -> &Path {
+                    self.inner.as_std_path()
+                }
+            }
+
+            impl AsRef<str> for NonEmpty<$t> {
+                #[inline]
+                fn as_ref(&self) -> &str {
+                    self.inner.as_str()
+                }
+            }
+        )*
+    };
+}
+
+as_ref_impls!(Utf8PathBuf, &Utf8Path);
+
+// AsRef<Utf8Path> is covered by AsRef<T> for NonEmpty<T>
+impl AsRef<Path> for NonEmpty<Utf8Path> {
+    #[inline]
+    fn as_ref(&self) -> &Path {
+        self.inner.as_std_path()
+    }
+}
+
+impl AsRef<str> for NonEmpty<Utf8Path> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.inner.as_str()
+    }
+}
+
+impl NonEmpty<Utf8PathBuf> {
+    /// Borrows the path as a `NonEmpty<&Utf8Path>`.
+    #[inline]
+    pub fn as_utf8_path_ne(&self) -> NonEmpty<&Utf8Path> {
+        NonEmpty { inner: self.inner.as_path() }
+    }
+}
+
+impl NonEmpty<PathBuf> {
+    /// Converts into a `NonEmpty<Utf8PathBuf>` if the path is valid UTF-8,
+    /// like `Utf8PathBuf::from_path_buf`.
+    ///
+    /// On failure the original value is returned, still wrapped.
+    pub fn into_utf8_path_buf_ne(self) -> Result<NonEmpty<Utf8PathBuf>, NonEmpty<PathBuf>> {
+        Utf8PathBuf::from_path_buf(self.inner)
+            .map(|inner| NonEmpty { inner })
+            .map_err(|inner| NonEmpty { inner })
+    }
+}
+
+impl From<NonEmpty<String>> for NonEmpty<Utf8PathBuf> {
+    #[inline]
+    fn from(s: NonEmpty<String>) -> Self {
+        NonEmpty { inner: Utf8PathBuf::from(s.inner) }
+    }
+}
+
+impl From<NonEmpty<Utf8PathBuf>> for NonEmpty<String> {
+    #[inline]
+    fn from(p: NonEmpty<Utf8PathBuf>) -> Self {
+        NonEmpty { inner: p.inner.into_string() }
+    }
+}
+
+impl<'a> From<&'a NonEmpty<Utf8Path>> for NonEmpty<Utf8PathBuf> {
+    #[inline]
+    fn from(p: &'a NonEmpty<Utf8Path>) -> Self {
+        NonEmpty { inner: p.inner.to_path_buf() }
+    }
+}
+
+impl From<NonEmpty<Utf8PathBuf>> for NonEmpty<PathBuf> {
+    #[inline]
+    fn from(p: NonEmpty<Utf8PathBuf>) -> Self {
+        NonEmpty { inner: p.inner.into_std_path_buf() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{self, File};
+    use std::io::Read;
+    use std::path::{Path, PathBuf};
+
+    use super::camino::{Utf8Path, Utf8PathBuf};
+    use super::{Utf8PathBufNE, Utf8PathNE};
+    use super::super::{NonEmpty, PathBufNE, StringNE, TryNonEmpty};
+
+    #[test]
+    fn empty_rejection() {
+        assert!(Utf8PathBuf::new().try_non_empty().is_none());
+        assert!(Utf8Path::new("").try_non_empty().is_none());
+        assert!(Utf8Path::new("a").try_non_empty().is_some());
+    }
+
+    #[test]
+    fn borrowed_unsized() {
+        let p: &Utf8PathNE = NonEmpty::from_ref(Utf8Path::new("a/b")).unwrap();
+        assert_eq!("b", p.file_name().unwrap());
+        assert_eq!(Path::new("a/b"), AsRef::<Path>::as_ref(p));
+        assert!(NonEmpty::from_ref(Utf8Path::new("")).is_none());
+        let owned: Utf8PathBufNE = p.into();
+        assert_eq!("a/b", owned.as_str());
+    }
+
+    #[test]
+    fn string_round_trip() {
+        let s: StringNE = "dir/file.txt".to_string().try_non_empty().unwrap();
+        let p: Utf8PathBufNE = s.into();
+        assert_eq!(Some("file.txt"), p.file_name());
+        assert_eq!("dir", p.as_utf8_path_ne().parent().unwrap());
+
+        let s: StringNE = p.clone().into();
+        assert_eq!("dir/file.txt", *s);
+
+        let std: PathBufNE = p.into();
+        assert_eq!(Path::new("dir/file.txt"), *std);
+        let p = std.into_utf8_path_buf_ne().unwrap();
+        assert_eq!("dir/file.txt", p.as_str());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn non_utf8_path() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let p: PathBufNE = PathBuf::from(OsStr::from_bytes(b"\xff")).try_non_empty().unwrap();
+        let p = p.into_utf8_path_buf_ne().unwrap_err();
+        assert_eq!(OsStr::from_bytes(b"\xff"), p.as_os_str());
+    }
+
+    #[test]
+    fn open_through_as_ref() {
+        let dir = Utf8PathBuf::from_path_buf(std::env::temp_dir()).unwrap();
+        let p: Utf8PathBufNE = dir.join(format!("non_empty_{}_open_through_as_ref.txt", std::process::id())).try_non_empty().unwrap();
+        fs::write(&p, "content").unwrap();
+
+        let mut content = String::new();
+        File::open(&p).unwrap().read_to_string(&mut content).unwrap();
+        assert_eq!("content", content);
+        assert!(AsRef::<str>::as_ref(&p).ends_with(".txt"));
+        let borrowed: NonEmpty<&Utf8Path> = p.as_utf8_path_ne();
+        assert!(AsRef::<Path>::as_ref(&borrowed).exists());
+        assert_eq!(p.as_path(), AsRef::<Utf8Path>::as_ref(&borrowed));
+
+        fs::remove_file(&p).unwrap();
+    }
+}
@@ -0,0 +1,62 @@
+extern crate chrono;
+
+use std::time;
+
+use self::chrono::Duration;
+
+use super::{IsEmpty, NonEmpty};
+
+/// Non-empty `chrono::Duration`, duration != 0
+pub type ChronoDurationNE = NonEmpty<Duration>;
+
+/// Only a zero duration is empty.
+///
+/// Negative durations are not empty, they are meaningful offsets.
+impl IsEmpty for Duration {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.is_zero()
+    }
+}
+
+impl From<NonEmpty<Duration>> for Duration {
+    #[inline]
+    fn from(v: NonEmpty<Duration>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl NonEmpty<Duration> {
+    /// Returns the absolute value as a non-empty std `Duration`.
+    ///
+    /// The conversion is lossless, both types have nanosecond precision.
+    pub fn abs_ne(&self) -> NonEmpty<time::Duration> {
+        let inner = self.inner.abs()
+            .to_std()
+            .expect("an absolute duration is never negative");
+        NonEmpty { inner }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time;
+
+    use super::chrono::Duration;
+    use super::ChronoDurationNE;
+    use super::super::{IsEmpty, TryNonEmpty};
+
+    #[test]
+    fn zero_positive_negative() {
+        assert!(Duration::zero().is_empty());
+        assert!(Duration::zero().try_non_empty().is_none());
+
+        let pos: ChronoDurationNE = Duration::milliseconds(1500).try_non_empty().unwrap();
+        assert_eq!(time::Duration::from_millis(1500), *pos.abs_ne());
+
+        let neg: ChronoDurationNE = Duration::nanoseconds(-7).try_non_empty().unwrap();
+        assert_eq!(time::Duration::from_nanos(7), *neg.abs_ne());
+        let inner: Duration = neg.into();
+        assert_eq!(Duration::nanoseconds(-7), inner);
+    }
+}
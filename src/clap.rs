@@ -0,0 +1,233 @@
+//! [clap](https://docs.rs/clap) value parsers rejecting empty arguments.
+//!
+//! `NonEmpty<T>` implements `ValueParserFactory`, so `value_parser!` and the
+//! derive API pick up [NonEmptyValueParser](struct.NonEmptyValueParser.html)
+//! automatically for the types implementing [NonEmptyArg](trait.NonEmptyArg.html).
+//!
+//! # Examples
+//! ```
+//! extern crate clap;
+//! extern crate non_empty;
+//!
+//! use clap::Parser;
+//! use clap::error::ErrorKind;
+//! use non_empty::{PathBufNE, StringNE, u32NE};
+//!
+//! #[derive(Parser)]
+//! struct Args {
+//!     #[arg(long)]
+//!     name: StringNE,
+//!     #[arg(long)]
+//!     path: PathBufNE,
+//!     #[arg(long)]
+//!     count: u32NE,
+//! }
+//!
+//! # fn main() {
+//! let args = Args::try_parse_from(["app", "--name", "abc", "--path", "a", "--count", "3"]).unwrap();
+//! assert_eq!("abc", *args.name);
+//! assert_eq!("a", args.path.to_str().unwrap());
+//! assert_eq!(3, *args.count);
+//!
+//! let err = Args::try_parse_from(["app", "--name", "", "--path", "a", "--count", "3"]).err().unwrap();
+//! assert_eq!(ErrorKind::InvalidValue, err.kind());
+//!
+//! let err = Args::try_parse_from(["app", "--name", "abc", "--path", "a", "--count", "0"]).err().unwrap();
+//! assert_eq!(ErrorKind::InvalidValue, err.kind());
+//! # }
+//! ```
+
+extern crate clap;
+
+use std::ffi::{OsStr, OsString};
+use std::path::PathBuf;
+
+use self::clap::builder::{OsStringValueParser, PathBufValueParser, RangedI64ValueParser,
+                          RangedU64ValueParser, StringValueParser, TypedValueParser,
+                          ValueParserFactory};
+use self::clap::error::{ContextKind, ContextValue, Error, ErrorKind};
+use self::clap::{Arg, Command};
+
+use super::{IsEmpty, NonEmpty};
+
+/// Types whose clap value parser can be wrapped by
+/// [NonEmptyValueParser](struct.NonEmptyValueParser.html).
+pub trait NonEmptyArg: IsEmpty + Clone + Send + Sync + 'static {
+    /// The parser of the inner value.
+    type Parser: TypedValueParser<Value = Self>;
+
+    /// Returns the parser of the inner value.
+    fn inner_value_parser() -> Self::Parser;
+}
+
+impl NonEmptyArg for String {
+    type Parser = StringValueParser;
+
+    fn inner_value_parser() -> StringValueParser {
+        StringValueParser::new()
+    }
+}
+
+impl NonEmptyArg for OsString {
+    type Parser = OsStringValueParser;
+
+    fn inner_value_parser() -> OsStringValueParser {
+        OsStringValueParser::new()
+    }
+}
+
+impl NonEmptyArg for PathBuf {
+    type Parser = PathBufValueParser;
+
+    fn inner_value_parser() -> PathBufValueParser {
+        PathBufValueParser::new()
+    }
+}
+
+macro_rules! int_args {
+    ($($t:ty)*) => ($(
+        impl NonEmptyArg for $t {
+            type Parser = <$t as ValueParserFactory>::Parser;
+
+            fn inner_value_parser() -> Self::Parser {
+                <$t as ValueParserFactory>::value_parser()
+            }
+        }
+    )*)
+}
+
+int_args! { i8 i16 i32 i64 u8 u16 u32 u64 }
+
+// clap has no `ValueParserFactory` for the pointer-sized integers,
+// the ranged parsers reject values which don't fit
+
+impl NonEmptyArg for isize {
+    type Parser = RangedI64ValueParser<isize>;
+
+    fn inner_value_parser() -> Self::Parser {
+        RangedI64ValueParser::new()
+    }
+}
+
+impl NonEmptyArg for usize {
+    type Parser = RangedU64ValueParser<usize>;
+
+    fn inner_value_parser() -> Self::Parser {
+        RangedU64ValueParser::new()
+    }
+}
+
+/// Parses the inner value and rejects it if it is empty.
+///
+/// The error has the kind `ErrorKind::InvalidValue` and names the argument.
+#[derive(Clone, Debug)]
+pub struct NonEmptyValueParser<P> {
+    inner: P,
+}
+
+/// Returns a value parser for `NonEmpty<T>`.
+///
+/// Only needed to wrap a parser explicitly, `value_parser!(NonEmpty<T>)`
+/// already returns this parser.
+pub fn non_empty_value_parser<T: NonEmptyArg>() -> NonEmptyValueParser<T::Parser> {
+    NonEmptyValueParser { inner: T::inner_value_parser() }
+}
+
+impl<P> TypedValueParser for NonEmptyValueParser<P>
+    where P: TypedValueParser,
+          P::Value: IsEmpty,
+{
+    type Value = NonEmpty<P::Value>;
+
+    fn parse_ref(&self, cmd: &Command, arg: Option<&Arg>, value: &OsStr) -> Result<Self::Value, Error> {
+        let inner = self.inner.parse_ref(cmd, arg, value)?;
+        if inner.is_empty() {
+            return Err(empty_value_error(cmd, arg, value))
+        }
+        Ok(NonEmpty { inner })
+    }
+}
+
+impl<T: NonEmptyArg> ValueParserFactory for NonEmpty<T> {
+    type Parser = NonEmptyValueParser<T::Parser>;
+
+    fn value_parser() -> Self::Parser {
+        non_empty_value_parser::<T>()
+    }
+}
+
+fn empty_value_error(cmd: &Command, arg: Option<&Arg>, value: &OsStr) -> Error {
+    let arg = arg.map(ToString::to_string).unwrap_or_else(|| "...".to_owned());
+    let mut err = Error::new(ErrorKind::InvalidValue).with_cmd(cmd);
+    err.insert(ContextKind::InvalidArg, ContextValue::String(arg));
+    err.insert(ContextKind::InvalidValue, ContextValue::String(value.to_string_lossy().into_owned()));
+    err.insert(ContextKind::Suggested, ContextValue::StyledStrs(vec!["the value must not be empty".into()]));
+    err
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::clap::error::ErrorKind;
+    use super::clap::{value_parser, Arg, Command};
+    use super::non_empty_value_parser;
+    use super::super::{PathBufNE, StringNE, isizeNE, u32NE, usizeNE};
+
+    fn cmd() -> Command {
+        Command::new("app")
+            .arg(Arg::new("name").long("name").value_parser(value_parser!(StringNE)))
+            .arg(Arg::new("path").long("path").value_parser(value_parser!(PathBufNE)))
+            .arg(Arg::new("count").long("count").value_parser(non_empty_value_parser::<u32>()))
+            .arg(Arg::new("jobs").long("jobs").value_parser(value_parser!(usizeNE)))
+            .arg(Arg::new("offset").long("offset").value_parser(value_parser!(isizeNE)).allow_negative_numbers(true))
+    }
+
+    #[test]
+    fn parse() {
+        let m = cmd().try_get_matches_from(["app", "--name", "a", "--path", "/tmp", "--count", "2"]).unwrap();
+        assert_eq!("a", **m.get_one::<StringNE>("name").unwrap());
+        assert_eq!(PathBuf::from("/tmp"), **m.get_one::<PathBufNE>("path").unwrap());
+        assert_eq!(2, **m.get_one::<u32NE>("count").unwrap());
+    }
+
+    #[test]
+    fn empty_string() {
+        let err = cmd().try_get_matches_from(["app", "--name", ""]).unwrap_err();
+        assert_eq!(ErrorKind::InvalidValue, err.kind());
+        let msg = err.to_string();
+        assert!(msg.contains("a value is required for '--name <name>'"), "{}", msg);
+        assert!(msg.contains("the value must not be empty"), "{}", msg);
+
+        let err = cmd().try_get_matches_from(["app", "--path", ""]).unwrap_err();
+        assert_eq!(ErrorKind::InvalidValue, err.kind());
+    }
+
+    #[test]
+    fn zero() {
+        let err = cmd().try_get_matches_from(["app", "--count", "0"]).unwrap_err();
+        assert_eq!(ErrorKind::InvalidValue, err.kind());
+        let msg = err.to_string();
+        assert!(msg.contains("invalid value '0' for '--count <count>'"), "{}", msg);
+    }
+
+    #[test]
+    fn inner_parser_errors_are_kept() {
+        let err = cmd().try_get_matches_from(["app", "--count", "abc"]).unwrap_err();
+        assert_eq!(ErrorKind::ValueValidation, err.kind());
+    }
+
+    #[test]
+    fn pointer_sized() {
+        let m = cmd().try_get_matches_from(["app", "--jobs", "8", "--offset", "-2"]).unwrap();
+        assert_eq!(8, **m.get_one::<usizeNE>("jobs").unwrap());
+        assert_eq!(-2, **m.get_one::<isizeNE>("offset").unwrap());
+
+        for args in [["app", "--jobs", "0"], ["app", "--offset", "0"]] {
+            let err = cmd().try_get_matches_from(args).unwrap_err();
+            assert_eq!(ErrorKind::InvalidValue, err.kind(), "{:?}", args);
+        }
+        let err = cmd().try_get_matches_from(["app", "--jobs=-1"]).unwrap_err();
+        assert_eq!(ErrorKind::ValueValidation, err.kind());
+    }
+}
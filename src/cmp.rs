@@ -0,0 +1,95 @@
+use std::borrow::Cow;
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+
+use super::NonEmpty;
+
+/////////////////////////////////////////////////////////////////////////
+// Equality between NonEmpty values of different inner types
+/////////////////////////////////////////////////////////////////////////
+
+/// Implements `==` in both directions, delegating to the std impls
+/// between the inner types.
+macro_rules! cross_eq_impls {
+    ($([$($g:tt)*] $a:ty, $b:ty;)*) => ($(
+        impl<$($g)*> PartialEq<NonEmpty<$b>> for NonEmpty<$a> {
+            #[inline]
+            fn eq(&self, other: &NonEmpty<$b>) -> bool {
+                self.inner == other.inner
+            }
+        }
+
+        impl<$($g)*> PartialEq<NonEmpty<$a>> for NonEmpty<$b> {
+            #[inline]
+            fn eq(&self, other: &NonEmpty<$a>) -> bool {
+                self.inner == other.inner
+            }
+        }
+    )*)
+}
+
+cross_eq_impls! {
+    ['a] String, &'a str;
+    ['a] Cow<'a, str>, String;
+    ['a, 'b] Cow<'a, str>, &'b str;
+    ['a] PathBuf, &'a Path;
+    ['a] OsString, &'a OsStr;
+}
+
+impl<'a, T, U> PartialEq<NonEmpty<&'a [U]>> for NonEmpty<Vec<T>>
+    where T: PartialEq<U>,
+{
+    #[inline]
+    fn eq(&self, other: &NonEmpty<&'a [U]>) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<T, U> PartialEq<NonEmpty<Vec<U>>> for NonEmpty<&[T]>
+    where T: PartialEq<U>,
+{
+    #[inline]
+    fn eq(&self, other: &NonEmpty<Vec<U>>) -> bool {
+        self.inner == other.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::ffi::{OsStr, OsString};
+    use std::path::{Path, PathBuf};
+
+    use super::super::ne;
+
+    /// Asserts `a == b` and `b == a`, and that both differ from `other`.
+    macro_rules! assert_cross_eq {
+        ($a:expr, $b:expr, $other:expr) => {{
+            let (a, b, other) = ($a, $b, $other);
+            assert!(a == b);
+            assert!(b == a);
+            assert!(a != other);
+            assert!(other != a);
+        }};
+    }
+
+    #[test]
+    fn strings() {
+        assert_cross_eq!(ne("a".to_string()), ne("a"), ne("b"));
+        assert_cross_eq!(ne(Cow::Borrowed("a")), ne("a".to_string()), ne("b".to_string()));
+        assert_cross_eq!(ne(Cow::Owned::<str>("a".to_string())), ne("a"), ne("b"));
+        assert_cross_eq!(ne(OsString::from("a")), ne(OsStr::new("a")), ne(OsStr::new("b")));
+    }
+
+    #[test]
+    fn paths() {
+        assert_cross_eq!(ne(PathBuf::from("a/b")), ne(Path::new("a/b")), ne(Path::new("a")));
+    }
+
+    #[test]
+    fn slices() {
+        let v = vec![1, 2];
+        assert_cross_eq!(ne(v.clone()), ne(&v[..]), ne(&v[..1]));
+        assert_cross_eq!(ne(&v[..]), ne(v.clone()), ne(vec![2, 1]));
+    }
+}
@@ -0,0 +1,82 @@
+extern crate compact_str;
+
+use self::compact_str::CompactString;
+
+use super::{IsEmpty, NonEmpty};
+
+/// Non-empty `CompactString`.
+pub type CompactStringNE = NonEmpty<CompactString>;
+
+impl IsEmpty for CompactString {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        CompactString::is_empty(self)
+    }
+}
+
+impl From<NonEmpty<CompactString>> for CompactString {
+    #[inline]
+    fn from(v: NonEmpty<CompactString>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl AsRef<str> for NonEmpty<CompactString> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.inner.as_str()
+    }
+}
+
+impl From<NonEmpty<String>> for NonEmpty<CompactString> {
+    #[inline]
+    fn from(s: NonEmpty<String>) -> Self {
+        NonEmpty { inner: CompactString::from(s.inner) }
+    }
+}
+
+impl From<NonEmpty<CompactString>> for NonEmpty<String> {
+    #[inline]
+    fn from(s: NonEmpty<CompactString>) -> Self {
+        NonEmpty { inner: String::from(s.inner) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::compact_str::CompactString;
+    use super::CompactStringNE;
+    use super::super::{StringNE, TryNonEmpty};
+
+    #[test]
+    fn empty_rejection() {
+        assert!(CompactString::default().try_non_empty().is_none());
+        assert!(CompactString::from("a").try_non_empty().is_some());
+    }
+
+    #[test]
+    fn inline_and_heap() {
+        let short: CompactStringNE = CompactString::from("short").try_non_empty().unwrap();
+        assert!(!short.is_heap_allocated());
+        assert_eq!("short", AsRef::<str>::as_ref(&short));
+
+        let long = "a string which is too long to be stored inline";
+        let long: CompactStringNE = CompactString::from(long).try_non_empty().unwrap();
+        assert!(long.is_heap_allocated());
+        assert_eq!(46, long.len());
+    }
+
+    #[test]
+    fn string_conversions() {
+        for s in &["short", "a string which is too long to be stored inline"] {
+            let string: StringNE = s.to_string().try_non_empty().unwrap();
+            let compact: CompactStringNE = string.into();
+            assert_eq!(s.len(), compact.len());
+
+            let string: StringNE = compact.clone().into();
+            assert_eq!(*s, *string);
+            let inner: CompactString = compact.into();
+            assert_eq!(*s, inner);
+        }
+    }
+}
@@ -0,0 +1,295 @@
+use std::collections::{BTreeMap, BinaryHeap, HashMap, LinkedList, VecDeque};
+use std::ffi::{OsStr, OsString};
+use std::hash::{BuildHasher, Hash};
+use std::path::{Path, PathBuf};
+
+use super::NonEmpty;
+
+/////////////////////////////////////////////////////////////////////////
+// Conversions between NonEmpty collections, no re-validation needed
+/////////////////////////////////////////////////////////////////////////
+
+impl<T> From<NonEmpty<Vec<T>>> for NonEmpty<VecDeque<T>> {
+    #[inline]
+    fn from(v: NonEmpty<Vec<T>>) -> Self {
+        NonEmpty { inner: VecDeque::from(v.inner) }
+    }
+}
+
+impl<T> From<NonEmpty<VecDeque<T>>> for NonEmpty<Vec<T>> {
+    #[inline]
+    fn from(v: NonEmpty<VecDeque<T>>) -> Self {
+        NonEmpty { inner: Vec::from(v.inner) }
+    }
+}
+
+impl<T> From<NonEmpty<Vec<T>>> for NonEmpty<LinkedList<T>> {
+    #[inline]
+    fn from(v: NonEmpty<Vec<T>>) -> Self {
+        NonEmpty { inner: v.inner.into_iter().collect() }
+    }
+}
+
+impl<T> From<NonEmpty<LinkedList<T>>> for NonEmpty<Vec<T>> {
+    #[inline]
+    fn from(v: NonEmpty<LinkedList<T>>) -> Self {
+        NonEmpty { inner: v.inner.into_iter().collect() }
+    }
+}
+
+impl<T: Ord> From<NonEmpty<Vec<T>>> for NonEmpty<BinaryHeap<T>> {
+    #[inline]
+    fn from(v: NonEmpty<Vec<T>>) -> Self {
+        NonEmpty { inner: BinaryHeap::from(v.inner) }
+    }
+}
+
+impl<T> From<NonEmpty<BinaryHeap<T>>> for NonEmpty<Vec<T>> {
+    /// The elements are in arbitrary order, like `BinaryHeap::into_vec`.
+    #[inline]
+    fn from(v: NonEmpty<BinaryHeap<T>>) -> Self {
+        NonEmpty { inner: v.inner.into_vec() }
+    }
+}
+
+/// Duplicate keys shrink the map, later values win,
+/// but at least one entry is always left.
+impl<K: Ord, V> From<NonEmpty<Vec<(K, V)>>> for NonEmpty<BTreeMap<K, V>> {
+    #[inline]
+    fn from(v: NonEmpty<Vec<(K, V)>>) -> Self {
+        NonEmpty { inner: v.inner.into_iter().collect() }
+    }
+}
+
+/// Duplicate keys shrink the map, later values win,
+/// but at least one entry is always left.
+impl<K, V, S> From<NonEmpty<Vec<(K, V)>>> for NonEmpty<HashMap<K, V, S>>
+    where K: Eq + Hash,
+          S: BuildHasher + Default,
+{
+    #[inline]
+    fn from(v: NonEmpty<Vec<(K, V)>>) -> Self {
+        NonEmpty { inner: v.inner.into_iter().collect() }
+    }
+}
+
+impl From<NonEmpty<String>> for NonEmpty<Box<str>> {
+    #[inline]
+    fn from(s: NonEmpty<String>) -> Self {
+        s.into_boxed_str_ne()
+    }
+}
+
+impl From<NonEmpty<Box<str>>> for NonEmpty<String> {
+    #[inline]
+    fn from(s: NonEmpty<Box<str>>) -> Self {
+        s.into_string_ne()
+    }
+}
+
+impl<T> From<NonEmpty<Vec<T>>> for NonEmpty<Box<[T]>> {
+    #[inline]
+    fn from(v: NonEmpty<Vec<T>>) -> Self {
+        v.into_boxed_slice_ne()
+    }
+}
+
+impl<T> From<NonEmpty<Box<[T]>>> for NonEmpty<Vec<T>> {
+    #[inline]
+    fn from(v: NonEmpty<Box<[T]>>) -> Self {
+        v.into_vec_ne()
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////
+// Conversions of NonEmpty strings and paths into other std types
+/////////////////////////////////////////////////////////////////////////
+
+impl From<NonEmpty<String>> for PathBuf {
+    #[inline]
+    fn from(s: NonEmpty<String>) -> Self {
+        PathBuf::from(s.inner)
+    }
+}
+
+impl From<NonEmpty<String>> for OsString {
+    #[inline]
+    fn from(s: NonEmpty<String>) -> Self {
+        OsString::from(s.inner)
+    }
+}
+
+impl From<NonEmpty<String>> for Vec<u8> {
+    #[inline]
+    fn from(s: NonEmpty<String>) -> Self {
+        s.inner.into_bytes()
+    }
+}
+
+impl From<NonEmpty<String>> for Box<str> {
+    #[inline]
+    fn from(s: NonEmpty<String>) -> Self {
+        s.inner.into_boxed_str()
+    }
+}
+
+impl From<NonEmpty<PathBuf>> for OsString {
+    #[inline]
+    fn from(p: NonEmpty<PathBuf>) -> Self {
+        p.inner.into_os_string()
+    }
+}
+
+impl<'a> From<NonEmpty<&'a str>> for String {
+    #[inline]
+    fn from(s: NonEmpty<&'a str>) -> Self {
+        s.inner.to_string()
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////
+// Conversions between borrowed and unsized NonEmpty values
+/////////////////////////////////////////////////////////////////////////
+
+impl<'a, T: ?Sized> From<NonEmpty<&'a T>> for &'a NonEmpty<T> {
+    #[inline]
+    fn from(v: NonEmpty<&'a T>) -> Self {
+        // safe, NonEmpty<T> is repr(transparent)
+        unsafe { &*(v.inner as *const T as *const NonEmpty<T>) }
+    }
+}
+
+impl<'a, T: ?Sized> From<&'a NonEmpty<T>> for NonEmpty<&'a T> {
+    #[inline]
+    fn from(v: &'a NonEmpty<T>) -> Self {
+        NonEmpty { inner: &v.inner }
+    }
+}
+
+impl<'a> From<&'a NonEmpty<str>> for NonEmpty<String> {
+    #[inline]
+    fn from(s: &'a NonEmpty<str>) -> Self {
+        NonEmpty { inner: s.inner.to_string() }
+    }
+}
+
+impl<'a> From<&'a NonEmpty<OsStr>> for NonEmpty<OsString> {
+    #[inline]
+    fn from(s: &'a NonEmpty<OsStr>) -> Self {
+        NonEmpty { inner: s.inner.to_os_string() }
+    }
+}
+
+impl<'a> From<&'a NonEmpty<Path>> for NonEmpty<PathBuf> {
+    #[inline]
+    fn from(p: &'a NonEmpty<Path>) -> Self {
+        NonEmpty { inner: p.inner.to_path_buf() }
+    }
+}
+
+impl<'a, T: Clone> From<&'a NonEmpty<[T]>> for NonEmpty<Vec<T>> {
+    #[inline]
+    fn from(v: &'a NonEmpty<[T]>) -> Self {
+        NonEmpty { inner: v.inner.to_vec() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, BinaryHeap, HashMap, LinkedList, VecDeque};
+    use std::ffi::OsString;
+    use std::path::{Path, PathBuf};
+
+    use super::super::{NonEmpty, StringNE, TryNonEmpty, VecNE, ne};
+
+    #[test]
+    fn vec_deque_round_trip() {
+        let v = ne(vec![1, 2, 3]);
+        let d: NonEmpty<VecDeque<i32>> = v.clone().into();
+        assert_eq!(VecDeque::from(vec![1, 2, 3]), *d);
+        assert_eq!(v, VecNE::from(d));
+    }
+
+    #[test]
+    fn linked_list_round_trip() {
+        let v = ne(vec![1, 2, 3]);
+        let l: NonEmpty<LinkedList<i32>> = v.clone().into();
+        assert_eq!(vec![1, 2, 3], l.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(v, VecNE::from(l));
+    }
+
+    #[test]
+    fn binary_heap() {
+        let h: NonEmpty<BinaryHeap<i32>> = ne(vec![3, 1, 2]).into();
+        assert_eq!(3, *h.peek());
+        let mut v = VecNE::from(h).into_inner();
+        v.sort();
+        assert_eq!(vec![1, 2, 3], v);
+    }
+
+    #[test]
+    fn maps_with_duplicate_keys() {
+        let m: NonEmpty<BTreeMap<&str, i32>> = ne(vec![("a", 1), ("a", 2)]).into();
+        assert_eq!(1, m.len());
+        assert_eq!(2, m["a"]);
+
+        let m: NonEmpty<HashMap<&str, i32>> = ne(vec![("a", 1), ("b", 2), ("a", 3)]).into();
+        assert_eq!(2, m.len());
+        assert_eq!(3, m["a"]);
+    }
+
+    #[test]
+    fn boxed_round_trips() {
+        let mut s = String::with_capacity(16);
+        s.push_str("héllo");
+        let s = ne(s);
+        let b: NonEmpty<Box<str>> = s.clone().into();
+        assert_eq!(s.len(), b.len());
+        assert_eq!(s.as_str(), &**b);
+        assert_eq!('h', b.first_char());
+        let s2: StringNE = b.into();
+        assert_eq!(s, s2);
+        assert_eq!(s2.len(), s2.capacity());
+
+        let v = ne(vec![3, 1, 2]);
+        let b: NonEmpty<Box<[i32]>> = v.clone().into();
+        assert_eq!(3, b.len());
+        assert_eq!(3, *b.max());
+        assert_eq!(&v[..], &b[..]);
+        assert_eq!(v, VecNE::from(b));
+
+        let b = ne(Box::<[i32]>::from(&[1][..]));
+        assert_eq!(vec![1], *b.into_vec_ne());
+        assert!(Box::<[i32]>::from(&[][..]).try_non_empty().is_none());
+        assert!(Box::<str>::from("").try_non_empty().is_none());
+    }
+
+    #[test]
+    fn string_into_std_types() {
+        let s = ne("dir/é".to_string());
+        assert_eq!(Path::new("dir/é"), PathBuf::from(s.clone()));
+        assert_eq!(OsString::from("dir/é"), OsString::from(s.clone()));
+        assert_eq!("dir/é".as_bytes(), &Vec::<u8>::from(s.clone())[..]);
+        let b: Box<str> = s.into();
+        assert_eq!("dir/é", &*b);
+
+        let s: String = ne("a").into();
+        assert_eq!("a", s);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn non_utf8_path_into_os_string() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let p = ne(PathBuf::from(OsStr::from_bytes(b"a\xff")));
+        assert_eq!(OsStr::from_bytes(b"a\xff"), OsString::from(p));
+    }
+
+    #[test]
+    fn path_into_os_string() {
+        let s: OsString = ne(PathBuf::from("a/b")).into();
+        assert_eq!(PathBuf::from("a/b").into_os_string(), s);
+    }
+}
@@ -0,0 +1,194 @@
+extern crate dashmap;
+
+use std::borrow::Borrow;
+use std::hash::{BuildHasher, Hash};
+
+use self::dashmap::{DashMap, DashSet};
+
+use super::{IsEmpty, NonEmpty, WouldBeEmpty};
+
+/// Non-empty `DashMap`.
+///
+/// `DashMap` mutates through `&self`, so methods reached through `Deref`
+/// like `clear` or `retain` can still empty the map. Only the methods of the
+/// wrapper are guarded, and their guard is best-effort, see its `remove` method.
+pub type DashMapNE<K, V> = NonEmpty<DashMap<K, V>>;
+
+/// Non-empty `DashSet`, see [DashMapNE](type.DashMapNE.html) for the caveats.
+pub type DashSetNE<T> = NonEmpty<DashSet<T>>;
+
+impl<K, V, S> IsEmpty for DashMap<K, V, S>
+    where K: Eq + Hash,
+          S: BuildHasher + Clone,
+{
+    #[inline]
+    fn is_empty(&self) -> bool {
+        DashMap::is_empty(self)
+    }
+}
+
+impl<T, S> IsEmpty for DashSet<T, S>
+    where T: Eq + Hash,
+          S: BuildHasher + Clone,
+{
+    #[inline]
+    fn is_empty(&self) -> bool {
+        DashSet::is_empty(self)
+    }
+}
+
+impl<K, V, S> From<NonEmpty<DashMap<K, V, S>>> for DashMap<K, V, S> {
+    #[inline]
+    fn from(v: NonEmpty<DashMap<K, V, S>>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl<T, S> From<NonEmpty<DashSet<T, S>>> for DashSet<T, S> {
+    #[inline]
+    fn from(v: NonEmpty<DashSet<T, S>>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl<K, V, S> NonEmpty<DashMap<K, V, S>>
+    where K: Eq + Hash,
+          S: BuildHasher + Clone,
+{
+    /// Inserts a key-value pair, like `DashMap::insert`.
+    ///
+    /// Returns the old value if the key was already present.
+    #[inline]
+    pub fn insert(&self, k: K, v: V) -> Option<V> {
+        self.inner.insert(k, v)
+    }
+
+    /// Removes a key from the map, like `DashMap::remove`.
+    ///
+    /// Returns `Err(WouldBeEmpty)` and leaves the map untouched
+    /// if `k` is the only key in the map.
+    ///
+    /// The guard is best-effort: the length check and the removal are not
+    /// atomic, so concurrent removals of different keys can still empty the map.
+    /// Keep one entry which is never removed, or serialize the removals,
+    /// if the map must stay non-empty under contention.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate dashmap;
+    /// extern crate non_empty;
+    ///
+    /// use dashmap::DashMap;
+    /// use non_empty::{TryNonEmpty, WouldBeEmpty};
+    ///
+    /// # fn main() {
+    /// let m = DashMap::new();
+    /// m.insert("system", 0);
+    /// let m = m.try_non_empty().unwrap();
+    ///
+    /// m.insert("user", 1);
+    /// assert_eq!(Ok(Some(("user", 1))), m.remove("user"));
+    /// assert_eq!(Err(WouldBeEmpty), m.remove("system"));
+    /// # }
+    /// ```
+    pub fn remove<Q>(&self, k: &Q) -> Result<Option<(K, V)>, WouldBeEmpty>
+        where K: Borrow<Q>,
+              Q: Hash + Eq + ?Sized,
+    {
+        if self.inner.len() == 1 && self.inner.contains_key(k) {
+            return Err(WouldBeEmpty)
+        }
+        Ok(self.inner.remove(k))
+    }
+}
+
+impl<T, S> NonEmpty<DashSet<T, S>>
+    where T: Eq + Hash,
+          S: BuildHasher + Clone,
+{
+    /// Adds an element, like `DashSet::insert`.
+    ///
+    /// Returns false if the element was already present.
+    #[inline]
+    pub fn insert(&self, value: T) -> bool {
+        self.inner.insert(value)
+    }
+
+    /// Removes an element, like `DashSet::remove`.
+    ///
+    /// Returns `Err(WouldBeEmpty)` and leaves the set untouched
+    /// if `value` is the only element in the set. The guard is best-effort,
+    /// like the one of `DashMapNE::remove`.
+    pub fn remove<Q>(&self, value: &Q) -> Result<Option<T>, WouldBeEmpty>
+        where T: Borrow<Q>,
+              Q: Hash + Eq + ?Sized,
+    {
+        if self.inner.len() == 1 && self.inner.contains(value) {
+            return Err(WouldBeEmpty)
+        }
+        Ok(self.inner.remove(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::thread;
+
+    use super::dashmap::{DashMap, DashSet};
+    use super::{DashMapNE, DashSetNE};
+    use super::super::{TryNonEmpty, WouldBeEmpty};
+
+    fn sessions() -> DashMapNE<String, u32> {
+        let m = DashMap::new();
+        m.insert("system".to_string(), 0);
+        m.try_non_empty().unwrap()
+    }
+
+    #[test]
+    fn empty_rejection() {
+        assert!(DashMap::<i32, i32>::new().try_non_empty().is_none());
+        assert!(DashSet::<i32>::new().try_non_empty().is_none());
+    }
+
+    #[test]
+    fn guarded_removal() {
+        let m = sessions();
+        assert_eq!(Ok(None), m.remove("x"));
+        assert_eq!(Err(WouldBeEmpty), m.remove("system"));
+        assert_eq!(None, m.insert("a".to_string(), 1));
+        assert_eq!(Ok(Some(("system".to_string(), 0))), m.remove("system"));
+        assert_eq!(Err(WouldBeEmpty), m.remove("a"));
+        assert_eq!(1, m.len());
+
+        let s = DashSet::new();
+        s.insert(1);
+        let s: DashSetNE<i32> = s.try_non_empty().unwrap();
+        assert!(s.insert(2));
+        assert_eq!(Ok(Some(1)), s.remove(&1));
+        assert_eq!(Err(WouldBeEmpty), s.remove(&2));
+        assert_eq!(Ok(None), s.remove(&3));
+    }
+
+    #[test]
+    fn concurrent_sessions() {
+        let m = Arc::new(sessions());
+        let workers: Vec<_> = (0..4).map(|t| {
+            let m = Arc::clone(&m);
+            thread::spawn(move || {
+                for i in 0..200 {
+                    let key = format!("{}-{}", t, i);
+                    m.insert(key.clone(), i);
+                    assert!(!m.is_empty());
+                    assert_eq!(Ok(Some((key.clone(), i))), m.remove(&key));
+                    assert!(m.contains_key("system"));
+                }
+            })
+        }).collect();
+        for w in workers {
+            w.join().unwrap();
+        }
+        let m = Arc::try_unwrap(m).unwrap();
+        assert!(m.into_inner().try_non_empty().is_some());
+    }
+}
@@ -0,0 +1,102 @@
+use std::ops::Deref;
+
+use super::{IsEmpty, NonEmpty};
+
+/// Adapter implementing `IsEmpty` for any `T: Default + PartialEq`,
+/// a value is empty if it equals `T::default()`.
+///
+/// A blanket `impl<T: Default + PartialEq> IsEmpty for T` would overlap with
+/// the impls of this crate, e.g. for `String` or `Vec<T>`, so the value is
+/// wrapped instead. Like `ZeroIsEmpty` of the `num-traits` feature the price
+/// is a second layer to reach the value.
+///
+/// # Examples
+/// ```
+/// use non_empty::{try_non_default, DefaultIsEmpty, TryNonEmpty};
+///
+/// #[derive(Clone, Default, PartialEq)]
+/// struct Retry {
+///     attempts: u32,
+///     backoff_ms: u32,
+/// }
+///
+/// assert!(DefaultIsEmpty(Retry::default()).try_non_empty().is_none());
+///
+/// let r = try_non_default(Retry { attempts: 3, backoff_ms: 0 }).unwrap();
+/// assert_eq!(3, r.attempts);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct DefaultIsEmpty<T>(pub T);
+
+impl<T> DefaultIsEmpty<T> {
+    /// Returns the wrapped value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Default + PartialEq> IsEmpty for DefaultIsEmpty<T> {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.0 == T::default()
+    }
+}
+
+impl<T> Deref for DefaultIsEmpty<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> From<T> for DefaultIsEmpty<T> {
+    #[inline]
+    fn from(v: T) -> Self {
+        DefaultIsEmpty(v)
+    }
+}
+
+/// Wraps a value if it doesn't equal `T::default()`, see
+/// [DefaultIsEmpty](struct.DefaultIsEmpty.html).
+///
+/// Unlike `try_non_empty` the value doesn't have to be `Clone`.
+pub fn try_non_default<T: Default + PartialEq>(value: T) -> Option<NonEmpty<DefaultIsEmpty<T>>> {
+    let v = DefaultIsEmpty(value);
+    if v.is_empty() {
+        return None
+    }
+    Some(NonEmpty { inner: v })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{try_non_default, DefaultIsEmpty};
+    use super::super::{NonEmpty, TryNonEmpty};
+
+    #[derive(Debug, Default, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[test]
+    fn default_is_empty() {
+        assert!(try_non_default(Point::default()).is_none());
+        assert!(try_non_default(Point { x: 0, y: 0 }).is_none());
+
+        let p: NonEmpty<DefaultIsEmpty<Point>> = try_non_default(Point { x: 0, y: 2 }).unwrap();
+        assert_eq!(2, p.y);
+        assert_eq!(Point { x: 0, y: 2 }, p.into_inner().into_inner());
+    }
+
+    #[test]
+    fn adapter() {
+        assert!(DefaultIsEmpty::from(0u8).try_non_empty().is_none());
+        assert!(DefaultIsEmpty(Some(0u8)).try_non_empty().is_some());
+        assert!(DefaultIsEmpty(None::<u8>).try_non_empty().is_none());
+    }
+}
@@ -0,0 +1,102 @@
+use std::borrow::{Cow, ToOwned};
+use std::ffi::{CString, OsString};
+use std::ops::Deref;
+use std::path::PathBuf;
+
+use super::NonEmpty;
+
+/// Marks `Deref` types whose target is never empty if the value isn't empty.
+///
+/// Enables [as_deref_ne](struct.NonEmpty.html#method.as_deref_ne).
+///
+/// # Safety
+/// If `is_empty()` is false for a value, it must be false for its deref target,
+/// otherwise `as_deref_ne` creates an empty `NonEmpty`.
+pub unsafe trait EmptyCoherentDeref: Deref {}
+
+unsafe impl EmptyCoherentDeref for String {}
+unsafe impl<T> EmptyCoherentDeref for Vec<T> {}
+unsafe impl EmptyCoherentDeref for PathBuf {}
+unsafe impl EmptyCoherentDeref for OsString {}
+unsafe impl EmptyCoherentDeref for CString {}
+unsafe impl<T: ?Sized> EmptyCoherentDeref for Box<T> {}
+unsafe impl<'a, B: ToOwned + ?Sized> EmptyCoherentDeref for Cow<'a, B> {}
+
+impl<T: EmptyCoherentDeref> NonEmpty<T> {
+    /// Borrows the deref target as `NonEmpty`, without re-validation.
+    ///
+    /// # Examples
+    /// ```
+    /// use non_empty::{NonEmpty, TryNonEmpty};
+    ///
+    /// let s = "hello".to_string().try_non_empty().unwrap();
+    /// let r: NonEmpty<&str> = s.as_deref_ne();
+    /// assert_eq!("hello", *r);
+    /// ```
+    #[inline]
+    pub fn as_deref_ne(&self) -> NonEmpty<&T::Target> {
+        NonEmpty { inner: &*self.inner }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::ops::Deref;
+    use std::path::{Path, PathBuf};
+
+    use super::EmptyCoherentDeref;
+    use super::super::{IsEmpty, NonEmpty, TryNonEmpty};
+
+    #[test]
+    fn std_pairs() {
+        let s = "a".to_string().try_non_empty().unwrap();
+        let r: NonEmpty<&str> = s.as_deref_ne();
+        assert_eq!("a", *r);
+
+        let v = vec![1, 2].try_non_empty().unwrap();
+        let r: NonEmpty<&[i32]> = v.as_deref_ne();
+        assert_eq!(2, *r.max());
+
+        let p = PathBuf::from("a").try_non_empty().unwrap();
+        let r: NonEmpty<&Path> = p.as_deref_ne();
+        assert_eq!(Path::new("a"), *r);
+
+        let b = Box::new(vec![1]).try_non_empty().unwrap();
+        let r: NonEmpty<&Vec<i32>> = b.as_deref_ne();
+        assert_eq!(vec![1], **r);
+    }
+
+    #[derive(Clone)]
+    struct Counted<'a> {
+        s: String,
+        checks: &'a Cell<u32>,
+    }
+
+    impl<'a> IsEmpty for Counted<'a> {
+        fn is_empty(&self) -> bool {
+            self.checks.set(self.checks.get() + 1);
+            self.s.is_empty()
+        }
+    }
+
+    impl<'a> Deref for Counted<'a> {
+        type Target = String;
+
+        fn deref(&self) -> &String {
+            &self.s
+        }
+    }
+
+    unsafe impl<'a> EmptyCoherentDeref for Counted<'a> {}
+
+    #[test]
+    fn no_revalidation() {
+        let checks = Cell::new(0);
+        let c = Counted { s: "a".to_string(), checks: &checks }.try_non_empty().unwrap();
+        assert_eq!(1, checks.get());
+        let r: NonEmpty<&String> = c.as_deref_ne();
+        assert_eq!("a", **r);
+        assert_eq!(1, checks.get());
+    }
+}
@@ -0,0 +1,101 @@
+extern crate either;
+
+use self::either::Either;
+
+use super::{IsEmpty, NonEmpty};
+
+/// Delegates to the active variant.
+impl<L: IsEmpty, R: IsEmpty> IsEmpty for Either<L, R> {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        match *self {
+            Either::Left(ref l) => l.is_empty(),
+            Either::Right(ref r) => r.is_empty(),
+        }
+    }
+}
+
+impl<L, R> From<NonEmpty<Either<L, R>>> for Either<L, R> {
+    #[inline]
+    fn from(v: NonEmpty<Either<L, R>>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl<L, R> NonEmpty<Either<L, R>> {
+    /// Moves the non-empty invariant into the active variant.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate either;
+    /// extern crate non_empty;
+    ///
+    /// use either::Either;
+    /// use non_empty::TryNonEmpty;
+    ///
+    /// # fn main() {
+    /// let e: Either<Vec<u8>, String> = Either::Left(vec![1]);
+    /// match e.try_non_empty().unwrap().factor_ne() {
+    ///     Either::Left(v) => assert_eq!(vec![1], *v),
+    ///     Either::Right(_) => unreachable!(),
+    /// }
+    /// # }
+    /// ```
+    pub fn factor_ne(self) -> Either<NonEmpty<L>, NonEmpty<R>> {
+        match self.inner {
+            Either::Left(inner) => Either::Left(NonEmpty { inner }),
+            Either::Right(inner) => Either::Right(NonEmpty { inner }),
+        }
+    }
+}
+
+/// The reverse of `factor_ne`, without re-validation.
+///
+/// Use `try_non_empty` on a plain `Either` to check the active variant.
+impl<L, R> From<Either<NonEmpty<L>, NonEmpty<R>>> for NonEmpty<Either<L, R>> {
+    fn from(e: Either<NonEmpty<L>, NonEmpty<R>>) -> Self {
+        let inner = match e {
+            Either::Left(l) => Either::Left(l.inner),
+            Either::Right(r) => Either::Right(r.inner),
+        };
+        NonEmpty { inner }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::either::Either;
+    use super::super::{IsEmpty, NonEmpty, TryNonEmpty};
+
+    type E = Either<Vec<i32>, String>;
+
+    #[test]
+    fn variants() {
+        assert!(E::Left(vec![]).is_empty());
+        assert!(E::Right(String::new()).is_empty());
+        assert!(!E::Left(vec![1]).is_empty());
+        assert!(!E::Right("a".to_string()).is_empty());
+
+        assert!(E::Left(vec![]).try_non_empty().is_none());
+        assert!(E::Right(String::new()).try_non_empty().is_none());
+    }
+
+    #[test]
+    fn factor_round_trip() {
+        let left: NonEmpty<E> = E::Left(vec![1, 2]).try_non_empty().unwrap();
+        let factored = left.clone().factor_ne();
+        assert_eq!(Some(2), factored.as_ref().left().map(|v| v.len()));
+        assert_eq!(left, NonEmpty::from(factored));
+
+        let right: NonEmpty<E> = E::Right("ab".to_string()).try_non_empty().unwrap();
+        match right.clone().factor_ne() {
+            Either::Right(s) => {
+                assert_eq!("ab", *s);
+                assert_eq!(right, NonEmpty::from(Either::<NonEmpty<Vec<i32>>, _>::Right(s)));
+            }
+            Either::Left(_) => panic!("expected the right variant"),
+        }
+        let inner: E = right.into();
+        assert_eq!(E::Right("ab".to_string()), inner);
+    }
+}
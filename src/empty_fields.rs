@@ -0,0 +1,220 @@
+use std::borrow::Cow;
+
+use super::{IsEmpty, NonEmpty};
+
+/// EmptyFields reports the names of all empty fields of a struct.
+///
+/// Implement it with the [empty_fields!](macro.empty_fields.html) macro.
+/// Nested structs implementing `EmptyFields` report their fields with
+/// a dotted path like `"server.host"`.
+pub trait EmptyFields {
+    /// Names of the empty fields in declaration order, empty if all fields are non-empty.
+    fn empty_fields(&self) -> Vec<Cow<'static, str>>;
+}
+
+/// Implements [EmptyFields](trait.EmptyFields.html) for a struct.
+///
+/// List the fields to check, fields marked with `: nested` must implement
+/// `EmptyFields` themselves, all other fields must implement `IsEmpty`.
+///
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate non_empty;
+///
+/// use non_empty::EmptyFields;
+///
+/// struct Server {
+///     host: String,
+///     port: u16,
+/// }
+///
+/// struct Config {
+///     name: String,
+///     server: Server,
+/// }
+///
+/// empty_fields!(Server { host, port });
+/// empty_fields!(Config { name, server: nested });
+///
+/// # fn main() {
+/// let c = Config {
+///     name: "".to_string(),
+///     server: Server { host: "".to_string(), port: 80 },
+/// };
+/// assert_eq!(vec!["name", "server.host"], c.empty_fields());
+/// # }
+/// ```
+#[macro_export]
+macro_rules! empty_fields {
+    (@field $v:ident, $e:expr, $name:expr) => {
+        if $crate::IsEmpty::is_empty(&$e) {
+            $v.push(::std::borrow::Cow::Borrowed($name));
+        }
+    };
+    (@field $v:ident, $e:expr, $name:expr, nested) => {
+        for f in $crate::EmptyFields::empty_fields(&$e) {
+            $v.push(::std::borrow::Cow::Owned(format!("{}.{}", $name, f)));
+        }
+    };
+    ($t:ty { $($field:ident $(: $kind:ident)*),* $(,)* }) => {
+        impl $crate::EmptyFields for $t {
+            fn empty_fields(&self) -> ::std::vec::Vec<::std::borrow::Cow<'static, str>> {
+                #[allow(unused_mut)]
+                let mut v = ::std::vec::Vec::new();
+                $( $crate::empty_fields!(@field v, self.$field, stringify!($field) $(, $kind)*); )*
+                v
+            }
+        }
+    };
+}
+
+/// Checks labeled values and wraps them, collecting the labels of all empty values.
+///
+/// Evaluates to `Result<(NonEmpty<A>, NonEmpty<B>, ...), Vec<&'static str>>`,
+/// the labels are in argument order. Supports up to 8 values.
+///
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate non_empty;
+///
+/// # fn main() {
+/// let (name, email, tags) = check_fields!(
+///     "name" => "bob",
+///     "email" => "bob@example.com",
+///     "tags" => vec!["admin"],
+/// ).unwrap();
+/// assert_eq!(vec!["admin"], *tags);
+///
+/// let errors = check_fields!("name" => "", "email" => "", "tags" => vec!["admin"]);
+/// assert_eq!(Err(vec!["name", "email"]), errors.map(|_| ()));
+/// # let _ = (name, email);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! check_fields {
+    ($($label:expr => $value:expr),+ $(,)*) => {{
+        let mut empty: ::std::vec::Vec<&'static str> = ::std::vec::Vec::new();
+        let fields = ($( $crate::check_field(&mut empty, $label, $value), )+);
+        if empty.is_empty() {
+            Ok($crate::UnwrapFields::unwrap_fields(fields))
+        } else {
+            Err(empty)
+        }
+    }};
+}
+
+#[doc(hidden)]
+pub fn check_field<T: IsEmpty>(empty: &mut Vec<&'static str>, label: &'static str, value: T)
+    -> Option<NonEmpty<T>>
+{
+    if value.is_empty() {
+        empty.push(label);
+        return None
+    }
+    Some(NonEmpty { inner: value })
+}
+
+#[doc(hidden)]
+pub trait UnwrapFields {
+    type Output;
+
+    fn unwrap_fields(self) -> Self::Output;
+}
+
+macro_rules! unwrap_fields_impls {
+    ($(($($t:ident $i:tt),+))*) => ($(
+        impl<$($t),+> UnwrapFields for ($(Option<NonEmpty<$t>>,)+) {
+            type Output = ($(NonEmpty<$t>,)+);
+
+            fn unwrap_fields(self) -> Self::Output {
+                ($(self.$i.expect("check_fields! checked all fields"),)+)
+            }
+        }
+    )*)
+}
+
+unwrap_fields_impls! {
+    (A 0)
+    (A 0, B 1)
+    (A 0, B 1, C 2)
+    (A 0, B 1, C 2, D 3)
+    (A 0, B 1, C 2, D 3, E 4)
+    (A 0, B 1, C 2, D 3, E 4, F 5)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EmptyFields;
+
+    struct Server {
+        host: String,
+        port: u16,
+    }
+
+    struct Tls {
+        cert: String,
+    }
+
+    struct Config {
+        name: &'static str,
+        tags: Vec<String>,
+        server: Server,
+        tls: Tls,
+    }
+
+    empty_fields!(Server { host, port });
+    empty_fields!(Tls { cert });
+    empty_fields!(Config { name, tags, server: nested, tls: nested });
+
+    fn server() -> Server {
+        Server { host: "localhost".to_string(), port: 80 }
+    }
+
+    #[test]
+    fn flat() {
+        assert!(server().empty_fields().is_empty());
+        let s = Server { host: String::new(), port: 0 };
+        assert_eq!(vec!["host", "port"], s.empty_fields());
+    }
+
+    #[test]
+    fn nested() {
+        let c = Config {
+            name: "",
+            tags: vec!["a".to_string()],
+            server: Server { host: String::new(), ..server() },
+            tls: Tls { cert: String::new() },
+        };
+        assert_eq!(vec!["name", "server.host", "tls.cert"], c.empty_fields());
+    }
+
+    #[test]
+    fn all_valid() {
+        let c = Config {
+            name: "app",
+            tags: vec!["a".to_string()],
+            server: server(),
+            tls: Tls { cert: "pem".to_string() },
+        };
+        assert!(c.empty_fields().is_empty());
+    }
+
+    #[test]
+    fn check_fields() {
+        let (name, age) = check_fields!("name" => "bob".to_string(), "age" => 42).unwrap();
+        assert_eq!(("bob", 42), (name.as_str(), *age));
+
+        let r = check_fields!("name" => "bob", "age" => 0, "tags" => vec![1]);
+        assert_eq!(Err(vec!["age"]), r.map(|_| ()));
+
+        let r = check_fields!("c" => "", "a" => "a", "b" => Vec::<u8>::new(), "d" => 0.0);
+        assert_eq!(Err(vec!["c", "b", "d"]), r.map(|_| ()));
+
+        let (one,) = check_fields!("one" => 1u8).unwrap();
+        assert_eq!(1, *one);
+    }
+}
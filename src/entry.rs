@@ -0,0 +1,129 @@
+use std::collections::{btree_map, hash_map};
+use std::hash::Hash;
+
+use super::WouldBeEmpty;
+
+// The std entry types are wrapped, because `OccupiedEntry::remove`
+// could remove the only entry of a `NonEmpty` map. `VacantEntry` can
+// only insert and is returned as is.
+macro_rules! entry_types {
+    ($entry:ident, $occupied:ident, $map:ident, $name:expr, [$($bound:tt)+]) => {
+        #[doc = concat!("Entry of a non-empty `", $name, "`, like `", stringify!($map), "::Entry`.")]
+        ///
+        /// Removing through the entry is guarded and fails for the only entry.
+        pub enum $entry<'a, K: 'a, V: 'a> {
+            /// An occupied entry.
+            Occupied($occupied<'a, K, V>),
+            /// A vacant entry.
+            Vacant($map::VacantEntry<'a, K, V>),
+        }
+
+        #[doc = concat!("Occupied entry of a non-empty `", $name, "`.")]
+        pub struct $occupied<'a, K: 'a, V: 'a> {
+            inner: $map::OccupiedEntry<'a, K, V>,
+            is_only_entry: bool,
+        }
+
+        impl<'a, K: $($bound)+, V> $entry<'a, K, V> {
+            pub(crate) fn new(entry: $map::Entry<'a, K, V>, len: usize) -> Self {
+                match entry {
+                    $map::Entry::Occupied(inner) => {
+                        $entry::Occupied($occupied { inner, is_only_entry: len == 1 })
+                    }
+                    $map::Entry::Vacant(v) => $entry::Vacant(v),
+                }
+            }
+
+            /// Returns the key of the entry.
+            pub fn key(&self) -> &K {
+                match *self {
+                    $entry::Occupied(ref e) => e.key(),
+                    $entry::Vacant(ref e) => e.key(),
+                }
+            }
+
+            /// Inserts `default` if the entry is vacant and returns the value.
+            pub fn or_insert(self, default: V) -> &'a mut V {
+                match self {
+                    $entry::Occupied(e) => e.into_mut(),
+                    $entry::Vacant(e) => e.insert(default),
+                }
+            }
+
+            /// Inserts the result of `default` if the entry is vacant and returns the value.
+            pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+                match self {
+                    $entry::Occupied(e) => e.into_mut(),
+                    $entry::Vacant(e) => e.insert(default()),
+                }
+            }
+
+            /// Inserts the default value if the entry is vacant and returns the value.
+            pub fn or_default(self) -> &'a mut V
+                where V: Default,
+            {
+                self.or_insert_with(V::default)
+            }
+
+            /// Modifies the value if the entry is occupied.
+            pub fn and_modify<F: FnOnce(&mut V)>(self, f: F) -> Self {
+                match self {
+                    $entry::Occupied(mut e) => {
+                        f(e.get_mut());
+                        $entry::Occupied(e)
+                    }
+                    $entry::Vacant(e) => $entry::Vacant(e),
+                }
+            }
+        }
+
+        impl<'a, K: $($bound)+, V> $occupied<'a, K, V> {
+            /// Returns the key of the entry.
+            pub fn key(&self) -> &K {
+                self.inner.key()
+            }
+
+            /// Returns the value of the entry.
+            pub fn get(&self) -> &V {
+                self.inner.get()
+            }
+
+            /// Returns the value of the entry mutably.
+            pub fn get_mut(&mut self) -> &mut V {
+                self.inner.get_mut()
+            }
+
+            /// Converts the entry into a mutable reference to its value.
+            pub fn into_mut(self) -> &'a mut V {
+                self.inner.into_mut()
+            }
+
+            /// Replaces the value and returns the old one.
+            pub fn insert(&mut self, value: V) -> V {
+                self.inner.insert(value)
+            }
+
+            /// Removes the entry and returns its value.
+            ///
+            /// Returns `Err(WouldBeEmpty)` and leaves the map untouched
+            /// if it's the only entry.
+            pub fn remove(self) -> Result<V, WouldBeEmpty> {
+                self.remove_entry().map(|(_, v)| v)
+            }
+
+            /// Removes the entry and returns its key and value.
+            ///
+            /// Returns `Err(WouldBeEmpty)` and leaves the map untouched
+            /// if it's the only entry.
+            pub fn remove_entry(self) -> Result<(K, V), WouldBeEmpty> {
+                if self.is_only_entry {
+                    return Err(WouldBeEmpty)
+                }
+                Ok(self.inner.remove_entry())
+            }
+        }
+    };
+}
+
+entry_types!(HashMapEntry, HashMapOccupiedEntry, hash_map, "HashMap", [Eq + Hash]);
+entry_types!(BTreeMapEntry, BTreeMapOccupiedEntry, btree_map, "BTreeMap", [Ord]);
@@ -0,0 +1,150 @@
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+
+/// Error returned by operations which refuse to leave a
+/// [NonEmpty](struct.NonEmpty.html) value empty.
+///
+/// The value is left untouched when this error is returned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WouldBeEmpty;
+
+impl fmt::Display for WouldBeEmpty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("operation would leave the value empty")
+    }
+}
+
+impl Error for WouldBeEmpty {}
+
+/// Error for an empty value, optionally carrying a label naming the value.
+///
+/// Returned by [non_empty_labeled](trait.TryNonEmpty.html#method.non_empty_labeled).
+///
+/// # Examples
+/// ```
+/// use non_empty::TryNonEmpty;
+///
+/// let e = "".non_empty_labeled("user.name").unwrap_err();
+/// assert_eq!(Some("user.name"), e.label());
+/// assert_eq!("value 'user.name' must not be empty", e.to_string());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct EmptyError {
+    label: Option<Cow<'static, str>>,
+}
+
+impl EmptyError {
+    /// Error without a label.
+    pub fn new() -> Self {
+        EmptyError { label: None }
+    }
+
+    /// Error labeled with the name of the empty value, e.g. `"user.name"`.
+    pub fn labeled<L: Into<Cow<'static, str>>>(label: L) -> Self {
+        EmptyError { label: Some(label.into()) }
+    }
+
+    /// The label of the empty value, if any.
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+}
+
+impl fmt::Display for EmptyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.label {
+            Some(ref label) => write!(f, "value '{}' must not be empty", label),
+            None => f.write_str("value must not be empty"),
+        }
+    }
+}
+
+impl Error for EmptyError {}
+
+/// Error returned by checked arithmetic on non-empty numbers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ArithmeticError {
+    /// The result overflowed the number type.
+    Overflow,
+    /// The result is zero, which is empty.
+    Zero,
+}
+
+impl fmt::Display for ArithmeticError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ArithmeticError::Overflow => f.write_str("arithmetic operation overflowed"),
+            ArithmeticError::Zero => f.write_str("arithmetic operation resulted in zero"),
+        }
+    }
+}
+
+impl Error for ArithmeticError {}
+
+/// Error returned by [parse_ne](struct.NonEmpty.html#method.parse_ne).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseNeError<E> {
+    /// The string couldn't be parsed.
+    Parse(E),
+    /// The string was parsed to an empty value, e.g. `"0"` to a number.
+    Empty,
+}
+
+/// Doesn't repeat the message of the parse error, which is the `source`.
+impl<E> fmt::Display for ParseNeError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseNeError::Parse(_) => f.write_str("failed to parse value"),
+            ParseNeError::Empty => f.write_str("parsed value is empty"),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for ParseNeError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ParseNeError::Parse(ref e) => Some(e),
+            ParseNeError::Empty => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use super::EmptyError;
+    use super::super::{NonEmpty, TryNonEmpty};
+
+    #[test]
+    fn empty_error_display() {
+        assert_eq!("value must not be empty", EmptyError::new().to_string());
+        assert_eq!(None, EmptyError::default().label());
+
+        let e = EmptyError::labeled(format!("users[{}].name", 3));
+        assert_eq!(Some("users[3].name"), e.label());
+        assert_eq!("value 'users[3].name' must not be empty", e.to_string());
+    }
+
+    fn name(s: &str) -> Result<NonEmpty<&str>, Box<dyn Error>> {
+        Ok(s.non_empty_labeled("user.name")?)
+    }
+
+    fn email(s: &str) -> anyhow::Result<NonEmpty<&str>> {
+        Ok(s.non_empty_labeled("user.email")?)
+    }
+
+    #[test]
+    fn non_empty_labeled() {
+        assert_eq!("bob", *name("bob").unwrap());
+        let e = name("").unwrap_err();
+        assert_eq!("value 'user.name' must not be empty", e.to_string());
+        assert_eq!(Some("user.name"), e.downcast_ref::<EmptyError>().unwrap().label());
+
+        assert!(email("bob@example.com").is_ok());
+        let e = email("").unwrap_err().context("invalid sign up");
+        assert_eq!("invalid sign up", e.to_string());
+        assert_eq!("value 'user.email' must not be empty", e.root_cause().to_string());
+    }
+}
@@ -0,0 +1,154 @@
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+use std::hash::{BuildHasher, Hash};
+
+use super::NonEmpty;
+
+/////////////////////////////////////////////////////////////////////////
+// Extending a non-empty collection keeps it non-empty
+/////////////////////////////////////////////////////////////////////////
+
+impl<T> Extend<T> for NonEmpty<Vec<T>> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.inner.extend(iter)
+    }
+}
+
+impl<T> Extend<T> for NonEmpty<VecDeque<T>> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.inner.extend(iter)
+    }
+}
+
+impl<T> Extend<T> for NonEmpty<LinkedList<T>> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.inner.extend(iter)
+    }
+}
+
+impl<T: Ord> Extend<T> for NonEmpty<BinaryHeap<T>> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.inner.extend(iter)
+    }
+}
+
+impl<K, V, S> Extend<(K, V)> for NonEmpty<HashMap<K, V, S>>
+    where K: Eq + Hash,
+          S: BuildHasher,
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.inner.extend(iter)
+    }
+}
+
+impl<K: Ord, V> Extend<(K, V)> for NonEmpty<BTreeMap<K, V>> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.inner.extend(iter)
+    }
+}
+
+impl<T, S> Extend<T> for NonEmpty<HashSet<T, S>>
+    where T: Eq + Hash,
+          S: BuildHasher,
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.inner.extend(iter)
+    }
+}
+
+impl<T: Ord> Extend<T> for NonEmpty<BTreeSet<T>> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.inner.extend(iter)
+    }
+}
+
+impl Extend<char> for NonEmpty<String> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        self.inner.extend(iter)
+    }
+}
+
+impl<'a> Extend<&'a str> for NonEmpty<String> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        self.inner.extend(iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+    use std::iter;
+
+    use super::super::ne;
+
+    #[test]
+    fn sequences() {
+        let mut v = ne(vec![1]);
+        v.extend(vec![2, 3]);
+        v.extend(iter::empty());
+        assert_eq!(vec![1, 2, 3], *v);
+
+        let mut v = ne(VecDeque::from(vec![1]));
+        v.extend(vec![2, 3]);
+        v.extend(iter::empty());
+        assert_eq!(VecDeque::from(vec![1, 2, 3]), *v);
+
+        let mut v = ne(iter::once(1).collect::<LinkedList<_>>());
+        v.extend(vec![2, 3]);
+        v.extend(iter::empty());
+        assert_eq!(vec![1, 2, 3], v.iter().cloned().collect::<Vec<_>>());
+
+        let mut v = ne(BinaryHeap::from(vec![2]));
+        v.extend(vec![1, 3]);
+        v.extend(iter::empty());
+        assert_eq!(vec![1, 2, 3], v.into_inner().into_sorted_vec());
+    }
+
+    #[test]
+    fn maps() {
+        let mut m = ne(iter::once(("a", 1)).collect::<HashMap<_, _>>());
+        m.extend(vec![("b", 2), ("a", 3)]);
+        m.extend(iter::empty());
+        assert_eq!(2, m.len());
+        assert_eq!(3, m["a"]);
+        assert_eq!(2, m["b"]);
+
+        let mut m = ne(iter::once(("a", 1)).collect::<BTreeMap<_, _>>());
+        m.extend(vec![("b", 2)]);
+        m.extend(iter::empty());
+        assert_eq!(vec![(&"a", &1), (&"b", &2)], m.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn sets() {
+        let mut s = ne(iter::once(1).collect::<HashSet<_>>());
+        s.extend(vec![1, 2]);
+        s.extend(iter::empty());
+        assert_eq!(2, s.len());
+        assert!(s.contains(&2));
+
+        let mut s = ne(iter::once(2).collect::<BTreeSet<_>>());
+        s.extend(vec![1, 3]);
+        s.extend(iter::empty());
+        assert_eq!(vec![1, 2, 3], s.iter().cloned().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn string() {
+        let mut s = ne("a".to_string());
+        s.extend(vec!['b', 'c']);
+        s.extend(vec!["de", "f"]);
+        s.extend(iter::empty::<char>());
+        s.extend(iter::empty::<&str>());
+        assert_eq!("abcdef", *s);
+    }
+}
@@ -0,0 +1,116 @@
+/// Creates a `NonEmpty<String>` like `format!`, panics if the result is empty.
+///
+/// A template with any literal text, like `"user-{}"`, can't produce an empty
+/// string, so the check is skipped. This is decided at compile time.
+///
+/// # Panics
+/// If the template has no literal text and all arguments format to `""`.
+///
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate non_empty;
+///
+/// use non_empty::StringNE;
+///
+/// # fn main() {
+/// let id = 7;
+/// let s: StringNE = format_ne!("user-{}", id);
+/// assert_eq!("user-7", *s);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! format_ne {
+    ($fmt:literal $($args:tt)*) => {
+        match $crate::try_format_ne!($fmt $($args)*) {
+            Some(s) => s,
+            None => panic!("format_ne! produced an empty string"),
+        }
+    };
+}
+
+/// Creates a `NonEmpty<String>` like `format!`, or `None` if the result is empty.
+///
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate non_empty;
+///
+/// # fn main() {
+/// let name = "";
+/// assert!(try_format_ne!("{}", name).is_none());
+/// assert_eq!("bob", *try_format_ne!("{}", "bob").unwrap());
+/// # }
+/// ```
+#[macro_export]
+macro_rules! try_format_ne {
+    ($fmt:literal $($args:tt)*) => {{
+        const HAS_LITERAL: bool = $crate::format_has_literal($fmt);
+        let s = format!($fmt $($args)*);
+        if HAS_LITERAL {
+            // safe, literal text is never empty
+            Some(unsafe { $crate::NonEmpty::new_unchecked(s) })
+        } else {
+            $crate::TryNonEmpty::try_non_empty(s)
+        }
+    }};
+}
+
+/// True if a format string has text besides `{...}` placeholders.
+#[doc(hidden)]
+pub const fn format_has_literal(fmt: &str) -> bool {
+    let b = fmt.as_bytes();
+    let mut i = 0;
+    while i < b.len() {
+        if b[i] == b'{' && i + 1 < b.len() && b[i + 1] != b'{' {
+            // skip the placeholder
+            while i < b.len() && b[i] != b'}' {
+                i += 1;
+            }
+            i += 1;
+        } else {
+            // literal text, including an escaped `{{` or `}}`
+            return true
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_has_literal;
+    use super::super::StringNE;
+
+    #[test]
+    fn has_literal() {
+        assert!(!format_has_literal(""));
+        assert!(!format_has_literal("{}"));
+        assert!(!format_has_literal("{}{name}{:>4}"));
+        assert!(format_has_literal("user-{}"));
+        assert!(format_has_literal("{}-"));
+        assert!(format_has_literal("{{}}"));
+    }
+
+    #[test]
+    fn literal_template() {
+        let s: StringNE = format_ne!("user-{}", "");
+        assert_eq!("user-", *s);
+        assert_eq!("{}", *format_ne!("{{}}"));
+        let id = 3;
+        assert_eq!("#3", *format_ne!("#{id}"));
+    }
+
+    #[test]
+    fn interpolation_only() {
+        assert_eq!("ab", *format_ne!("{}{}", "a", "b"));
+        assert_eq!("b", *try_format_ne!("{}{}", "", "b").unwrap());
+        assert!(try_format_ne!("{}{}", "", "").is_none());
+        assert!(try_format_ne!("{:.0}", "abc").is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "format_ne! produced an empty string")]
+    fn empty_panics() {
+        let _ = format_ne!("{}", "");
+    }
+}
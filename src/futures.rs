@@ -0,0 +1,174 @@
+extern crate futures_core;
+extern crate pin_project_lite;
+
+use std::future::Future;
+use std::iter;
+use std::mem;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+use self::futures_core::Stream;
+
+use super::{IsEmpty, NonEmpty};
+
+/// Extension trait with adapters for streams of possibly empty items.
+///
+/// Implemented for every `Stream`.
+pub trait NonEmptyStreamExt: Stream + Sized {
+    /// Skips empty items and wraps the others, the stream counterpart of
+    /// filtering with `try_non_empty`.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate futures;
+    /// extern crate non_empty;
+    ///
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    /// use non_empty::NonEmptyStreamExt;
+    ///
+    /// # fn main() {
+    /// let lines = stream::iter(vec!["a", "", "b"]).filter_non_empty();
+    /// let lines: Vec<&str> = block_on(lines.map(|s| *s).collect());
+    /// assert_eq!(vec!["a", "b"], lines);
+    /// # }
+    /// ```
+    fn filter_non_empty(self) -> FilterNonEmpty<Self>
+        where Self::Item: IsEmpty,
+    {
+        FilterNonEmpty { stream: self }
+    }
+
+    /// Collects the items into `C`, resolves to `None` if the collection is empty.
+    ///
+    /// The collection is empty if no item arrived, but also e.g. for a `String`
+    /// collected from empty `&str` items.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate futures;
+    /// extern crate non_empty;
+    ///
+    /// use futures::executor::block_on;
+    /// use futures::stream;
+    /// use non_empty::NonEmptyStreamExt;
+    ///
+    /// # fn main() {
+    /// let v = block_on(stream::iter(vec![1, 2]).try_collect_non_empty::<Vec<_>>());
+    /// assert_eq!(vec![1, 2], *v.unwrap());
+    ///
+    /// let v = block_on(stream::iter(Vec::<i32>::new()).try_collect_non_empty::<Vec<_>>());
+    /// assert!(v.is_none());
+    /// # }
+    /// ```
+    fn try_collect_non_empty<C>(self) -> TryCollectNonEmpty<Self, C>
+        where C: Default + Extend<Self::Item> + IsEmpty,
+    {
+        TryCollectNonEmpty { stream: self, collection: C::default() }
+    }
+}
+
+impl<S: Stream> NonEmptyStreamExt for S {}
+
+pin_project_lite::pin_project! {
+    /// Stream returned by [filter_non_empty](trait.NonEmptyStreamExt.html#method.filter_non_empty).
+    #[must_use = "streams do nothing unless polled"]
+    #[derive(Debug)]
+    pub struct FilterNonEmpty<S> {
+        #[pin]
+        stream: S,
+    }
+}
+
+impl<S> Stream for FilterNonEmpty<S>
+    where S: Stream,
+          S::Item: IsEmpty,
+{
+    type Item = NonEmpty<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut stream = self.project().stream;
+        loop {
+            match ready!(stream.as_mut().poll_next(cx)) {
+                Some(inner) if !inner.is_empty() => return Poll::Ready(Some(NonEmpty { inner })),
+                Some(_) => continue,
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.stream.size_hint().1)
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// Future returned by [try_collect_non_empty](trait.NonEmptyStreamExt.html#method.try_collect_non_empty).
+    #[must_use = "futures do nothing unless polled"]
+    #[derive(Debug)]
+    pub struct TryCollectNonEmpty<S, C> {
+        #[pin]
+        stream: S,
+        collection: C,
+    }
+}
+
+impl<S, C> Future for TryCollectNonEmpty<S, C>
+    where S: Stream,
+          C: Default + Extend<S::Item> + IsEmpty,
+{
+    type Output = Option<NonEmpty<C>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => this.collection.extend(iter::once(item)),
+                None => {
+                    let inner = mem::take(this.collection);
+                    if inner.is_empty() {
+                        return Poll::Ready(None)
+                    }
+                    return Poll::Ready(Some(NonEmpty { inner }))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate futures;
+
+    use self::futures::executor::block_on;
+    use self::futures::stream::{self, Stream, StreamExt};
+    use super::NonEmptyStreamExt;
+    use super::super::NonEmpty;
+
+    #[test]
+    fn filter_non_empty() {
+        let s = stream::iter(vec!["", "a", "", "", "bc", ""]).filter_non_empty();
+        assert_eq!((0, Some(6)), s.size_hint());
+        let v: Vec<NonEmpty<&str>> = block_on(s.collect());
+        assert_eq!(vec!["a", "bc"], v.into_iter().map(NonEmpty::into_inner).collect::<Vec<_>>());
+
+        let s = stream::iter(vec![String::new(), String::new()]).filter_non_empty();
+        assert_eq!(0, block_on(s.count()));
+        assert_eq!(0, block_on(stream::iter(Vec::<Vec<u8>>::new()).filter_non_empty().count()));
+    }
+
+    #[test]
+    fn try_collect_non_empty() {
+        let v = block_on(stream::iter(vec![3, 0, 1]).try_collect_non_empty::<Vec<_>>());
+        assert_eq!(vec![3, 0, 1], *v.unwrap());
+        assert!(block_on(stream::iter(Vec::<u8>::new()).try_collect_non_empty::<Vec<_>>()).is_none());
+
+        let s = block_on(stream::iter(vec!["a", "", "b"]).try_collect_non_empty::<String>());
+        assert_eq!("ab", *s.unwrap());
+        let s = block_on(stream::iter(vec!["", ""]).try_collect_non_empty::<String>());
+        assert!(s.is_none());
+
+        let words = stream::iter(vec!["", "x", ""]).filter_non_empty().map(NonEmpty::into_inner);
+        assert_eq!("x", *block_on(words.try_collect_non_empty::<String>()).unwrap());
+    }
+}
@@ -0,0 +1,60 @@
+extern crate garde;
+
+use self::garde::rules::length::{HasBytes, HasChars, HasSimpleLength};
+
+use super::NonEmpty;
+
+/// Lets `#[garde(length(...))]` check a `NonEmpty` field like its inner value,
+/// e.g. `#[garde(length(max = 20))] name: StringNE`.
+///
+/// There's no need for `min = 1`, a `NonEmpty` value is never empty.
+impl<T: HasSimpleLength> HasSimpleLength for NonEmpty<T> {
+    #[inline]
+    fn length(&self) -> usize {
+        self.inner.length()
+    }
+}
+
+/// Used by `#[garde(length(bytes, ...))]`.
+impl<T: HasBytes> HasBytes for NonEmpty<T> {
+    #[inline]
+    fn num_bytes(&self) -> usize {
+        self.inner.num_bytes()
+    }
+}
+
+/// Used by `#[garde(length(chars, ...))]`.
+impl<T: HasChars> HasChars for NonEmpty<T> {
+    #[inline]
+    fn num_chars(&self) -> usize {
+        self.inner.num_chars()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::garde::rules::length::{bytes, chars, simple};
+    use super::super::{StringNE, TryNonEmpty, VecNE};
+
+    // the same calls `#[derive(garde::Validate)]` generates for the length rule
+
+    #[test]
+    fn simple_length() {
+        let name: StringNE = "bob".to_string().try_non_empty().unwrap();
+        assert!(simple::apply(&name, (0, 3)).is_ok());
+        assert!(simple::apply(&name, (0, 2)).is_err());
+
+        let tags: VecNE<u8> = vec![1].try_non_empty().unwrap();
+        assert!(simple::apply(&tags, (2, 3)).is_err());
+        assert!(simple::apply(&Some(tags), (1, 1)).is_ok());
+        assert!(simple::apply(&None::<VecNE<u8>>, (1, 1)).is_ok());
+    }
+
+    #[test]
+    fn bytes_and_chars() {
+        let name: StringNE = "éé".to_string().try_non_empty().unwrap();
+        assert!(simple::apply(&name, (0, 2)).is_err());
+        assert!(bytes::apply(&name, (4, 4)).is_ok());
+        assert!(chars::apply(&name, (2, 2)).is_ok());
+    }
+}
@@ -0,0 +1,246 @@
+use std::borrow::Borrow;
+use std::collections::hash_map::{HashMap, Keys, Values};
+use std::hash::{BuildHasher, Hash};
+
+use super::{HashMapEntry, NonEmpty, NonEmptyIter, WouldBeEmpty};
+
+impl<K, V, S> NonEmpty<HashMap<K, V, S>> {
+    /// Returns an arbitrary entry of the map.
+    ///
+    /// Which entry is returned is not specified and may differ between
+    /// maps with the same content.
+    pub fn any_entry(&self) -> (&K, &V) {
+        self.inner.iter().next().expect("NonEmpty is never empty")
+    }
+
+    /// Returns an iterator over the keys, guaranteed to yield at least one item.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::HashMap;
+    /// use non_empty::TryNonEmpty;
+    ///
+    /// let mut m = HashMap::new();
+    /// m.insert("a", 1);
+    /// m.insert("b", 2);
+    /// let m = m.try_non_empty().unwrap();
+    ///
+    /// assert_eq!("b", *m.keys_ne().max());
+    /// assert_eq!(1, *m.values_ne().min());
+    /// ```
+    pub fn keys_ne(&self) -> NonEmptyIter<Keys<'_, K, V>> {
+        NonEmptyIter::new_unchecked(self.inner.keys())
+    }
+
+    /// Returns an iterator over the values, guaranteed to yield at least one item.
+    pub fn values_ne(&self) -> NonEmptyIter<Values<'_, K, V>> {
+        NonEmptyIter::new_unchecked(self.inner.values())
+    }
+
+    /// Consumes the map and returns its keys in arbitrary order.
+    pub fn into_keys_ne(self) -> NonEmpty<Vec<K>> {
+        NonEmpty { inner: self.inner.into_keys().collect() }
+    }
+
+    /// Consumes the map and returns its values in arbitrary order.
+    pub fn into_values_ne(self) -> NonEmpty<Vec<V>> {
+        NonEmpty { inner: self.inner.into_values().collect() }
+    }
+}
+
+impl<K, V, S> NonEmpty<HashMap<K, V, S>>
+    where K: Eq + Hash,
+          S: BuildHasher,
+{
+    capacity_methods!();
+
+    /// Inserts a key-value pair, like `HashMap::insert`.
+    ///
+    /// Returns the old value if the key was already present.
+    #[inline]
+    pub fn insert(&mut self, k: K, v: V) -> Option<V> {
+        self.inner.insert(k, v)
+    }
+
+    /// Gets the entry of a key, like `HashMap::entry`.
+    ///
+    /// Removing the only entry through the returned entry fails with `WouldBeEmpty`.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::HashMap;
+    /// use non_empty::{HashMapEntry, TryNonEmpty, WouldBeEmpty};
+    ///
+    /// let mut m = HashMap::new();
+    /// m.insert("a", 1);
+    /// let mut m = m.try_non_empty().unwrap();
+    ///
+    /// *m.entry("a").or_insert(0) += 1;
+    /// assert_eq!(Some(&2), m.get("a"));
+    ///
+    /// match m.entry("a") {
+    ///     HashMapEntry::Occupied(e) => assert_eq!(Err(WouldBeEmpty), e.remove()),
+    ///     HashMapEntry::Vacant(_) => unreachable!(),
+    /// }
+    /// ```
+    pub fn entry(&mut self, key: K) -> HashMapEntry<'_, K, V> {
+        let len = self.inner.len();
+        HashMapEntry::new(self.inner.entry(key), len)
+    }
+
+    /// Removes a key from the map, like `HashMap::remove`.
+    ///
+    /// Returns `Err(WouldBeEmpty)` and leaves the map untouched
+    /// if `k` is the only key in the map.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::HashMap;
+    /// use non_empty::{TryNonEmpty, WouldBeEmpty};
+    ///
+    /// let mut m = HashMap::new();
+    /// m.insert("a", 1);
+    /// let mut m = m.try_non_empty().unwrap();
+    ///
+    /// assert_eq!(Err(WouldBeEmpty), m.remove("a"));
+    /// m.insert("b", 2);
+    /// assert_eq!(Ok(Some(1)), m.remove("a"));
+    /// ```
+    pub fn remove<Q>(&mut self, k: &Q) -> Result<Option<V>, WouldBeEmpty>
+        where K: Borrow<Q>,
+              Q: Hash + Eq + ?Sized,
+    {
+        if self.inner.len() == 1 && self.inner.contains_key(k) {
+            return Err(WouldBeEmpty)
+        }
+        Ok(self.inner.remove(k))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::super::{HashMapEntry, HashMapNE, TryNonEmpty, WouldBeEmpty};
+
+    fn one() -> HashMapNE<String, i32> {
+        let mut m = HashMap::new();
+        m.insert("a".to_string(), 1);
+        m.try_non_empty().unwrap()
+    }
+
+    #[test]
+    fn insert() {
+        let mut m = one();
+        assert_eq!(Some(1), m.insert("a".to_string(), 2));
+        assert_eq!(None, m.insert("b".to_string(), 3));
+        assert_eq!(2, m["a"]);
+        assert_eq!(2, m.len());
+    }
+
+    #[test]
+    fn any_entry() {
+        let mut m = one();
+        assert_eq!((&"a".to_string(), &1), m.any_entry());
+
+        m.insert("b".to_string(), 2);
+        let (k, v) = m.any_entry();
+        assert_eq!(Some(v), m.get(k));
+    }
+
+    #[test]
+    fn keys_and_values_ne() {
+        let mut m = one();
+        m.insert("b".to_string(), 2);
+        m.insert("c".to_string(), 3);
+
+        let mut keys = m.keys_ne().into_iter().cloned().collect::<Vec<_>>();
+        keys.sort();
+        assert_eq!(vec!["a", "b", "c"], keys);
+        assert_eq!("c", m.keys_ne().max());
+
+        let mut values = m.values_ne().into_iter().cloned().collect::<Vec<_>>();
+        values.sort();
+        assert_eq!(vec![1, 2, 3], values);
+        assert_eq!(1, *m.values_ne().min());
+    }
+
+    #[test]
+    fn into_keys_and_values_ne() {
+        assert_eq!(vec!["a"], *one().into_keys_ne());
+        assert_eq!(vec![1], *one().into_values_ne());
+
+        let mut m = one();
+        m.insert("b".to_string(), 2);
+        let mut keys = m.clone().into_keys_ne().into_inner();
+        keys.sort();
+        assert_eq!(vec!["a", "b"], keys);
+        let mut values = m.into_values_ne().into_inner();
+        values.sort();
+        assert_eq!(vec![1, 2], values);
+    }
+
+    #[test]
+    fn remove() {
+        let mut m = one();
+        assert_eq!(Ok(None), m.remove("x"));
+        assert_eq!(Err(WouldBeEmpty), m.remove("a"));
+        assert_eq!(1, m["a"]);
+
+        m.insert("b".to_string(), 2);
+        assert_eq!(Ok(Some(1)), m.remove("a"));
+        assert_eq!(Err(WouldBeEmpty), m.remove("b"));
+        assert_eq!(1, m.len());
+    }
+
+    #[test]
+    fn capacity() {
+        let mut m: HashMapNE<i32, i32> = vec![(1, 1)].into_iter().collect::<HashMap<_, _>>().try_non_empty().unwrap();
+        m.reserve(100);
+        assert!(m.capacity() >= 101);
+        m.shrink_to(10);
+        assert!(m.capacity() >= 10);
+        m.shrink_to_fit();
+        assert!(m.capacity() < 101);
+        assert_eq!(Some(&1), m.get(&1));
+    }
+
+    #[test]
+    fn entry() {
+        let mut m = one();
+        *m.entry("a".to_string()).or_insert(0) += 10;
+        *m.entry("b".to_string()).or_default() += 2;
+        m.entry("c".to_string()).and_modify(|v| *v = 100).or_insert_with(|| 3);
+        m.entry("c".to_string()).and_modify(|v| *v += 1);
+        assert_eq!(Some(&11), m.get("a"));
+        assert_eq!(Some(&2), m.get("b"));
+        assert_eq!(Some(&4), m.get("c"));
+        assert_eq!("c", m.entry("c".to_string()).key());
+
+        if let HashMapEntry::Occupied(e) = m.entry("b".to_string()) {
+            assert_eq!(Ok(("b".to_string(), 2)), e.remove_entry());
+        }
+        if let HashMapEntry::Occupied(mut e) = m.entry("c".to_string()) {
+            assert_eq!(4, e.insert(5));
+            assert_eq!(Ok(5), e.remove());
+        }
+        assert_eq!(1, m.len());
+    }
+
+    #[test]
+    fn entry_guards_the_only_entry() {
+        let mut m = one();
+        match m.entry("a".to_string()) {
+            HashMapEntry::Occupied(mut e) => {
+                *e.get_mut() += 1;
+                assert_eq!(Err(WouldBeEmpty), e.remove());
+            }
+            HashMapEntry::Vacant(_) => panic!("entry is occupied"),
+        }
+        match m.entry("a".to_string()) {
+            HashMapEntry::Occupied(e) => assert_eq!(Err(WouldBeEmpty), e.remove_entry()),
+            HashMapEntry::Vacant(_) => panic!("entry is occupied"),
+        }
+        assert_eq!(Some(&2), m.get("a"));
+    }
+}
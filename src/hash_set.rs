@@ -0,0 +1,151 @@
+use std::borrow::Borrow;
+use std::collections::hash_set::{HashSet, Iter};
+use std::hash::{BuildHasher, Hash};
+
+use super::{NonEmpty, NonEmptyIter, WouldBeEmpty};
+
+impl<T, S> NonEmpty<HashSet<T, S>> {
+    /// Returns an arbitrary element of the set.
+    ///
+    /// Which element is returned is not specified and may differ between
+    /// sets with the same content.
+    pub fn any_element(&self) -> &T {
+        self.inner.iter().next().expect("NonEmpty is never empty")
+    }
+
+    /// Returns an iterator over the elements, guaranteed to yield at least one item.
+    pub fn iter_ne(&self) -> NonEmptyIter<Iter<'_, T>> {
+        NonEmptyIter::new_unchecked(self.inner.iter())
+    }
+}
+
+impl<T, S> NonEmpty<HashSet<T, S>>
+    where T: Eq + Hash,
+          S: BuildHasher,
+{
+    capacity_methods!();
+
+    /// Adds a value to the set, like `HashSet::insert`.
+    ///
+    /// Returns whether the value was newly inserted.
+    /// Use the `Extend` implementation to add many values at once.
+    #[inline]
+    pub fn insert(&mut self, value: T) -> bool {
+        self.inner.insert(value)
+    }
+
+    /// Removes a value from the set, like `HashSet::remove`.
+    ///
+    /// Returns `Err(WouldBeEmpty)` and leaves the set untouched
+    /// if `value` is the only element.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::HashSet;
+    /// use non_empty::{TryNonEmpty, WouldBeEmpty};
+    ///
+    /// let mut s = vec!["a"].into_iter().collect::<HashSet<_>>().try_non_empty().unwrap();
+    /// assert_eq!(Ok(false), s.remove("b"));
+    /// assert_eq!(Err(WouldBeEmpty), s.remove("a"));
+    /// ```
+    pub fn remove<Q>(&mut self, value: &Q) -> Result<bool, WouldBeEmpty>
+        where T: Borrow<Q>,
+              Q: Hash + Eq + ?Sized,
+    {
+        if self.inner.len() == 1 && self.inner.contains(value) {
+            return Err(WouldBeEmpty)
+        }
+        Ok(self.inner.remove(value))
+    }
+
+    /// Removes and returns the value equal to `value`, like `HashSet::take`.
+    ///
+    /// Returns `Err(WouldBeEmpty)` and leaves the set untouched
+    /// if `value` is the only element.
+    pub fn take<Q>(&mut self, value: &Q) -> Result<Option<T>, WouldBeEmpty>
+        where T: Borrow<Q>,
+              Q: Hash + Eq + ?Sized,
+    {
+        if self.inner.len() == 1 && self.inner.contains(value) {
+            return Err(WouldBeEmpty)
+        }
+        Ok(self.inner.take(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::collections::hash_map::RandomState;
+
+    use super::super::{HashSetNE, TryNonEmpty, WouldBeEmpty};
+
+    fn set(v: &[i32]) -> HashSetNE<i32, RandomState> {
+        v.iter().cloned().collect::<HashSet<_>>().try_non_empty().unwrap()
+    }
+
+    #[test]
+    fn any_element() {
+        assert_eq!(1, *set(&[1]).any_element());
+
+        let s = set(&[1, 2, 3]);
+        assert!(s.contains(s.any_element()));
+    }
+
+    #[test]
+    fn iter_ne() {
+        let s = set(&[3, 1, 2]);
+        assert_eq!(3, *s.iter_ne().max());
+        assert_eq!(1, *s.iter_ne().min());
+
+        let mut all = s.iter_ne().into_iter().cloned().collect::<Vec<_>>();
+        all.sort();
+        assert_eq!(vec![1, 2, 3], all);
+    }
+
+    #[test]
+    fn insert() {
+        let mut s = set(&[1]);
+        assert!(!s.insert(1));
+        assert!(s.insert(2));
+        s.extend(vec![3, 4]);
+        assert_eq!(4, s.len());
+    }
+
+    #[test]
+    fn remove() {
+        let mut s = set(&[1]);
+        assert_eq!(Ok(false), s.remove(&2));
+        assert_eq!(Err(WouldBeEmpty), s.remove(&1));
+        assert!(s.contains(&1));
+
+        let mut s = set(&[1, 2, 3]);
+        assert_eq!(Ok(true), s.remove(&1));
+        assert_eq!(Ok(true), s.remove(&2));
+        assert_eq!(Err(WouldBeEmpty), s.remove(&3));
+        assert_eq!(1, s.len());
+    }
+
+    #[test]
+    fn take() {
+        let mut s = set(&[1]);
+        assert_eq!(Ok(None), s.take(&2));
+        assert_eq!(Err(WouldBeEmpty), s.take(&1));
+
+        let mut s = set(&[1, 2]);
+        assert_eq!(Ok(Some(1)), s.take(&1));
+        assert_eq!(Err(WouldBeEmpty), s.take(&2));
+    }
+
+    #[test]
+    fn capacity() {
+        let mut s = set(&[1, 2]);
+        s.reserve(100);
+        assert!(s.capacity() >= 102);
+        s.shrink_to(10);
+        assert!(s.capacity() >= 10);
+        s.shrink_to_fit();
+        assert!(s.capacity() < 102);
+        assert_eq!(2, s.len());
+    }
+}
@@ -0,0 +1,134 @@
+extern crate heapless;
+
+use self::heapless::{String, Vec};
+
+use super::{IsEmpty, NonEmpty, WouldBeEmpty};
+
+/// Non-empty `heapless::Vec`.
+pub type HeaplessVecNE<T, const N: usize> = NonEmpty<Vec<T, N>>;
+
+/// Non-empty `heapless::String`.
+pub type HeaplessStringNE<const N: usize> = NonEmpty<String<N>>;
+
+impl<T, const N: usize> IsEmpty for Vec<T, N> {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        Vec::is_empty(self)
+    }
+}
+
+impl<const N: usize> IsEmpty for String<N> {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.as_str().is_empty()
+    }
+}
+
+impl<T, const N: usize> From<NonEmpty<Vec<T, N>>> for Vec<T, N> {
+    #[inline]
+    fn from(v: NonEmpty<Vec<T, N>>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl<const N: usize> From<NonEmpty<String<N>>> for String<N> {
+    #[inline]
+    fn from(v: NonEmpty<String<N>>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl<T, const N: usize> NonEmpty<Vec<T, N>> {
+    /// Appends an element, like `heapless::Vec::push`.
+    ///
+    /// Returns the element back if the vector is full.
+    #[inline]
+    pub fn push(&mut self, item: T) -> Result<(), T> {
+        self.inner.push(item)
+    }
+
+    /// Removes and returns the last element.
+    ///
+    /// Returns `Err(WouldBeEmpty)` and leaves the vector untouched
+    /// if it's the only element.
+    pub fn pop(&mut self) -> Result<T, WouldBeEmpty> {
+        if self.inner.len() == 1 {
+            return Err(WouldBeEmpty)
+        }
+        Ok(self.inner.pop().expect("NonEmpty is never empty"))
+    }
+}
+
+impl<const N: usize> NonEmpty<String<N>> {
+    /// Appends a char, like `heapless::String::push`.
+    ///
+    /// Returns an error if the string is full.
+    #[allow(clippy::result_unit_err)]
+    #[inline]
+    pub fn push(&mut self, c: char) -> Result<(), ()> {
+        self.inner.push(c)
+    }
+
+    /// Appends a string slice, like `heapless::String::push_str`.
+    ///
+    /// Returns an error and leaves the string untouched if the slice doesn't fit.
+    #[allow(clippy::result_unit_err)]
+    #[inline]
+    pub fn push_str(&mut self, s: &str) -> Result<(), ()> {
+        self.inner.push_str(s)
+    }
+
+    /// Removes and returns the last char.
+    ///
+    /// Returns `Err(WouldBeEmpty)` and leaves the string untouched
+    /// if it's the only char.
+    pub fn pop(&mut self) -> Result<char, WouldBeEmpty> {
+        if self.inner.chars().nth(1).is_none() {
+            return Err(WouldBeEmpty)
+        }
+        Ok(self.inner.pop().expect("NonEmpty is never empty"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::heapless::{String, Vec};
+    use super::{HeaplessStringNE, HeaplessVecNE};
+    use super::super::{TryNonEmpty, WouldBeEmpty};
+
+    #[test]
+    fn empty_rejection() {
+        assert!(Vec::<u8, 4>::new().try_non_empty().is_none());
+        assert!(String::<4>::new().try_non_empty().is_none());
+        assert!("a".parse::<String<4>>().unwrap().try_non_empty().is_some());
+    }
+
+    #[test]
+    fn vec_capacity() {
+        let mut v: HeaplessVecNE<u8, 2> = Vec::from_slice(&[1]).unwrap().try_non_empty().unwrap();
+        assert_eq!(Ok(()), v.push(2));
+        assert_eq!(Err(3), v.push(3));
+        assert_eq!(&[1, 2], &v[..]);
+
+        assert_eq!(Ok(2), v.pop());
+        assert_eq!(Err(WouldBeEmpty), v.pop());
+        let inner: Vec<u8, 2> = v.into();
+        assert_eq!(&[1], &inner[..]);
+    }
+
+    #[test]
+    fn string_capacity() {
+        let mut s: HeaplessStringNE<4> = "é".parse::<String<4>>().unwrap().try_non_empty().unwrap();
+        assert_eq!(Err(WouldBeEmpty), s.pop());
+        assert_eq!(Ok(()), s.push('a'));
+        assert_eq!(Err(()), s.push_str("bc"));
+        assert_eq!(Ok(()), s.push('x'));
+        assert_eq!(Err(()), s.push('y'));
+        assert_eq!("éax", &*s);
+
+        assert_eq!(Ok('x'), s.pop());
+        assert_eq!(Ok('a'), s.pop());
+        assert_eq!(Err(WouldBeEmpty), s.pop());
+        assert_eq!("é", &*s);
+    }
+}
@@ -1,18 +1,62 @@
-use super::{NonEmpty, TryNonEmpty};
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{BuildHasher, Hash};
+
+use super::{IsEmpty, NonEmpty, TryNonEmpty};
 
 /////////////////////////////////////////////////////////////////////////
 // Helper functions to convert multiple values at once to NonEmpty
 /////////////////////////////////////////////////////////////////////////
 
+/// TryNonEmptyAll converts all values of a tuple to `NonEmpty`'s at once.
+///
+/// Implemented for tuples with two up to six values.
+///
+/// # Examples
+/// ```
+/// use non_empty::TryNonEmptyAll;
+///
+/// let (a, b, c) = ("a", 1, vec![2]).try_all_non_empty().unwrap();
+/// assert_eq!(("a", 1, vec![2]), (*a, *b, c.into_inner()));
+/// assert!(("a", 0, vec![2]).try_all_non_empty().is_none());
+/// ```
+pub trait TryNonEmptyAll {
+    /// The tuple of `NonEmpty`'s.
+    type Output;
+
+    /// Wraps all values or fails if any of them is empty.
+    fn try_all_non_empty(self) -> Option<Self::Output>;
+}
+
+macro_rules! try_non_empty_all_impls {
+    ($(($($t:ident $i:tt),+))*) => ($(
+        impl<$($t: IsEmpty),+> TryNonEmptyAll for ($($t,)+) {
+            type Output = ($(NonEmpty<$t>,)+);
+
+            #[inline]
+            fn try_all_non_empty(self) -> Option<Self::Output> {
+                if $(self.$i.is_empty())||+ {
+                    return None
+                }
+                Some(($(NonEmpty { inner: self.$i },)+))
+            }
+        }
+    )*)
+}
+
+try_non_empty_all_impls! {
+    (A 0, B 1)
+    (A 0, B 1, C 2)
+    (A 0, B 1, C 2, D 3)
+    (A 0, B 1, C 2, D 3, E 4)
+    (A 0, B 1, C 2, D 3, E 4, F 5)
+}
+
 /// Convert two values to a tuple of `NonEmpty`'s or fail.
 pub fn try_non_empty2<A, B>(a: A, b: B) -> Option<(NonEmpty<A>, NonEmpty<B>)>
     where A: TryNonEmpty,
           B: TryNonEmpty,
 {
-    if let (Some(a), Some(b)) = (a.try_non_empty(), b.try_non_empty()) {
-        return Some((a, b))
-    }
-    return None
+    (a, b).try_all_non_empty()
 }
 
 /// Convert three values to a tuple of `NonEmpty`'s or fail.
@@ -22,13 +66,11 @@ pub fn try_non_empty3<A, B, C>(a: A, b: B, c: C)
           B: TryNonEmpty,
           C: TryNonEmpty,
 {
-    if let (Some(a), Some(b), Some(c)) = (a.try_non_empty(), b.try_non_empty(), c.try_non_empty()) {
-        return Some((a, b, c))
-    }
-    return None
+    (a, b, c).try_all_non_empty()
 }
 
 /// Convert four values to a tuple of `NonEmpty`'s or fail.
+#[allow(clippy::type_complexity)]
 pub fn try_non_empty4<A, B, C, D>(a: A, b: B, c: C, d: D)
     -> Option<(NonEmpty<A>, NonEmpty<B>, NonEmpty<C>, NonEmpty<D>)>
     where A: TryNonEmpty,
@@ -36,18 +78,11 @@ pub fn try_non_empty4<A, B, C, D>(a: A, b: B, c: C, d: D)
           C: TryNonEmpty,
           D: TryNonEmpty,
 {
-    if let (Some(a), Some(b), Some(c), Some(d)) = (
-        a.try_non_empty(),
-        b.try_non_empty(),
-        c.try_non_empty(),
-        d.try_non_empty(),
-    ) {
-        return Some((a, b, c, d))
-    }
-    return None
+    (a, b, c, d).try_all_non_empty()
 }
 
 /// Convert five values to a tuple of `NonEmpty`'s or fail.
+#[allow(clippy::type_complexity)]
 pub fn try_non_empty5<A, B, C, D, E>(a: A, b: B, c: C, d: D, e: E)
     -> Option<(NonEmpty<A>, NonEmpty<B>, NonEmpty<C>, NonEmpty<D>, NonEmpty<E>)>
     where A: TryNonEmpty,
@@ -56,19 +91,11 @@ pub fn try_non_empty5<A, B, C, D, E>(a: A, b: B, c: C, d: D, e: E)
           D: TryNonEmpty,
           E: TryNonEmpty,
 {
-    if let (Some(a), Some(b), Some(c), Some(d), Some(e)) = (
-        a.try_non_empty(),
-        b.try_non_empty(),
-        c.try_non_empty(),
-        d.try_non_empty(),
-        e.try_non_empty(),
-    ) {
-        return Some((a, b, c, d, e))
-    }
-    return None
+    (a, b, c, d, e).try_all_non_empty()
 }
 
 /// Convert six values to a tuple of `NonEmpty`'s or fail.
+#[allow(clippy::type_complexity)]
 pub fn try_non_empty6<A, B, C, D, E, F>(a: A, b: B, c: C, d: D, e: E, f: F)
     -> Option<(NonEmpty<A>, NonEmpty<B>, NonEmpty<C>, NonEmpty<D>, NonEmpty<E>, NonEmpty<F>)>
     where A: TryNonEmpty,
@@ -78,17 +105,55 @@ pub fn try_non_empty6<A, B, C, D, E, F>(a: A, b: B, c: C, d: D, e: E, f: F)
           E: TryNonEmpty,
           F: TryNonEmpty,
 {
-    if let (Some(a), Some(b), Some(c), Some(d), Some(e), Some(f)) = (
-        a.try_non_empty(),
-        b.try_non_empty(),
-        c.try_non_empty(),
-        d.try_non_empty(),
-        e.try_non_empty(),
-        f.try_non_empty(),
-    ) {
-        return Some((a, b, c, d, e, f))
-    }
-    return None
+    (a, b, c, d, e, f).try_all_non_empty()
+}
+
+macro_rules! try_non_empty_with_fns {
+    ($($(#[$m:meta])* fn $name:ident($($f:ident: $F:ident -> $t:ident),+);)*) => ($(
+        $(#[$m])*
+        pub fn $name<$($t, $F),+>($($f: $F),+) -> Option<($(NonEmpty<$t>,)+)>
+            where $($t: IsEmpty, $F: FnOnce() -> $t,)+
+        {
+            Some(($({
+                let inner = $f();
+                if inner.is_empty() {
+                    return None
+                }
+                NonEmpty { inner }
+            },)+))
+        }
+    )*)
+}
+
+try_non_empty_with_fns! {
+    /// Lazily convert two values to a tuple of `NonEmpty`'s or fail.
+    ///
+    /// The closures are called left to right, no further closure is
+    /// called once a value is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use non_empty::try_non_empty2_with;
+    ///
+    /// let (user, home) = try_non_empty2_with(|| "bob", || "/home/bob").unwrap();
+    /// assert_eq!(("bob", "/home/bob"), (*user, *home));
+    ///
+    /// let r = try_non_empty2_with(|| "", || -> &str { unreachable!() });
+    /// assert!(r.is_none());
+    /// ```
+    fn try_non_empty2_with(a: FA -> A, b: FB -> B);
+    /// Lazily convert three values to a tuple of `NonEmpty`'s or fail,
+    /// see [try_non_empty2_with](fn.try_non_empty2_with.html).
+    fn try_non_empty3_with(a: FA -> A, b: FB -> B, c: FC -> C);
+    /// Lazily convert four values to a tuple of `NonEmpty`'s or fail,
+    /// see [try_non_empty2_with](fn.try_non_empty2_with.html).
+    fn try_non_empty4_with(a: FA -> A, b: FB -> B, c: FC -> C, d: FD -> D);
+    /// Lazily convert five values to a tuple of `NonEmpty`'s or fail,
+    /// see [try_non_empty2_with](fn.try_non_empty2_with.html).
+    fn try_non_empty5_with(a: FA -> A, b: FB -> B, c: FC -> C, d: FD -> D, e: FE -> E);
+    /// Lazily convert six values to a tuple of `NonEmpty`'s or fail,
+    /// see [try_non_empty2_with](fn.try_non_empty2_with.html).
+    fn try_non_empty6_with(a: FA -> A, b: FB -> B, c: FC -> C, d: FD -> D, e: FE -> E, f: FF -> F);
 }
 
 #[allow(non_snake_case)]
@@ -102,7 +167,7 @@ pub fn try_non_emptyN<T, A>(a: A) -> Option<Vec<NonEmpty<T>>>
     let a = a.into_iter()
         .map(T::try_non_empty)
         .take_while(|v| v.is_some())
-        .filter_map(|v| v)
+        .flatten()
         .collect::<Vec<_>>();
     if a.len() == input_len {
         return Some(a)
@@ -110,10 +175,267 @@ pub fn try_non_emptyN<T, A>(a: A) -> Option<Vec<NonEmpty<T>>>
     None
 }
 
+/// Convert an array of values to an array of `NonEmpty`'s or fail, without allocating.
+///
+/// Unlike `try_non_emptyN` the values don't have to be `Clone`.
+///
+/// # Examples
+/// ```
+/// use non_empty::try_non_empty_array;
+///
+/// let [host, user] = try_non_empty_array(["localhost", "bob"]).unwrap();
+/// assert_eq!("bob", *user);
+/// assert!(try_non_empty_array(["localhost", ""]).is_none());
+/// # let _ = host;
+/// ```
+pub fn try_non_empty_array<T, const N: usize>(arr: [T; N]) -> Option<[NonEmpty<T>; N]>
+    where T: IsEmpty,
+{
+    try_non_empty_array_or_index(arr).ok()
+}
+
+/// Convert an array of values to an array of `NonEmpty`'s, without allocating.
+///
+/// # Errors
+/// Returns the index of the first empty value.
+pub fn try_non_empty_array_or_index<T, const N: usize>(arr: [T; N]) -> Result<[NonEmpty<T>; N], usize>
+    where T: IsEmpty,
+{
+    if let Some(i) = first_empty_index(&arr) {
+        return Err(i)
+    }
+    Ok(arr.map(|inner| NonEmpty { inner }))
+}
+
+/// Zips two non-empty values into a non-empty pair.
+///
+/// A tuple is empty if any of its components is empty, see
+/// [unzip](fn.unzip.html) for the reverse.
+///
+/// # Examples
+/// ```
+/// use non_empty::{try_non_empty2, unzip, zip};
+///
+/// let (name, age) = try_non_empty2("bob", 42).unwrap();
+/// let pair = zip(name, age);
+/// assert_eq!(("bob", 42), *pair);
+///
+/// let (name, age) = unzip(pair);
+/// assert_eq!("bob", *name);
+/// assert_eq!(42, *age);
+/// ```
+pub fn zip<A, B>(a: NonEmpty<A>, b: NonEmpty<B>) -> NonEmpty<(A, B)> {
+    NonEmpty { inner: (a.inner, b.inner) }
+}
+
+/// Unzips a non-empty pair into its non-empty components.
+///
+/// Can't fail, because a tuple is empty if any of its components is
+/// empty, e.g. `("a", 0)` is empty. With the opposite definition,
+/// empty only if all components are empty, a pair like `("a", 0)`
+/// would be non-empty and `unzip` would have to return `Option`s.
+pub fn unzip<A, B>(pair: NonEmpty<(A, B)>) -> (NonEmpty<A>, NonEmpty<B>) {
+    let (a, b) = pair.inner;
+    (NonEmpty { inner: a }, NonEmpty { inner: b })
+}
+
+/// Returns true if none of the values is empty, stopping at the first empty value.
+///
+/// Returns true for no values at all.
+///
+/// # Examples
+/// ```
+/// use non_empty::all_non_empty;
+///
+/// let names = vec!["a".to_string(), "".to_string()];
+/// assert!(!all_non_empty(&names));
+/// assert!(all_non_empty(&names[..1]));
+/// ```
+pub fn all_non_empty<'a, T, I>(iter: I) -> bool
+    where T: IsEmpty + 'a,
+          I: IntoIterator<Item = &'a T>,
+{
+    first_empty_index(iter).is_none()
+}
+
+/// Returns the index of the first empty value, stopping there.
+///
+/// # Examples
+/// ```
+/// use non_empty::first_empty_index;
+///
+/// assert_eq!(Some(1), first_empty_index(&["a", "", ""]));
+/// ```
+pub fn first_empty_index<'a, T, I>(iter: I) -> Option<usize>
+    where T: IsEmpty + 'a,
+          I: IntoIterator<Item = &'a T>,
+{
+    iter.into_iter().position(IsEmpty::is_empty)
+}
+
+/// Converts all values of a `HashMap` to `NonEmpty`'s, keeping the hasher.
+///
+/// # Errors
+/// Returns the key of an empty value, if there are several empty values
+/// it's unspecified which key is returned.
+///
+/// # Examples
+/// ```
+/// use std::collections::HashMap;
+/// use non_empty::try_non_empty_values;
+///
+/// let mut config = HashMap::new();
+/// config.insert("host", "localhost".to_string());
+/// config.insert("user", "".to_string());
+/// assert_eq!(Err("user"), try_non_empty_values(config).map(|_| ()));
+/// ```
+pub fn try_non_empty_values<K, V, S>(map: HashMap<K, V, S>) -> Result<HashMap<K, NonEmpty<V>, S>, K>
+    where K: Eq + Hash,
+          V: TryNonEmpty,
+          S: BuildHasher + Clone,
+{
+    let mut out = HashMap::with_capacity_and_hasher(map.len(), map.hasher().clone());
+    for (k, v) in map {
+        match v.try_non_empty() {
+            Some(v) => out.insert(k, v),
+            None => return Err(k),
+        };
+    }
+    Ok(out)
+}
+
+/// Converts all keys and values of a `HashMap` to `NonEmpty`'s, keeping the hasher.
+///
+/// # Errors
+/// Returns the key of an empty value or an empty key, if there are
+/// several it's unspecified which key is returned.
+pub fn try_non_empty_entries<K, V, S>(map: HashMap<K, V, S>)
+    -> Result<HashMap<NonEmpty<K>, NonEmpty<V>, S>, K>
+    where K: Eq + Hash + TryNonEmpty,
+          V: TryNonEmpty,
+          S: BuildHasher + Clone,
+{
+    let mut out = HashMap::with_capacity_and_hasher(map.len(), map.hasher().clone());
+    for (k, v) in map {
+        let v = match v.try_non_empty() {
+            Some(v) => v,
+            None => return Err(k),
+        };
+        if k.is_empty() {
+            return Err(k)
+        }
+        out.insert(NonEmpty { inner: k }, v);
+    }
+    Ok(out)
+}
+
+/// Converts all values of a `BTreeMap` to `NonEmpty`'s.
+///
+/// # Errors
+/// Returns the smallest key with an empty value.
+pub fn try_non_empty_btree_values<K, V>(map: BTreeMap<K, V>) -> Result<BTreeMap<K, NonEmpty<V>>, K>
+    where K: Ord,
+          V: TryNonEmpty,
+{
+    let mut out = BTreeMap::new();
+    for (k, v) in map {
+        match v.try_non_empty() {
+            Some(v) => out.insert(k, v),
+            None => return Err(k),
+        };
+    }
+    Ok(out)
+}
+
+/// Checks all values of a `BTreeMap`, collecting the keys of all empty values.
+///
+/// An empty map has no empty values and passes the check,
+/// use `try_non_empty` on the map itself to reject it.
+///
+/// # Errors
+/// Returns the keys of all empty values, in sorted order.
+///
+/// # Examples
+/// ```
+/// use std::collections::BTreeMap;
+/// use non_empty::check_map_non_empty;
+///
+/// let mut config = BTreeMap::new();
+/// config.insert("user", "");
+/// config.insert("host", "localhost");
+/// config.insert("db", "");
+/// assert_eq!(Err(vec!["db", "user"]), check_map_non_empty(&config));
+/// ```
+pub fn check_map_non_empty<K, V>(map: &BTreeMap<K, V>) -> Result<(), Vec<K>>
+    where K: Ord + Clone,
+          V: IsEmpty,
+{
+    let empty: Vec<K> = map.iter()
+        .filter(|&(_, v)| v.is_empty())
+        .map(|(k, _)| k.clone())
+        .collect();
+    if empty.is_empty() {
+        return Ok(())
+    }
+    Err(empty)
+}
+
+/// Converts all values of a `BTreeMap` to `NonEmpty`'s,
+/// after [check_map_non_empty](fn.check_map_non_empty.html) passed.
+///
+/// An empty map results in an empty map.
+///
+/// # Errors
+/// Returns the keys of all empty values, in sorted order.
+pub fn into_non_empty_values<K, V>(map: BTreeMap<K, V>) -> Result<BTreeMap<K, NonEmpty<V>>, Vec<K>>
+    where K: Ord + Clone,
+          V: IsEmpty,
+{
+    check_map_non_empty(&map)?;
+    Ok(map.into_iter()
+        .map(|(k, inner)| (k, NonEmpty { inner }))
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_try_non_empty_with() {
+        use std::cell::Cell;
+
+        let calls = Cell::new(0);
+        let count = |v: &'static str| {
+            let calls = &calls;
+            move || {
+                calls.set(calls.get() + 1);
+                v
+            }
+        };
+
+        let (a, b) = try_non_empty2_with(count("a"), count("b")).unwrap();
+        assert_eq!(("a", "b"), (*a, *b));
+        assert_eq!(2, calls.get());
+
+        calls.set(0);
+        assert!(try_non_empty2_with(count(""), count("b")).is_none());
+        assert_eq!(1, calls.get());
+
+        calls.set(0);
+        assert!(try_non_empty4_with(count("a"), count(""), count("c"), count("d")).is_none());
+        assert_eq!(2, calls.get());
+
+        calls.set(0);
+        assert!(try_non_empty6_with(count("a"), count("b"), count("c"), count("d"), count("e"), count("")).is_none());
+        assert_eq!(6, calls.get());
+
+        let (a, b, c) = try_non_empty3_with(|| 1, || vec![2], || "3".to_string()).unwrap();
+        assert_eq!((1, vec![2], "3"), (*a, b.into_inner(), c.as_str()));
+        let r = try_non_empty5_with(|| 1, || 2, || 3, || 4, || 5).unwrap();
+        assert_eq!(5, *r.4);
+    }
+
     #[test]
     fn test_try_non_empty2() {
         assert!(try_non_empty2("a", 0).is_none());
@@ -172,4 +494,174 @@ mod tests {
         assert_eq!(*a[0], 3);
         assert_eq!(*a[1], 5);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_try_all_non_empty() {
+        let (a, b) = ("a", vec![1]).try_all_non_empty().unwrap();
+        assert_eq!(("a", vec![1]), (*a, b.into_inner()));
+
+        let t = ("a", 1, 2.0, vec![3], "b".to_string(), 4u8).try_all_non_empty().unwrap();
+        assert_eq!(4, *t.5);
+        assert!(("", 1, 2.0, vec![3], "b", 4u8).try_all_non_empty().is_none());
+        assert!(("a", 1, 0.0, vec![3], "b", 4u8).try_all_non_empty().is_none());
+        assert!(("a", 1, 2.0, vec![3], "b", 0u8).try_all_non_empty().is_none());
+
+        // works without Clone
+        struct NotClone(u8);
+        impl IsEmpty for NotClone {
+            fn is_empty(&self) -> bool {
+                self.0 == 0
+            }
+        }
+        assert!((NotClone(1), NotClone(0)).try_all_non_empty().is_none());
+    }
+
+    fn count_valid<T: TryNonEmptyAll>(rows: Vec<T>) -> usize {
+        rows.into_iter().filter_map(TryNonEmptyAll::try_all_non_empty).count()
+    }
+
+    #[test]
+    fn test_try_all_non_empty_generic() {
+        assert_eq!(2, count_valid(vec![("a", 1), ("", 1), ("b", 2), ("c", 0)]));
+    }
+
+    #[test]
+    fn test_try_non_empty_array() {
+        let [a] = try_non_empty_array([7]).unwrap();
+        assert_eq!(7, *a);
+        assert!(try_non_empty_array([0]).is_none());
+
+        assert_eq!(Some(2), try_non_empty_array_or_index([1, 2, 0, 4]).err());
+        assert_eq!(Some(1), try_non_empty_array_or_index([1, 0, 0, 4]).err());
+        let a = try_non_empty_array_or_index([1, 2, 3, 4]).unwrap();
+        assert_eq!([1, 2, 3, 4], a.map(NonEmpty::into_inner));
+
+        // works without Clone
+        #[derive(Debug)]
+        struct Field(&'static str);
+        impl IsEmpty for Field {
+            fn is_empty(&self) -> bool {
+                self.0.is_empty()
+            }
+        }
+        let [a, b] = try_non_empty_array([Field("a"), Field("b")]).unwrap();
+        assert_eq!(("a", "b"), (a.0, b.0));
+        assert_eq!(Err(1), try_non_empty_array_or_index([Field("a"), Field("")]).map(|_| ()));
+    }
+
+    fn config(user: &str) -> HashMap<&'static str, String> {
+        let mut m = HashMap::new();
+        m.insert("host", "localhost".to_string());
+        m.insert("user", user.to_string());
+        m.insert("port", "80".to_string());
+        m
+    }
+
+    #[test]
+    fn test_try_non_empty_values() {
+        assert_eq!(Err("user"), try_non_empty_values(config("")).map(|_| ()));
+
+        let m = try_non_empty_values(config("bob")).unwrap();
+        assert_eq!(3, m.len());
+        assert_eq!("bob", *m["user"]);
+
+        let b: BTreeMap<_, _> = config("").into_iter().collect();
+        assert_eq!(Err("user"), try_non_empty_btree_values(b).map(|_| ()));
+        let b: BTreeMap<_, _> = config("bob").into_iter().collect();
+        assert_eq!("80", *try_non_empty_btree_values(b).unwrap()["port"]);
+    }
+
+    #[test]
+    fn test_try_non_empty_entries() {
+        let mut m = HashMap::new();
+        m.insert("a", vec![1]);
+        m.insert("", vec![2]);
+        assert_eq!(Err(""), try_non_empty_entries(m.clone()).map(|_| ()));
+        m.remove("");
+        m.insert("b", vec![]);
+        assert_eq!(Err("b"), try_non_empty_entries(m.clone()).map(|_| ()));
+        m.remove("b");
+        let m = try_non_empty_entries(m).unwrap();
+        // NonEmpty<K> borrows as K, so lookups work with plain keys
+        assert_eq!(vec![1], *m[&"a"]);
+    }
+
+    #[test]
+    fn hasher_is_kept() {
+        use std::collections::hash_map::DefaultHasher;
+
+        // has state and no Default, so the hasher can only be cloned from the input
+        #[derive(Clone)]
+        struct Tagged(u64);
+
+        impl BuildHasher for Tagged {
+            type Hasher = DefaultHasher;
+
+            fn build_hasher(&self) -> DefaultHasher {
+                DefaultHasher::new()
+            }
+        }
+
+        let mut m = HashMap::with_hasher(Tagged(7));
+        m.insert("a", "1");
+        let values = try_non_empty_values(m.clone()).unwrap();
+        assert_eq!(7, values.hasher().0);
+        assert_eq!("1", *values["a"]);
+
+        let entries = try_non_empty_entries(m).unwrap();
+        assert_eq!(7, entries.hasher().0);
+        assert_eq!("1", *entries[&"a"]);
+    }
+
+    #[test]
+    fn test_check_map_non_empty() {
+        let mut m = BTreeMap::new();
+        assert_eq!(Ok(()), check_map_non_empty(&m));
+        assert!(into_non_empty_values(m.clone()).unwrap().is_empty());
+
+        m.insert("c", "");
+        m.insert("b", "x");
+        m.insert("a", "");
+        assert_eq!(Err(vec!["a", "c"]), check_map_non_empty(&m));
+        assert_eq!(Err(vec!["a", "c"]), into_non_empty_values(m.clone()).map(|_| ()));
+
+        m.insert("a", "y");
+        m.insert("c", "z");
+        let m = into_non_empty_values(m).unwrap();
+        let values: Vec<&str> = m.values().map(|v| **v).collect();
+        assert_eq!(vec!["y", "x", "z"], values);
+    }
+
+    #[test]
+    fn test_first_empty_index() {
+        let none: Vec<String> = Vec::new();
+        assert_eq!(None, first_empty_index(&none));
+        assert!(all_non_empty(&none));
+
+        assert_eq!(Some(0), first_empty_index(&["", "a", "b"]));
+        assert_eq!(Some(1), first_empty_index(&["a", "", "b"]));
+        assert_eq!(Some(2), first_empty_index(&["a", "b", ""]));
+        assert!(!all_non_empty(&["a", "b", ""]));
+
+        assert_eq!(None, first_empty_index(&["a", "b"]));
+        assert!(all_non_empty(&["a", "b"]));
+        assert!(all_non_empty([vec![1], vec![2]].iter()));
+    }
+
+    #[test]
+    fn test_zip_unzip() {
+        let (a, b) = try_non_empty2("a".to_string(), vec![1]).unwrap();
+        let pair = zip(a.clone(), b.clone());
+        assert_eq!(("a".to_string(), vec![1]), *pair);
+        assert_eq!((a, b), unzip(pair));
+
+        // one empty component makes the whole tuple empty
+        assert!(("a", 0).try_non_empty().is_none());
+        assert!(("", 1).try_non_empty().is_none());
+        let (a, b) = unzip(("a", 1).try_non_empty().unwrap());
+        assert_eq!(("a", 1), (*a, *b));
+
+        assert!((1, 2, 3, 4, 5, 6).try_non_empty().is_some());
+        assert!((1, 2, 3, 4, 5, 0).try_non_empty().is_none());
+    }
+}
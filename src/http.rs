@@ -0,0 +1,123 @@
+extern crate http;
+
+use std::convert::TryFrom;
+
+use self::http::header::{AsHeaderName, HeaderMap, HeaderValue, InvalidHeaderValue};
+
+use super::{IsEmpty, NonEmpty};
+
+/// Non-empty `HeaderValue`.
+pub type HeaderValueNE = NonEmpty<HeaderValue>;
+
+/// Non-empty `HeaderMap`.
+pub type HeaderMapNE = NonEmpty<HeaderMap>;
+
+/// A header value of zero length is empty.
+impl IsEmpty for HeaderValue {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        HeaderValue::is_empty(self)
+    }
+}
+
+impl<T> IsEmpty for HeaderMap<T> {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        HeaderMap::is_empty(self)
+    }
+}
+
+impl From<NonEmpty<HeaderValue>> for HeaderValue {
+    #[inline]
+    fn from(v: NonEmpty<HeaderValue>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl<T> From<NonEmpty<HeaderMap<T>>> for HeaderMap<T> {
+    #[inline]
+    fn from(v: NonEmpty<HeaderMap<T>>) -> Self {
+        v.into_inner()
+    }
+}
+
+/// Returns the first value of the header `name`, `None` if it is missing or empty.
+///
+/// # Examples
+/// ```
+/// extern crate http;
+/// extern crate non_empty;
+///
+/// use http::header::{HeaderMap, HeaderValue, AUTHORIZATION};
+/// use non_empty::get_non_empty;
+///
+/// # fn main() {
+/// let mut headers = HeaderMap::new();
+/// assert!(get_non_empty(&headers, AUTHORIZATION).is_none());
+///
+/// headers.insert(AUTHORIZATION, HeaderValue::from_static(""));
+/// assert!(get_non_empty(&headers, AUTHORIZATION).is_none());
+///
+/// headers.insert(AUTHORIZATION, HeaderValue::from_static("Bearer abc"));
+/// assert_eq!("Bearer abc", *get_non_empty(&headers, "authorization").unwrap());
+/// # }
+/// ```
+pub fn get_non_empty<K: AsHeaderName>(headers: &HeaderMap, name: K) -> Option<NonEmpty<&HeaderValue>> {
+    headers.get(name)
+        .filter(|v| !v.is_empty())
+        .map(|inner| NonEmpty { inner })
+}
+
+impl NonEmpty<String> {
+    /// Converts the string into a non-empty header value.
+    ///
+    /// Fails like `HeaderValue::try_from` if the string contains bytes
+    /// which are not allowed in a header value, e.g. a newline.
+    pub fn into_header_value_ne(self) -> Result<HeaderValueNE, InvalidHeaderValue> {
+        HeaderValue::try_from(self.inner).map(|inner| NonEmpty { inner })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::http::header::{HeaderMap, HeaderValue, CONTENT_TYPE, USER_AGENT};
+    use super::{get_non_empty, HeaderMapNE, HeaderValueNE};
+    use super::super::{StringNE, TryNonEmpty};
+
+    #[test]
+    fn empty_rejection() {
+        assert!(HeaderValue::from_static("").try_non_empty().is_none());
+        assert!(HeaderMap::<HeaderValue>::new().try_non_empty().is_none());
+
+        let v: HeaderValueNE = HeaderValue::from_static("gzip").try_non_empty().unwrap();
+        assert_eq!("gzip", *v);
+
+        let mut m = HeaderMap::new();
+        m.insert(USER_AGENT, HeaderValue::from_static(""));
+        let m: HeaderMapNE = m.try_non_empty().unwrap();
+        assert_eq!(1, m.len());
+    }
+
+    #[test]
+    fn get_headers() {
+        let mut m = HeaderMap::new();
+        m.insert(USER_AGENT, HeaderValue::from_static(""));
+        m.insert(CONTENT_TYPE, HeaderValue::from_static("text/plain"));
+
+        assert!(get_non_empty(&m, "x-missing").is_none());
+        assert!(get_non_empty(&m, USER_AGENT).is_none());
+        assert_eq!("text/plain", *get_non_empty(&m, CONTENT_TYPE).unwrap());
+        assert_eq!("text/plain", *get_non_empty(&m, "Content-Type").unwrap());
+    }
+
+    #[test]
+    fn from_string() {
+        let s: StringNE = "text/html".to_string().try_non_empty().unwrap();
+        assert_eq!("text/html", *s.into_header_value_ne().unwrap());
+
+        let s: StringNE = "a\r\nb".to_string().try_non_empty().unwrap();
+        assert!(s.into_header_value_ne().is_err());
+        let s: StringNE = "\u{7f}".to_string().try_non_empty().unwrap();
+        assert!(s.into_header_value_ne().is_err());
+    }
+}
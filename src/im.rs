@@ -0,0 +1,290 @@
+extern crate im;
+
+use std::hash::{BuildHasher, Hash};
+
+use self::im::{HashMap, HashSet, OrdMap, OrdSet, Vector};
+
+use super::{IsEmpty, NonEmpty};
+
+/// Non-empty `im::Vector`.
+pub type ImVectorNE<T> = NonEmpty<Vector<T>>;
+
+/// Non-empty `im::HashMap`.
+pub type ImHashMapNE<K, V> = NonEmpty<HashMap<K, V>>;
+
+/// Non-empty `im::HashSet`.
+pub type ImHashSetNE<T> = NonEmpty<HashSet<T>>;
+
+/// Non-empty `im::OrdMap`.
+pub type ImOrdMapNE<K, V> = NonEmpty<OrdMap<K, V>>;
+
+/// Non-empty `im::OrdSet`.
+pub type ImOrdSetNE<T> = NonEmpty<OrdSet<T>>;
+
+impl<T: Clone> IsEmpty for Vector<T> {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        Vector::is_empty(self)
+    }
+}
+
+impl<K, V, S> IsEmpty for HashMap<K, V, S> {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        HashMap::is_empty(self)
+    }
+}
+
+impl<T, S> IsEmpty for HashSet<T, S> {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        HashSet::is_empty(self)
+    }
+}
+
+impl<K, V> IsEmpty for OrdMap<K, V> {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        OrdMap::is_empty(self)
+    }
+}
+
+impl<T> IsEmpty for OrdSet<T> {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        OrdSet::is_empty(self)
+    }
+}
+
+impl<T: Clone> From<NonEmpty<Vector<T>>> for Vector<T> {
+    #[inline]
+    fn from(v: NonEmpty<Vector<T>>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl<K, V, S> From<NonEmpty<HashMap<K, V, S>>> for HashMap<K, V, S> {
+    #[inline]
+    fn from(v: NonEmpty<HashMap<K, V, S>>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl<T, S> From<NonEmpty<HashSet<T, S>>> for HashSet<T, S> {
+    #[inline]
+    fn from(v: NonEmpty<HashSet<T, S>>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl<K, V> From<NonEmpty<OrdMap<K, V>>> for OrdMap<K, V> {
+    #[inline]
+    fn from(v: NonEmpty<OrdMap<K, V>>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl<T> From<NonEmpty<OrdSet<T>>> for OrdSet<T> {
+    #[inline]
+    fn from(v: NonEmpty<OrdSet<T>>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl<T: Clone> NonEmpty<Vector<T>> {
+    /// Returns the first element.
+    pub fn front(&self) -> &T {
+        self.inner.front().expect("NonEmpty is never empty")
+    }
+
+    /// Returns the last element.
+    pub fn back(&self) -> &T {
+        self.inner.back().expect("NonEmpty is never empty")
+    }
+
+    /// Appends an element, like `Vector::push_back`.
+    #[inline]
+    pub fn push_back(&mut self, value: T) {
+        self.inner.push_back(value)
+    }
+
+    /// Prepends an element, like `Vector::push_front`.
+    #[inline]
+    pub fn push_front(&mut self, value: T) {
+        self.inner.push_front(value)
+    }
+
+    /// Returns a copy with the element at `index` replaced, like `Vector::update`.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds, like `Vector::update`.
+    pub fn update(&self, index: usize, value: T) -> Self {
+        NonEmpty { inner: self.inner.update(index, value) }
+    }
+}
+
+impl<K, V, S> NonEmpty<HashMap<K, V, S>>
+    where K: Hash + Eq + Clone,
+          V: Clone,
+          S: BuildHasher,
+{
+    /// Inserts a key-value pair, like `im::HashMap::insert`.
+    ///
+    /// Returns the old value if the key was already present.
+    #[inline]
+    pub fn insert(&mut self, k: K, v: V) -> Option<V> {
+        self.inner.insert(k, v)
+    }
+
+    /// Returns a copy with the key-value pair inserted, like `im::HashMap::update`.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate im;
+    /// extern crate non_empty;
+    ///
+    /// use non_empty::TryNonEmpty;
+    ///
+    /// # fn main() {
+    /// let v1 = im::hashmap!{"port" => 80}.try_non_empty().unwrap();
+    /// let v2 = v1.update("port", 8080);
+    /// assert_eq!(Some(&80), v1.get("port"));
+    /// assert_eq!(Some(&8080), v2.get("port"));
+    /// # }
+    /// ```
+    pub fn update(&self, k: K, v: V) -> Self {
+        NonEmpty { inner: self.inner.update(k, v) }
+    }
+}
+
+impl<T, S> NonEmpty<HashSet<T, S>>
+    where T: Hash + Eq + Clone,
+          S: BuildHasher,
+{
+    /// Adds an element, like `im::HashSet::insert`.
+    ///
+    /// Returns the replaced element if it was already present.
+    #[inline]
+    pub fn insert(&mut self, value: T) -> Option<T> {
+        self.inner.insert(value)
+    }
+
+    /// Returns a copy with the element added, like `im::HashSet::update`.
+    pub fn update(&self, value: T) -> Self {
+        NonEmpty { inner: self.inner.update(value) }
+    }
+}
+
+impl<K, V> NonEmpty<OrdMap<K, V>>
+    where K: Ord + Clone,
+          V: Clone,
+{
+    /// Returns the entry with the smallest key.
+    pub fn get_min(&self) -> &(K, V) {
+        self.inner.get_min().expect("NonEmpty is never empty")
+    }
+
+    /// Returns the entry with the largest key.
+    pub fn get_max(&self) -> &(K, V) {
+        self.inner.get_max().expect("NonEmpty is never empty")
+    }
+
+    /// Inserts a key-value pair, like `OrdMap::insert`.
+    ///
+    /// Returns the old value if the key was already present.
+    #[inline]
+    pub fn insert(&mut self, k: K, v: V) -> Option<V> {
+        self.inner.insert(k, v)
+    }
+
+    /// Returns a copy with the key-value pair inserted, like `OrdMap::update`.
+    pub fn update(&self, k: K, v: V) -> Self {
+        NonEmpty { inner: self.inner.update(k, v) }
+    }
+}
+
+impl<T> NonEmpty<OrdSet<T>>
+    where T: Ord + Clone,
+{
+    /// Returns the smallest element.
+    pub fn get_min(&self) -> &T {
+        self.inner.get_min().expect("NonEmpty is never empty")
+    }
+
+    /// Returns the largest element.
+    pub fn get_max(&self) -> &T {
+        self.inner.get_max().expect("NonEmpty is never empty")
+    }
+
+    /// Adds an element, like `OrdSet::insert`.
+    ///
+    /// Returns the replaced element if it was already present.
+    #[inline]
+    pub fn insert(&mut self, value: T) -> Option<T> {
+        self.inner.insert(value)
+    }
+
+    /// Returns a copy with the element added, like `OrdSet::update`.
+    pub fn update(&self, value: T) -> Self {
+        NonEmpty { inner: self.inner.update(value) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::im::{HashMap, HashSet, OrdMap, OrdSet, Vector};
+    use super::{ImHashMapNE, ImOrdMapNE, ImOrdSetNE, ImVectorNE};
+    use super::super::TryNonEmpty;
+
+    #[test]
+    fn empty_rejection() {
+        assert!(Vector::<i32>::new().try_non_empty().is_none());
+        assert!(HashMap::<i32, i32>::new().try_non_empty().is_none());
+        assert!(HashSet::<i32>::new().try_non_empty().is_none());
+        assert!(OrdMap::<i32, i32>::new().try_non_empty().is_none());
+        assert!(OrdSet::<i32>::new().try_non_empty().is_none());
+        assert!(Vector::unit(1).try_non_empty().is_some());
+        assert!(HashSet::unit(1).try_non_empty().is_some());
+    }
+
+    #[test]
+    fn persistent_updates() {
+        let v1: ImVectorNE<i32> = Vector::unit(1).try_non_empty().unwrap();
+        let v2 = v1.update(0, 2);
+        assert_eq!((1, 2), (*v1.front(), *v2.front()));
+        let mut v3 = v2.clone();
+        v3.push_back(3);
+        v3.push_front(0);
+        assert_eq!((0, 3), (*v3.front(), *v3.back()));
+        assert_eq!(1, v2.len());
+
+        let m1: ImOrdMapNE<&str, i32> = OrdMap::unit("b", 2).try_non_empty().unwrap();
+        let m2 = m1.update("a", 1);
+        assert_eq!(&("b", 2), m1.get_min());
+        assert_eq!(&("a", 1), m2.get_min());
+        assert_eq!(&("b", 2), m2.get_max());
+
+        let s1: ImOrdSetNE<i32> = OrdSet::unit(5).try_non_empty().unwrap();
+        let mut s2 = s1.update(9);
+        assert_eq!(None, s2.insert(1));
+        assert_eq!((5, 5), (*s1.get_min(), *s1.get_max()));
+        assert_eq!((1, 9), (*s2.get_min(), *s2.get_max()));
+    }
+
+    #[test]
+    fn structural_sharing() {
+        let m: ImHashMapNE<&str, i32> = HashMap::unit("host", 1).try_non_empty().unwrap();
+        let snapshot = m.clone();
+        assert!(m.ptr_eq(&snapshot));
+
+        let mut next = m.clone();
+        assert_eq!(Some(1), next.insert("host", 2));
+        assert!(!next.ptr_eq(&snapshot));
+        assert_eq!(Some(&1), snapshot.get("host"));
+
+        let s = HashSet::unit(1).try_non_empty().unwrap();
+        let mut t = s.update(2);
+        assert_eq!(Some(2), t.insert(2));
+        assert_eq!((1, 2), (s.len(), t.len()));
+    }
+}
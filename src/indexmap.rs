@@ -0,0 +1,209 @@
+extern crate indexmap;
+
+use std::hash::{BuildHasher, Hash};
+
+use self::indexmap::{Equivalent, IndexMap, IndexSet};
+
+use super::{IsEmpty, NonEmpty, WouldBeEmpty};
+
+/// Non-empty `IndexMap`.
+pub type IndexMapNE<K, V> = NonEmpty<IndexMap<K, V>>;
+
+/// Non-empty `IndexSet`.
+pub type IndexSetNE<T> = NonEmpty<IndexSet<T>>;
+
+impl<K, V, S> IsEmpty for IndexMap<K, V, S> {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        IndexMap::is_empty(self)
+    }
+}
+
+impl<T, S> IsEmpty for IndexSet<T, S> {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        IndexSet::is_empty(self)
+    }
+}
+
+impl<K, V, S> From<NonEmpty<IndexMap<K, V, S>>> for IndexMap<K, V, S> {
+    #[inline]
+    fn from(v: NonEmpty<IndexMap<K, V, S>>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl<T, S> From<NonEmpty<IndexSet<T, S>>> for IndexSet<T, S> {
+    #[inline]
+    fn from(v: NonEmpty<IndexSet<T, S>>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl<K, V, S> NonEmpty<IndexMap<K, V, S>> {
+    /// Returns the first inserted entry.
+    pub fn first(&self) -> (&K, &V) {
+        self.inner.first().expect("NonEmpty is never empty")
+    }
+
+    /// Returns the last inserted entry.
+    pub fn last(&self) -> (&K, &V) {
+        self.inner.last().expect("NonEmpty is never empty")
+    }
+}
+
+impl<K, V, S> NonEmpty<IndexMap<K, V, S>>
+    where K: Hash + Eq,
+          S: BuildHasher,
+{
+    /// Inserts a key-value pair, like `IndexMap::insert`.
+    ///
+    /// Returns the old value if the key was already present.
+    #[inline]
+    pub fn insert(&mut self, k: K, v: V) -> Option<V> {
+        self.inner.insert(k, v)
+    }
+
+    /// Removes a key and shifts the following entries, like `IndexMap::shift_remove`.
+    ///
+    /// Returns `Err(WouldBeEmpty)` and leaves the map untouched
+    /// if `k` is the only key in the map.
+    pub fn shift_remove<Q>(&mut self, k: &Q) -> Result<Option<V>, WouldBeEmpty>
+        where Q: Hash + Equivalent<K> + ?Sized,
+    {
+        if self.inner.len() == 1 && self.inner.contains_key(k) {
+            return Err(WouldBeEmpty)
+        }
+        Ok(self.inner.shift_remove(k))
+    }
+
+    /// Removes a key and moves the last entry into its place, like `IndexMap::swap_remove`.
+    ///
+    /// Returns `Err(WouldBeEmpty)` and leaves the map untouched
+    /// if `k` is the only key in the map.
+    pub fn swap_remove<Q>(&mut self, k: &Q) -> Result<Option<V>, WouldBeEmpty>
+        where Q: Hash + Equivalent<K> + ?Sized,
+    {
+        if self.inner.len() == 1 && self.inner.contains_key(k) {
+            return Err(WouldBeEmpty)
+        }
+        Ok(self.inner.swap_remove(k))
+    }
+}
+
+impl<T, S> NonEmpty<IndexSet<T, S>> {
+    /// Returns the first inserted element.
+    pub fn first(&self) -> &T {
+        self.inner.first().expect("NonEmpty is never empty")
+    }
+
+    /// Returns the last inserted element.
+    pub fn last(&self) -> &T {
+        self.inner.last().expect("NonEmpty is never empty")
+    }
+}
+
+impl<T, S> NonEmpty<IndexSet<T, S>>
+    where T: Hash + Eq,
+          S: BuildHasher,
+{
+    /// Adds an element, like `IndexSet::insert`.
+    ///
+    /// Returns false if the element was already present.
+    #[inline]
+    pub fn insert(&mut self, value: T) -> bool {
+        self.inner.insert(value)
+    }
+
+    /// Removes an element and shifts the following elements, like `IndexSet::shift_remove`.
+    ///
+    /// Returns `Err(WouldBeEmpty)` and leaves the set untouched
+    /// if `value` is the only element in the set.
+    pub fn shift_remove<Q>(&mut self, value: &Q) -> Result<bool, WouldBeEmpty>
+        where Q: Hash + Equivalent<T> + ?Sized,
+    {
+        if self.inner.len() == 1 && self.inner.contains(value) {
+            return Err(WouldBeEmpty)
+        }
+        Ok(self.inner.shift_remove(value))
+    }
+
+    /// Removes an element and moves the last element into its place, like `IndexSet::swap_remove`.
+    ///
+    /// Returns `Err(WouldBeEmpty)` and leaves the set untouched
+    /// if `value` is the only element in the set.
+    pub fn swap_remove<Q>(&mut self, value: &Q) -> Result<bool, WouldBeEmpty>
+        where Q: Hash + Equivalent<T> + ?Sized,
+    {
+        if self.inner.len() == 1 && self.inner.contains(value) {
+            return Err(WouldBeEmpty)
+        }
+        Ok(self.inner.swap_remove(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::indexmap::{IndexMap, IndexSet};
+    use super::{IndexMapNE, IndexSetNE};
+    use super::super::{TryNonEmpty, WouldBeEmpty};
+
+    fn map() -> IndexMapNE<&'static str, i32> {
+        vec![("c", 3), ("a", 1), ("b", 2)].into_iter()
+            .collect::<IndexMap<_, _>>()
+            .try_non_empty()
+            .unwrap()
+    }
+
+    #[test]
+    fn empty_rejection() {
+        assert!(IndexMap::<i32, i32>::new().try_non_empty().is_none());
+        assert!(IndexSet::<i32>::new().try_non_empty().is_none());
+    }
+
+    #[test]
+    fn first_last_follow_insertion_order() {
+        let mut m = map();
+        assert_eq!((&"c", &3), m.first());
+        assert_eq!((&"b", &2), m.last());
+        m.insert("a", 10);
+        assert_eq!((&"b", &2), m.last());
+        m.insert("d", 4);
+        assert_eq!((&"d", &4), m.last());
+    }
+
+    #[test]
+    fn guarded_removal() {
+        let mut m = map();
+        assert_eq!(Ok(Some(3)), m.shift_remove("c"));
+        assert_eq!((&"a", &1), m.first());
+        assert_eq!(Ok(None), m.swap_remove("x"));
+        assert_eq!(Ok(Some(1)), m.swap_remove("a"));
+        assert_eq!(Err(WouldBeEmpty), m.shift_remove("b"));
+        assert_eq!(Err(WouldBeEmpty), m.swap_remove("b"));
+        assert_eq!((&"b", &2), m.first());
+
+        let mut s: IndexSetNE<i32> = vec![1, 2, 3].into_iter().collect::<IndexSet<_>>().try_non_empty().unwrap();
+        assert_eq!(1, *s.first());
+        assert_eq!(Ok(true), s.swap_remove(&1));
+        assert_eq!(3, *s.first());
+        assert_eq!(Ok(true), s.shift_remove(&3));
+        assert_eq!(Err(WouldBeEmpty), s.shift_remove(&2));
+        assert_eq!(Ok(false), s.swap_remove(&9));
+        assert!(s.insert(5));
+        assert_eq!(5, *s.last());
+    }
+
+    #[test]
+    fn into_iterator() {
+        let m = map();
+        let keys: Vec<&str> = (&m).into_iter().map(|(k, _)| *k).collect();
+        assert_eq!(vec!["c", "a", "b"], keys);
+        let mut sum = 0;
+        for (_, v) in &m {
+            sum += v;
+        }
+        assert_eq!(6, sum);
+        assert_eq!(vec![("c", 3), ("a", 1), ("b", 2)], m.into_iter().collect::<Vec<_>>());
+    }
+}
@@ -11,14 +11,21 @@ impl IsEmpty for String {
     }
 }
 
-impl<'a> IsEmpty for &'a String {
+impl IsEmpty for &String {
     #[inline]
     fn is_empty(&self) -> bool {
         String::is_empty(self)
     }
 }
 
-impl<'a> IsEmpty for &'a str {
+impl IsEmpty for str {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        str::is_empty(self)
+    }
+}
+
+impl IsEmpty for &str {
     #[inline]
     fn is_empty(&self) -> bool {
         str::is_empty(self)
@@ -32,14 +39,49 @@ impl IsEmpty for ::std::ffi::OsStr {
     }
 }
 
-impl<'a> IsEmpty for &'a ::std::ffi::OsStr {
+impl IsEmpty for &::std::ffi::OsStr {
     #[inline]
     fn is_empty(&self) -> bool {
         ::std::ffi::OsStr::is_empty(self)
     }
 }
 
-impl<'a> IsEmpty for &'a ::std::path::Path {
+impl IsEmpty for ::std::ffi::OsString {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.as_os_str().is_empty()
+    }
+}
+
+impl IsEmpty for &::std::ffi::OsString {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.as_os_str().is_empty()
+    }
+}
+
+impl IsEmpty for ::std::ffi::CString {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.as_bytes().is_empty()
+    }
+}
+
+impl IsEmpty for &::std::ffi::CStr {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.to_bytes().is_empty()
+    }
+}
+
+impl IsEmpty for ::std::path::Path {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.as_os_str().is_empty()
+    }
+}
+
+impl IsEmpty for &::std::path::Path {
     #[inline]
     fn is_empty(&self) -> bool {
         self.as_os_str().is_empty()
@@ -53,7 +95,7 @@ impl IsEmpty for ::std::path::PathBuf {
     }
 }
 
-impl<'a> IsEmpty for &'a ::std::path::PathBuf {
+impl IsEmpty for &::std::path::PathBuf {
     #[inline]
     fn is_empty(&self) -> bool {
         self.as_os_str().is_empty()
@@ -67,7 +109,7 @@ impl<T> IsEmpty for Vec<T> {
     }
 }
 
-impl<'a, T> IsEmpty for &'a Vec<T> {
+impl<T> IsEmpty for &Vec<T> {
     #[inline]
     fn is_empty(&self) -> bool {
         Vec::is_empty(self)
@@ -81,6 +123,56 @@ impl<T> IsEmpty for [T] {
     }
 }
 
+impl<T> IsEmpty for &[T] {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        <[T]>::is_empty(self)
+    }
+}
+
+impl<T> IsEmpty for &mut [T] {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        <[T]>::is_empty(self)
+    }
+}
+
+impl IsEmpty for ::std::borrow::Cow<'_, str> {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        str::is_empty(self)
+    }
+}
+
+impl<T: Clone> IsEmpty for ::std::borrow::Cow<'_, [T]> {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        <[T]>::is_empty(self)
+    }
+}
+
+impl IsEmpty for ::std::borrow::Cow<'_, ::std::ffi::OsStr> {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        ::std::ffi::OsStr::is_empty(self)
+    }
+}
+
+impl IsEmpty for ::std::borrow::Cow<'_, ::std::path::Path> {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.as_os_str().is_empty()
+    }
+}
+
+/// Covers `Box<str>`, `Box<[T]>`, `Box<Path>` and trait objects like `Box<dyn IsEmpty>`.
+impl<T: ?Sized + IsEmpty> IsEmpty for Box<T> {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        T::is_empty(self)
+    }
+}
+
 impl<K, V, S> IsEmpty for ::std::collections::HashMap<K, V, S>
     where S: ::std::hash::BuildHasher,
           K: ::std::hash::Hash + Eq
@@ -91,7 +183,7 @@ impl<K, V, S> IsEmpty for ::std::collections::HashMap<K, V, S>
     }
 }
 
-impl<'a, K, V, S> IsEmpty for &'a ::std::collections::HashMap<K, V, S>
+impl<K, V, S> IsEmpty for &::std::collections::HashMap<K, V, S>
     where S: ::std::hash::BuildHasher,
           K: ::std::hash::Hash + Eq
 {
@@ -111,7 +203,7 @@ impl<T, S> IsEmpty for ::std::collections::HashSet<T, S>
     }
 }
 
-impl<'a, T, S> IsEmpty for &'a ::std::collections::HashSet<T, S>
+impl<T, S> IsEmpty for &::std::collections::HashSet<T, S>
     where S: ::std::hash::BuildHasher,
           T: ::std::hash::Hash + Eq
 {
@@ -128,7 +220,7 @@ impl<T> IsEmpty for ::std::collections::LinkedList<T> {
     }
 }
 
-impl<'a, T> IsEmpty for &'a ::std::collections::LinkedList<T> {
+impl<T> IsEmpty for &::std::collections::LinkedList<T> {
     #[inline]
     fn is_empty(&self) -> bool {
         ::std::collections::LinkedList::is_empty(self)
@@ -142,7 +234,7 @@ impl<T> IsEmpty for ::std::collections::VecDeque<T> {
     }
 }
 
-impl<'a, T> IsEmpty for &'a ::std::collections::VecDeque<T> {
+impl<T> IsEmpty for &::std::collections::VecDeque<T> {
     #[inline]
     fn is_empty(&self) -> bool {
         ::std::collections::VecDeque::is_empty(self)
@@ -156,7 +248,7 @@ impl<K, V> IsEmpty for ::std::collections::BTreeMap<K, V> {
     }
 }
 
-impl<'a, K, V> IsEmpty for &'a ::std::collections::BTreeMap<K, V> {
+impl<K, V> IsEmpty for &::std::collections::BTreeMap<K, V> {
     #[inline]
     fn is_empty(&self) -> bool {
         ::std::collections::BTreeMap::is_empty(self)
@@ -170,7 +262,7 @@ impl<T: Ord> IsEmpty for ::std::collections::BTreeSet<T> {
     }
 }
 
-impl<'a, T: Ord> IsEmpty for &'a ::std::collections::BTreeSet<T> {
+impl<T: Ord> IsEmpty for &::std::collections::BTreeSet<T> {
     #[inline]
     fn is_empty(&self) -> bool {
         ::std::collections::BTreeSet::is_empty(self)
@@ -184,7 +276,7 @@ impl<T: Ord> IsEmpty for ::std::collections::BinaryHeap<T> {
     }
 }
 
-impl<'a, T: Ord> IsEmpty for &'a ::std::collections::BinaryHeap<T> {
+impl<T: Ord> IsEmpty for &::std::collections::BinaryHeap<T> {
     #[inline]
     fn is_empty(&self) -> bool {
         ::std::collections::BinaryHeap::is_empty(self)
@@ -273,4 +365,32 @@ impl IsEmpty for f64 {
     fn is_empty(&self) -> bool {
         *self == 0_f64
     }
-}
\ No newline at end of file
+}
+
+impl IsEmpty for ::std::time::Duration {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.is_zero()
+    }
+}
+
+macro_rules! tuple_impls {
+    ($(($($t:ident $i:tt),+))*) => ($(
+        /// A tuple is empty if any of its components is empty,
+        /// so every component of a non-empty tuple is non-empty.
+        impl<$($t: IsEmpty),+> IsEmpty for ($($t,)+) {
+            #[inline]
+            fn is_empty(&self) -> bool {
+                $(self.$i.is_empty())||+
+            }
+        }
+    )*)
+}
+
+tuple_impls! {
+    (A 0, B 1)
+    (A 0, B 1, C 2)
+    (A 0, B 1, C 2, D 3)
+    (A 0, B 1, C 2, D 3, E 4)
+    (A 0, B 1, C 2, D 3, E 4, F 5)
+}
@@ -0,0 +1,385 @@
+use std::iter::{self, Chain, Cloned, Copied, Enumerate, Filter, FilterMap, FromIterator, Inspect, Map, Once, Rev, Zip};
+use std::num::NonZeroUsize;
+
+use super::{IsEmpty, NonEmpty};
+
+/// Iterator wrapper which is guaranteed to yield at least one item.
+///
+/// Terminal operations like [first](#method.first), [max](#method.max) or
+/// [reduce](#method.reduce) return the item directly instead of an `Option`.
+/// Adapters which preserve the length, like [map](#method.map), return a
+/// `NonEmptyIter` again, while [filter](#method.filter) returns a plain iterator.
+///
+/// `NonEmptyIter` is not an `Iterator` itself, because advancing it with `next()`
+/// could drain it. Use `into_iter()` or a `for` loop to get to the items.
+///
+/// # Examples
+/// ```
+/// use non_empty::{TryNonEmpty, VecNE};
+///
+/// let v: VecNE<&str> = vec!["a", "abc", "de"].try_non_empty().unwrap();
+///
+/// let longest: usize = v.iter_ne().map(|s| s.len()).max();
+/// assert_eq!(3, longest);
+/// ```
+#[derive(Clone, Debug)]
+pub struct NonEmptyIter<I> {
+    iter: I,
+}
+
+impl<I> NonEmptyIter<I>
+where
+    I: Iterator,
+{
+    /// Wraps an iterator the caller knows to yield at least one item.
+    pub(crate) fn new_unchecked(iter: I) -> Self {
+        NonEmptyIter { iter }
+    }
+
+    /// Returns the first item.
+    pub fn first(self) -> I::Item {
+        let mut iter = self.iter;
+        iter.next().expect("NonEmptyIter is never empty")
+    }
+
+    /// Returns the last item.
+    pub fn last(self) -> I::Item {
+        self.iter.last().expect("NonEmptyIter is never empty")
+    }
+
+    /// Returns the maximum item, the last one if several are equally maximum.
+    pub fn max(self) -> I::Item
+        where I::Item: Ord,
+    {
+        self.iter.max().expect("NonEmptyIter is never empty")
+    }
+
+    /// Returns the minimum item, the first one if several are equally minimum.
+    pub fn min(self) -> I::Item
+        where I::Item: Ord,
+    {
+        self.iter.min().expect("NonEmptyIter is never empty")
+    }
+
+    /// Reduces the items to a single one, by repeatedly applying `f`.
+    ///
+    /// A single item is returned untouched.
+    pub fn reduce<F>(self, f: F) -> I::Item
+        where F: FnMut(I::Item, I::Item) -> I::Item,
+    {
+        self.iter.reduce(f).expect("NonEmptyIter is never empty")
+    }
+
+    /// Collects the items into a `NonEmpty<C>`.
+    ///
+    /// # Panics
+    /// Panics if `C` is empty despite at least one item being collected,
+    /// e.g. a `String` built from empty `&str` items.
+    /// Use [try_collect_ne](#method.try_collect_ne) if that can happen.
+    pub fn collect_ne<C>(self) -> NonEmpty<C>
+        where C: FromIterator<I::Item> + IsEmpty,
+    {
+        self.try_collect_ne().expect("collect_ne produced an empty collection")
+    }
+
+    /// Collects the items into a `NonEmpty<C>`, returns `None` if `C` is empty
+    /// despite at least one item being collected.
+    ///
+    /// # Examples
+    /// ```
+    /// use non_empty::{StringNE, TryNonEmpty};
+    ///
+    /// let words = vec!["", ""].try_non_empty().unwrap();
+    /// assert!(words.into_iter_ne().try_collect_ne::<String>().is_none());
+    ///
+    /// let words = vec!["a", "", "b"].try_non_empty().unwrap();
+    /// let s: StringNE = words.into_iter_ne().try_collect_ne().unwrap();
+    /// assert_eq!("ab", *s);
+    /// ```
+    pub fn try_collect_ne<C>(self) -> Option<NonEmpty<C>>
+        where C: FromIterator<I::Item> + IsEmpty,
+    {
+        let inner = self.iter.collect::<C>();
+        if inner.is_empty() {
+            return None
+        }
+        Some(NonEmpty { inner })
+    }
+
+    /// Like `Iterator::map`, preserves the non-empty guarantee.
+    pub fn map<B, F>(self, f: F) -> NonEmptyIter<Map<I, F>>
+        where F: FnMut(I::Item) -> B,
+    {
+        NonEmptyIter { iter: self.iter.map(f) }
+    }
+
+    /// Like `Iterator::enumerate`, preserves the non-empty guarantee.
+    pub fn enumerate(self) -> NonEmptyIter<Enumerate<I>> {
+        NonEmptyIter { iter: self.iter.enumerate() }
+    }
+
+    /// Like `Iterator::inspect`, preserves the non-empty guarantee.
+    pub fn inspect<F>(self, f: F) -> NonEmptyIter<Inspect<I, F>>
+        where F: FnMut(&I::Item),
+    {
+        NonEmptyIter { iter: self.iter.inspect(f) }
+    }
+
+    /// Like `Iterator::chain`, preserves the non-empty guarantee.
+    pub fn chain<U>(self, other: U) -> NonEmptyIter<Chain<I, U::IntoIter>>
+        where U: IntoIterator<Item = I::Item>,
+    {
+        NonEmptyIter { iter: self.iter.chain(other) }
+    }
+
+    /// Like `Iterator::zip`, but both sides must be non-empty to preserve the guarantee.
+    pub fn zip<U>(self, other: NonEmptyIter<U>) -> NonEmptyIter<Zip<I, U>>
+        where U: Iterator,
+    {
+        NonEmptyIter { iter: self.iter.zip(other.iter) }
+    }
+
+    /// Like `Iterator::rev`, preserves the non-empty guarantee.
+    pub fn rev(self) -> NonEmptyIter<Rev<I>>
+        where I: DoubleEndedIterator,
+    {
+        NonEmptyIter { iter: self.iter.rev() }
+    }
+
+    /// Like `Iterator::cloned`, preserves the non-empty guarantee.
+    pub fn cloned<'a, T>(self) -> NonEmptyIter<Cloned<I>>
+        where I: Iterator<Item = &'a T>,
+              T: 'a + Clone,
+    {
+        NonEmptyIter { iter: self.iter.cloned() }
+    }
+
+    /// Like `Iterator::copied`, preserves the non-empty guarantee.
+    pub fn copied<'a, T>(self) -> NonEmptyIter<Copied<I>>
+        where I: Iterator<Item = &'a T>,
+              T: 'a + Copy,
+    {
+        NonEmptyIter { iter: self.iter.copied() }
+    }
+
+    /// Like `Iterator::filter`, returns a plain iterator,
+    /// because all items might be filtered out.
+    pub fn filter<P>(self, predicate: P) -> Filter<I, P>
+        where P: FnMut(&I::Item) -> bool,
+    {
+        self.iter.filter(predicate)
+    }
+
+    /// Like `Iterator::filter_map`, returns a plain iterator,
+    /// because all items might be filtered out.
+    pub fn filter_map<B, F>(self, f: F) -> FilterMap<I, F>
+        where F: FnMut(I::Item) -> Option<B>,
+    {
+        self.iter.filter_map(f)
+    }
+}
+
+impl<I> IntoIterator for NonEmptyIter<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+    type IntoIter = I;
+
+    fn into_iter(self) -> I {
+        self.iter
+    }
+}
+
+/// TryNonEmptyIterator upgrades any iterator to a [NonEmptyIter](struct.NonEmptyIter.html).
+pub trait TryNonEmptyIterator: Iterator + Sized {
+    /// Pulls the first item and returns an iterator replaying it before the rest,
+    /// or `None` if the iterator is exhausted.
+    ///
+    /// Only the first item is consumed up front, the rest stays lazy.
+    ///
+    /// # Examples
+    /// ```
+    /// use non_empty::TryNonEmptyIterator;
+    ///
+    /// let lines = "b\nc\na".lines();
+    /// assert_eq!("c", lines.try_into_non_empty_iter().unwrap().max());
+    /// assert!("".lines().try_into_non_empty_iter().is_none());
+    /// ```
+    fn try_into_non_empty_iter(self) -> Option<NonEmptyIter<Chain<Once<Self::Item>, Self>>>;
+
+    /// Returns an iterator over non-empty batches of up to `size` items.
+    ///
+    /// The last batch may be shorter, an exhausted iterator yields no batches.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use non_empty::TryNonEmptyIterator;
+    ///
+    /// let size = NonZeroUsize::new(2).unwrap();
+    /// let batches: Vec<Vec<i32>> = (1..=5).chunks_ne(size).map(|b| b.into_inner()).collect();
+    /// assert_eq!(vec![vec![1, 2], vec![3, 4], vec![5]], batches);
+    /// ```
+    fn chunks_ne(self, size: NonZeroUsize) -> ChunksNe<Self> {
+        ChunksNe { iter: self, size: size.get() }
+    }
+}
+
+impl<I: Iterator> TryNonEmptyIterator for I {
+    fn try_into_non_empty_iter(mut self) -> Option<NonEmptyIter<Chain<Once<I::Item>, I>>> {
+        let first = self.next()?;
+        Some(NonEmptyIter::new_unchecked(iter::once(first).chain(self)))
+    }
+}
+
+/// Iterator over non-empty batches, see
+/// [chunks_ne](trait.TryNonEmptyIterator.html#method.chunks_ne).
+#[derive(Clone, Debug)]
+pub struct ChunksNe<I> {
+    iter: I,
+    size: usize,
+}
+
+impl<I: Iterator> Iterator for ChunksNe<I> {
+    type Item = NonEmpty<Vec<I::Item>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let inner: Vec<I::Item> = self.iter.by_ref().take(self.size).collect();
+        if inner.is_empty() {
+            return None
+        }
+        Some(NonEmpty { inner })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use std::iter::Filter;
+    use std::slice;
+
+    use super::{NonEmptyIter, TryNonEmptyIterator};
+    use super::super::{NonEmpty, TryNonEmpty, VecNE};
+
+    fn sample() -> VecNE<i32> {
+        vec![3, 1, 4, 1, 5].try_non_empty().unwrap()
+    }
+
+    #[test]
+    fn terminal_operations() {
+        let v = sample();
+        assert_eq!(3, *v.iter_ne().first());
+        assert_eq!(5, *v.iter_ne().last());
+        assert_eq!(5, *v.iter_ne().max());
+        assert_eq!(1, *v.iter_ne().min());
+        assert_eq!(14, v.iter_ne().copied().reduce(|a, b| a + b));
+
+        let set: NonEmpty<BTreeSet<i32>> = v.iter_ne().copied().collect_ne();
+        assert_eq!(4, set.len());
+
+        let s: NonEmpty<String> = vec!["a", "b"].try_non_empty().unwrap()
+            .into_iter_ne()
+            .collect_ne();
+        assert_eq!("ab", *s);
+    }
+
+    #[test]
+    #[should_panic]
+    fn collect_ne_empty_collection() {
+        let _: NonEmpty<String> = vec![""].try_non_empty().unwrap()
+            .into_iter_ne()
+            .collect_ne();
+    }
+
+    #[test]
+    fn try_collect_ne() {
+        let v = sample();
+        let set: NonEmpty<BTreeSet<i32>> = v.iter_ne().copied().try_collect_ne().unwrap();
+        assert_eq!(4, set.len());
+
+        let empty = vec!["", ""].try_non_empty().unwrap().into_iter_ne();
+        assert_eq!(None, empty.try_collect_ne::<String>());
+    }
+
+    #[test]
+    fn single_item() {
+        let v: VecNE<i32> = vec![7].try_non_empty().unwrap();
+        assert_eq!(7, *v.iter_ne().first());
+        assert_eq!(7, *v.iter_ne().last());
+        assert_eq!(7, v.into_iter_ne().reduce(|_, _| unreachable!()));
+    }
+
+    #[test]
+    fn adapters_stay_non_empty() {
+        let v = sample();
+        let _: NonEmptyIter<slice::Iter<i32>> = v.iter_ne();
+        let (i, x) = v.iter_ne().enumerate().max();
+        assert_eq!((4, 5), (i, *x));
+        assert_eq!(10, v.iter_ne().map(|x| x * 2).max());
+        assert_eq!(5, *v.iter_ne().rev().first());
+        assert_eq!(9, *v.iter_ne().chain(&[9]).last());
+        assert_eq!((3, 'a'), v.iter_ne().copied().zip(vec!['a'].try_non_empty().unwrap().into_iter_ne()).first());
+
+        let mut seen = Vec::new();
+        let last = v.iter_ne().cloned().inspect(|x| seen.push(*x)).last();
+        assert_eq!(5, last);
+        assert_eq!(*v, seen);
+    }
+
+    #[test]
+    fn filter_downgrades() {
+        let v = sample();
+        let filtered: Filter<slice::Iter<i32>, _> = v.iter_ne().filter(|x| **x > 10);
+        assert_eq!(0, filtered.count());
+        assert_eq!(vec![8], v.iter_ne().filter_map(|x| if x % 2 == 1 { None } else { Some(x * 2) }).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn into_iterator() {
+        let v = sample();
+        let mut sum = 0;
+        for x in v.iter_ne() {
+            sum += x;
+        }
+        assert_eq!(14, sum);
+        assert_eq!(*v, v.clone().into_iter_ne().into_iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn try_into_non_empty_iter() {
+        assert!(Vec::<i32>::new().into_iter().try_into_non_empty_iter().is_none());
+        assert_eq!(7, vec![7].into_iter().try_into_non_empty_iter().unwrap().first());
+
+        let it = vec![3, 1, 4].into_iter().try_into_non_empty_iter().unwrap();
+        assert_eq!(8, it.clone().reduce(|a, b| a + b));
+        assert_eq!(4, it.clone().max());
+        assert_eq!(vec![3, 1, 4], it.into_iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn try_into_non_empty_iter_is_lazy() {
+        use std::cell::Cell;
+
+        let pulled = Cell::new(0);
+        let source = (1..=3).inspect(|_| pulled.set(pulled.get() + 1));
+        let it = source.try_into_non_empty_iter().unwrap();
+        assert_eq!(1, pulled.get());
+        assert_eq!(6, it.reduce(|a, b| a + b));
+        assert_eq!(3, pulled.get());
+    }
+
+    #[test]
+    fn iterator_chunks_ne() {
+        use std::num::NonZeroUsize;
+
+        fn batches(n: i32, size: usize) -> Vec<Vec<i32>> {
+            (0..n).chunks_ne(NonZeroUsize::new(size).unwrap()).map(NonEmpty::into_inner).collect()
+        }
+
+        assert_eq!(vec![vec![0, 1], vec![2, 3]], batches(4, 2));
+        assert_eq!(vec![vec![0, 1, 2], vec![3]], batches(4, 3));
+        assert_eq!(vec![vec![0, 1]], batches(2, 5));
+        assert!(batches(0, 3).is_empty());
+    }
+}
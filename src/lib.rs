@@ -36,7 +36,7 @@
 //! [Deref](https://doc.rust-lang.org/std/ops/trait.Deref.html),
 //! [AsRef](https://doc.rust-lang.org/std/convert/trait.AsRef.html),
 //! [Borrow](https://doc.rust-lang.org/collections/borrow/trait.Borrow.html)
-//! and [Into](https://doc.rust-lang.org/std/convert/trait.Into.html)
+//! and [From](https://doc.rust-lang.org/std/convert/trait.From.html)
 //! traits to easily access its inner value.
 //!
 //! ```
@@ -74,9 +74,164 @@
 mod is_empty;
 mod non_empty_into;
 mod helper_try_convert;
+#[macro_use]
+mod vec;
+mod iter;
+mod extend;
+mod string;
+mod non_empty_string;
+mod non_empty_vec;
+mod convert;
+mod path;
+mod ops;
+mod cmp;
+mod default;
+mod deref;
+mod entry;
+mod error;
+#[macro_use]
+mod empty_fields;
+#[macro_use]
+mod format;
+mod array;
+mod os_str;
+mod c_str;
+mod hash_map;
+mod hash_set;
+mod btree_map;
+mod btree_set;
+mod vec_deque;
+mod binary_heap;
+mod linked_list;
+mod num;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "arrayvec")]
+mod arrayvec;
+#[cfg(feature = "bytes")]
+mod bytes;
+#[cfg(feature = "camino")]
+mod camino;
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "clap")]
+pub mod clap;
+#[cfg(feature = "compact_str")]
+mod compact_str;
+#[cfg(feature = "dashmap")]
+mod dashmap;
+#[cfg(feature = "either")]
+mod either;
+#[cfg(feature = "futures")]
+mod futures;
+#[cfg(feature = "garde")]
+mod garde;
+#[cfg(feature = "heapless")]
+mod heapless;
+#[cfg(feature = "http")]
+mod http;
+#[cfg(feature = "im")]
+mod im;
+#[cfg(feature = "indexmap")]
+mod indexmap;
+#[cfg(feature = "num-bigint")]
+mod num_bigint;
+#[cfg(feature = "num-traits")]
+mod num_traits;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "rayon")]
+mod rayon;
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "sqlx")]
+mod sqlx;
+#[cfg(feature = "serde_json")]
+mod serde_json;
+#[cfg(feature = "smallvec")]
+mod smallvec;
+#[cfg(feature = "time")]
+mod time;
+#[cfg(feature = "tinyvec")]
+mod tinyvec;
+#[cfg(feature = "toml")]
+mod toml;
+#[cfg(feature = "unicode-segmentation")]
+mod unicode_segmentation;
+#[cfg(feature = "uuid")]
+mod uuid;
+#[cfg(feature = "validator")]
+mod validator;
+#[cfg(feature = "yaml")]
+mod yaml;
+#[cfg(feature = "zeroize")]
+mod zeroize;
 pub use is_empty::IsEmpty;
+pub use empty_fields::EmptyFields;
+#[doc(hidden)]
+pub use empty_fields::{check_field, UnwrapFields};
+#[doc(hidden)]
+pub use format::format_has_literal;
 pub use helper_try_convert::*;
-pub use non_empty_into::*;
+pub use iter::{ChunksNe, NonEmptyIter, TryNonEmptyIterator};
+pub use default::{try_non_default, DefaultIsEmpty};
+pub use deref::EmptyCoherentDeref;
+pub use entry::{BTreeMapEntry, BTreeMapOccupiedEntry, HashMapEntry, HashMapOccupiedEntry};
+pub use error::{ArithmeticError, EmptyError, ParseNeError, WouldBeEmpty};
+pub use non_empty_string::NonEmptyString;
+pub use non_empty_vec::NonEmptyVec;
+pub use num::{CheckedDivNe, DivNe};
+pub use vec::{slice_chunks_ne, slice_windows_ne};
+#[cfg(feature = "arrayvec")]
+pub use arrayvec::{ArrayStringNE, ArrayVecNE};
+#[cfg(feature = "bytes")]
+pub use bytes::{BytesMutNE, BytesNE};
+#[cfg(feature = "camino")]
+pub use camino::{Utf8PathBufNE, Utf8PathNE};
+#[cfg(feature = "chrono")]
+pub use chrono::ChronoDurationNE;
+#[cfg(feature = "compact_str")]
+pub use compact_str::CompactStringNE;
+#[cfg(feature = "dashmap")]
+pub use dashmap::{DashMapNE, DashSetNE};
+#[cfg(feature = "futures")]
+pub use futures::{FilterNonEmpty, NonEmptyStreamExt, TryCollectNonEmpty};
+#[cfg(feature = "heapless")]
+pub use heapless::{HeaplessStringNE, HeaplessVecNE};
+#[cfg(feature = "http")]
+pub use http::{get_non_empty, HeaderMapNE, HeaderValueNE};
+#[cfg(feature = "im")]
+pub use im::{ImHashMapNE, ImHashSetNE, ImOrdMapNE, ImOrdSetNE, ImVectorNE};
+#[cfg(feature = "indexmap")]
+pub use indexmap::{IndexMapNE, IndexSetNE};
+#[cfg(feature = "num-bigint")]
+pub use num_bigint::{BigIntNE, BigUintNE};
+#[cfg(feature = "num-traits")]
+pub use num_traits::{NonZeroOf, ZeroIsEmpty};
+#[cfg(feature = "rayon")]
+pub use rayon::{par_try_non_empty_all, PAR_THRESHOLD};
+#[cfg(feature = "rust_decimal")]
+pub use rust_decimal::DecimalNE;
+#[cfg(feature = "serde_json")]
+pub use serde_json::{prune_empty, JsonValueNE};
+#[cfg(feature = "smallvec")]
+pub use smallvec::SmallVecNE;
+#[cfg(feature = "time")]
+pub use time::TimeDurationNE;
+#[cfg(feature = "tinyvec")]
+pub use tinyvec::{TinyArrayVecNE, TinyVecNE};
+#[cfg(feature = "toml")]
+pub use toml::{prune_empty_toml, TomlValueNE};
+#[cfg(feature = "uuid")]
+pub use uuid::UuidNE;
+#[cfg(feature = "yaml")]
+pub use yaml::{prune_empty_yaml, YamlValueNE};
 
 /// Struct owning a non-empty value.
 ///
@@ -94,8 +249,9 @@ pub use non_empty_into::*;
 /// // use alias-types like StringNE, to improve the readability of the type.
 /// let s: StringNE = "hello".to_string().try_non_empty().unwrap();
 /// ```
-#[derive(Clone, PartialEq, Debug)]
-pub struct NonEmpty<T> {
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[repr(transparent)]
+pub struct NonEmpty<T: ?Sized> {
     inner: T,
 }
 
@@ -104,9 +260,76 @@ impl<T> NonEmpty<T> {
     pub fn into_inner(self) -> T {
         self.inner
     }
+
+    /// Wraps a value without checking it.
+    ///
+    /// # Safety
+    /// The value must not be empty, see
+    /// [as_mut_unchecked](struct.NonEmpty.html#method.as_mut_unchecked).
+    #[inline]
+    pub unsafe fn new_unchecked(inner: T) -> Self {
+        NonEmpty { inner }
+    }
 }
 
-impl<T> AsRef<T> for NonEmpty<T> {
+impl<T: ?Sized> NonEmpty<T> {
+    /// Mutable reference to the inner value, without any check.
+    ///
+    /// `NonEmpty<T>` doesn't implement `DerefMut`, because `*v = Vec::new()`
+    /// would break the invariant. Prefer the safe mutators, they can't
+    /// empty the value: methods like `push` or `Extend` only grow it,
+    /// removals like `pop` return `Err(WouldBeEmpty)` instead of removing
+    /// the last element, and anything else can be done by `into_inner`
+    /// followed by `try_non_empty`.
+    ///
+    /// # Safety
+    /// The caller must not leave the value empty. Code relying on the
+    /// invariant, e.g. `max()` on a `NonEmpty<Vec<T>>`, panics on an
+    /// empty value, and so could code outside of this crate.
+    ///
+    /// # Examples
+    /// ```
+    /// use non_empty::{TryNonEmpty, VecNE};
+    ///
+    /// let mut v: VecNE<u32> = vec![3, 1, 2].try_non_empty().unwrap();
+    /// // sorting keeps all elements
+    /// unsafe { v.as_mut_unchecked().sort_unstable() };
+    /// assert_eq!(vec![1, 2, 3], *v);
+    /// ```
+    #[inline]
+    pub unsafe fn as_mut_unchecked(&mut self) -> &mut T {
+        &mut self.inner
+    }
+}
+
+impl<T: ?Sized + IsEmpty> NonEmpty<T> {
+    /// Borrows a value as `&NonEmpty<T>` or fails if it's empty.
+    ///
+    /// Makes unsized types like `StrNE`, `PathNE`, `OsStrNE` or `SliceNE<T>` usable,
+    /// e.g. as `&StrNE` function parameter.
+    ///
+    /// # Examples
+    /// ```
+    /// use non_empty::{NonEmpty, StrNE};
+    ///
+    /// fn shout(name: &StrNE) -> String {
+    ///     name.to_uppercase()
+    /// }
+    ///
+    /// let name = NonEmpty::from_ref("bob").unwrap();
+    /// assert_eq!("BOB", shout(name));
+    /// assert!(NonEmpty::<str>::from_ref("").is_none());
+    /// ```
+    pub fn from_ref(v: &T) -> Option<&NonEmpty<T>> {
+        if v.is_empty() {
+            return None
+        }
+        // safe, NonEmpty<T> is repr(transparent)
+        Some(unsafe { &*(v as *const T as *const NonEmpty<T>) })
+    }
+}
+
+impl<T: ?Sized> AsRef<T> for NonEmpty<T> {
     /// Reference to the inner type `T`.
     #[inline]
     fn as_ref(&self) -> &T {
@@ -114,7 +337,7 @@ impl<T> AsRef<T> for NonEmpty<T> {
     }
 }
 
-impl<T> std::borrow::Borrow<T> for NonEmpty<T> {
+impl<T: ?Sized> std::borrow::Borrow<T> for NonEmpty<T> {
     /// Reference to the inner type `T`.
     #[inline]
     fn borrow(&self) -> &T {
@@ -137,7 +360,7 @@ impl<T> std::borrow::Borrow<T> for NonEmpty<T> {
 /// // Deref NonEmpty<String> to &str
 /// foobar(&s);
 /// ```
-impl<T> std::ops::Deref for NonEmpty<T> {
+impl<T: ?Sized> std::ops::Deref for NonEmpty<T> {
     type Target = T;
 
     /// Reference to the inner type `T` of the [NonEmpty](struct.NonEmpty.html) struct.
@@ -158,6 +381,18 @@ where
     }
 }
 
+impl<'a, T: ?Sized> std::iter::IntoIterator for &'a NonEmpty<T>
+where
+    &'a T: std::iter::IntoIterator,
+{
+    type Item = <&'a T as std::iter::IntoIterator>::Item;
+    type IntoIter = <&'a T as std::iter::IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self.inner).into_iter()
+    }
+}
+
 /// The only way to create a `NonEmpty<T>` struct.
 ///
 /// # Examples
@@ -180,6 +415,25 @@ where
 pub trait TryNonEmpty: IsEmpty + Sized + Clone {
     /// Only way to create a [NonEmpty](struct.NonEmpty.html) struct.
     fn try_non_empty(self) -> Option<NonEmpty<Self>>;
+
+    /// Like `try_non_empty`, but fails with an error naming the value.
+    ///
+    /// # Examples
+    /// ```
+    /// use non_empty::{EmptyError, NonEmpty, TryNonEmpty};
+    ///
+    /// fn user_name(name: String) -> Result<NonEmpty<String>, EmptyError> {
+    ///     name.non_empty_labeled("user.name")
+    /// }
+    ///
+    /// assert!(user_name("bob".to_string()).is_ok());
+    /// assert_eq!(Some("user.name"), user_name(String::new()).unwrap_err().label());
+    /// ```
+    fn non_empty_labeled<L>(self, label: L) -> Result<NonEmpty<Self>, EmptyError>
+        where L: Into<::std::borrow::Cow<'static, str>>,
+    {
+        self.try_non_empty().ok_or_else(|| EmptyError::labeled(label))
+    }
 }
 
 impl<T> TryNonEmpty for T
@@ -197,6 +451,57 @@ where
     }
 }
 
+/// TryNonEmptyBoxed converts boxed values of any size to a `NonEmpty<Box<T>>`.
+///
+/// Covers what [TryNonEmpty](trait.TryNonEmpty.html) can't, because the
+/// boxed value isn't `Clone`, like a `Box<dyn IsEmpty>` trait object.
+///
+/// # Examples
+/// ```
+/// use non_empty::{IsEmpty, TryNonEmptyBoxed};
+///
+/// trait Shape: IsEmpty {
+///     fn area(&self) -> u32;
+/// }
+///
+/// struct Square(u32);
+///
+/// impl IsEmpty for Square {
+///     fn is_empty(&self) -> bool {
+///         self.0 == 0
+///     }
+/// }
+///
+/// impl Shape for Square {
+///     fn area(&self) -> u32 {
+///         self.0 * self.0
+///     }
+/// }
+///
+/// let s: Box<dyn Shape> = Box::new(Square(2));
+/// assert_eq!(4, s.try_non_empty_boxed().unwrap().area());
+/// let s: Box<dyn Shape> = Box::new(Square(0));
+/// assert!(s.try_non_empty_boxed().is_none());
+/// ```
+pub trait TryNonEmptyBoxed: IsEmpty {
+    /// Wraps the box or fails if its content is empty.
+    fn try_non_empty_boxed(self: Box<Self>) -> Option<NonEmpty<Box<Self>>>;
+}
+
+impl<T> TryNonEmptyBoxed for T
+where
+    T: IsEmpty + ?Sized,
+{
+    #[inline]
+    fn try_non_empty_boxed(self: Box<Self>) -> Option<NonEmpty<Box<T>>> {
+        if !self.is_empty() {
+            Some(NonEmpty { inner: self })
+        } else {
+            None
+        }
+    }
+}
+
 
 /////////////////////////////////////////////////////////////////////////
 // TYPE-ALIASES
@@ -205,13 +510,19 @@ where
 /// Non-empty `String`
 pub type StringNE = NonEmpty<String>;
 
-/// Non-empty `str`
+/// Non-empty `str`, borrow one with `NonEmpty::from_ref`
 pub type StrNE = NonEmpty<str>;
 
-/// Non-empty `OsStr`
+/// Non-empty `OsStr`, borrow one with `NonEmpty::from_ref`
 pub type OsStrNE = NonEmpty<std::ffi::OsStr>;
 
-/// Non-empty `Path`
+/// Non-empty `OsString`
+pub type OsStringNE = NonEmpty<std::ffi::OsString>;
+
+/// Non-empty `CString`
+pub type CStringNE = NonEmpty<std::ffi::CString>;
+
+/// Non-empty `Path`, borrow one with `NonEmpty::from_ref`
 pub type PathNE = NonEmpty<std::path::Path>;
 
 /// Non-empty `PathBuf`
@@ -220,7 +531,7 @@ pub type PathBufNE = NonEmpty<std::path::PathBuf>;
 /// Non-empty `Vec<T>`
 pub type VecNE<T> = NonEmpty<Vec<T>>;
 
-/// Non-empty `[T]`
+/// Non-empty `[T]`, borrow one with `NonEmpty::from_ref`
 pub type SliceNE<T> = NonEmpty<[T]>;
 
 /// Non-empty `HashMap<K, V>`
@@ -292,6 +603,15 @@ pub type f32NE = NonEmpty<f32>;
 #[allow(non_camel_case_types)]
 pub type f64NE = NonEmpty<f64>;
 
+/// Non-empty `Duration`, duration != 0
+pub type DurationNE = NonEmpty<std::time::Duration>;
+
+/// Test helper, wraps `v` and panics if it is empty.
+#[cfg(test)]
+pub(crate) fn ne<T: TryNonEmpty>(v: T) -> NonEmpty<T> {
+    v.try_non_empty().unwrap()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -301,4 +621,87 @@ mod tests {
         assert!("".try_non_empty().is_none());
         assert_eq!("bar", "bar".try_non_empty().unwrap().into_inner());
     }
+
+    #[test]
+    fn duration() {
+        use std::time::Duration;
+        assert!(Duration::ZERO.try_non_empty().is_none());
+        let d: DurationNE = Duration::from_nanos(1).try_non_empty().unwrap();
+        let inner: Duration = d.into();
+        assert_eq!(Duration::from_nanos(1), inner);
+    }
+
+    #[test]
+    fn as_mut_unchecked() {
+        let mut s: StringNE = "ab".to_string().try_non_empty().unwrap();
+        unsafe {
+            s.as_mut_unchecked().make_ascii_uppercase();
+            s.as_mut_unchecked().truncate(1);
+        }
+        assert_eq!("A", *s);
+
+        let mut v: VecNE<u8> = vec![1, 2, 3].try_non_empty().unwrap();
+        unsafe { v.as_mut_unchecked().retain(|&x| x != 2) };
+        assert_eq!(vec![1, 3], *v);
+        assert_eq!(3, *v.max());
+    }
+
+    #[test]
+    fn from_ref_unsized() {
+        use std::ffi::OsStr;
+        use std::path::Path;
+
+        let s: &StrNE = NonEmpty::from_ref("abc").unwrap();
+        assert_eq!("abc", &**s);
+        assert_eq!(3, s.len());
+        assert!(NonEmpty::<str>::from_ref("").is_none());
+        let owned: StringNE = s.into();
+        assert_eq!("abc", *owned);
+
+        let p: &PathNE = NonEmpty::from_ref(Path::new("/tmp")).unwrap();
+        assert!(p.is_absolute());
+        assert!(NonEmpty::from_ref(Path::new("")).is_none());
+        let owned: PathBufNE = p.into();
+        assert_eq!(Path::new("/tmp"), &*owned);
+
+        let o: &OsStrNE = NonEmpty::from_ref(OsStr::new("x")).unwrap();
+        assert_eq!(OsStr::new("x"), &**o);
+        assert!(NonEmpty::from_ref(OsStr::new("")).is_none());
+
+        let b: &SliceNE<u8> = NonEmpty::from_ref(&b"hello"[..]).unwrap();
+        assert_eq!(b'o', *b.max());
+        assert!(NonEmpty::<[u8]>::from_ref(&[]).is_none());
+        let owned: VecNE<u8> = b.into();
+        assert_eq!(b"hello".to_vec(), *owned);
+
+        // borrowed and unsized wrappers convert into each other
+        let s: NonEmpty<&str> = "abc".try_non_empty().unwrap();
+        let r: &StrNE = s.clone().into();
+        let back: NonEmpty<&str> = r.into();
+        assert_eq!(s, back);
+    }
+
+    #[test]
+    fn try_non_empty_boxed() {
+        use std::path::Path;
+
+        let s: Box<str> = Box::from("abc");
+        assert_eq!("abc", &**s.try_non_empty_boxed().unwrap());
+        assert!(Box::<str>::from("").try_non_empty_boxed().is_none());
+
+        let v: Box<[i32]> = vec![1, 2].into_boxed_slice();
+        assert_eq!(2, *v.try_non_empty_boxed().unwrap().max());
+        assert!(Vec::<i32>::new().into_boxed_slice().try_non_empty_boxed().is_none());
+
+        let p: Box<Path> = Path::new("a").into();
+        assert!(p.try_non_empty_boxed().is_some());
+        // boxed values which are Clone work with try_non_empty too
+        let p: Box<Path> = Path::new("").into();
+        assert!(p.try_non_empty().is_none());
+
+        let d: Box<dyn IsEmpty> = Box::new(vec![1]);
+        assert!(d.try_non_empty_boxed().is_some());
+        let d: Box<dyn IsEmpty> = Box::new(String::new());
+        assert!(d.try_non_empty_boxed().is_none());
+    }
 }
@@ -0,0 +1,117 @@
+use std::collections::LinkedList;
+
+use super::{NonEmpty, WouldBeEmpty};
+
+impl<T> NonEmpty<LinkedList<T>> {
+    /// Returns the first element.
+    pub fn front(&self) -> &T {
+        self.inner.front().expect("NonEmpty is never empty")
+    }
+
+    /// Returns the last element.
+    pub fn back(&self) -> &T {
+        self.inner.back().expect("NonEmpty is never empty")
+    }
+
+    /// Prepends an element to the list.
+    #[inline]
+    pub fn push_front(&mut self, value: T) {
+        self.inner.push_front(value)
+    }
+
+    /// Appends an element to the back of the list.
+    #[inline]
+    pub fn push_back(&mut self, value: T) {
+        self.inner.push_back(value)
+    }
+
+    /// Removes and returns the first element.
+    ///
+    /// Returns `Err(WouldBeEmpty)` and leaves the list untouched
+    /// if it's the only element.
+    pub fn pop_front(&mut self) -> Result<T, WouldBeEmpty> {
+        if self.inner.len() == 1 {
+            return Err(WouldBeEmpty)
+        }
+        Ok(self.inner.pop_front().expect("NonEmpty is never empty"))
+    }
+
+    /// Removes and returns the last element.
+    ///
+    /// Returns `Err(WouldBeEmpty)` and leaves the list untouched
+    /// if it's the only element.
+    pub fn pop_back(&mut self) -> Result<T, WouldBeEmpty> {
+        if self.inner.len() == 1 {
+            return Err(WouldBeEmpty)
+        }
+        Ok(self.inner.pop_back().expect("NonEmpty is never empty"))
+    }
+
+    /// Moves all elements from `other` to the end of the list,
+    /// like `LinkedList::append`. Leaves `other` empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::LinkedList;
+    /// use non_empty::TryNonEmpty;
+    ///
+    /// let mut list = vec![1].into_iter().collect::<LinkedList<_>>().try_non_empty().unwrap();
+    /// let mut other = vec![2, 3].into_iter().collect::<LinkedList<_>>();
+    /// list.append(&mut other);
+    ///
+    /// assert_eq!(3, *list.back());
+    /// assert!(other.is_empty());
+    /// ```
+    #[inline]
+    pub fn append(&mut self, other: &mut LinkedList<T>) {
+        self.inner.append(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::LinkedList;
+
+    use super::super::{LinkedListNE, TryNonEmpty, WouldBeEmpty};
+
+    fn list(v: Vec<i32>) -> LinkedListNE<i32> {
+        v.into_iter().collect::<LinkedList<_>>().try_non_empty().unwrap()
+    }
+
+    fn to_vec(l: &LinkedListNE<i32>) -> Vec<i32> {
+        l.iter().cloned().collect()
+    }
+
+    #[test]
+    fn single_element() {
+        let mut l = list(vec![1]);
+        assert_eq!(1, *l.front());
+        assert_eq!(1, *l.back());
+        assert_eq!(Err(WouldBeEmpty), l.pop_front());
+        assert_eq!(Err(WouldBeEmpty), l.pop_back());
+        l.append(&mut LinkedList::new());
+        assert_eq!(vec![1], to_vec(&l));
+    }
+
+    #[test]
+    fn multiple_elements() {
+        let mut l = list(vec![2]);
+        l.push_front(1);
+        l.push_back(3);
+        assert_eq!(1, *l.front());
+        assert_eq!(3, *l.back());
+
+        let mut other = vec![4, 5].into_iter().collect::<LinkedList<_>>();
+        l.append(&mut other);
+        assert!(other.is_empty());
+        assert_eq!(vec![1, 2, 3, 4, 5], to_vec(&l));
+
+        assert_eq!(Ok(1), l.pop_front());
+        assert_eq!(Ok(5), l.pop_back());
+        assert_eq!(Ok(4), l.pop_back());
+        assert_eq!(Ok(2), l.pop_front());
+        assert_eq!(Err(WouldBeEmpty), l.pop_front());
+        assert_eq!(Err(WouldBeEmpty), l.pop_back());
+        assert_eq!(vec![3], to_vec(&l));
+    }
+}
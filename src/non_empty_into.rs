@@ -1,281 +1,453 @@
 use super::NonEmpty;
 
-impl Into<String> for NonEmpty<String> {
+impl From<NonEmpty<String>> for String {
     #[inline]
-    fn into(self) -> String {
-        self.into_inner()
+    fn from(v: NonEmpty<String>) -> Self {
+        v.into_inner()
     }
 }
 
-impl<'a> Into<&'a String> for NonEmpty<&'a String> {
+impl<'a> From<NonEmpty<&'a String>> for &'a String {
     #[inline]
-    fn into(self) -> &'a String {
-        self.into_inner()
+    fn from(v: NonEmpty<&'a String>) -> Self {
+        v.into_inner()
     }
 }
 
-impl<'a> Into<&'a str> for NonEmpty<&'a str> {
+impl<'a> From<NonEmpty<&'a str>> for &'a str {
     #[inline]
-    fn into(self) -> &'a str {
-        self.into_inner()
+    fn from(v: NonEmpty<&'a str>) -> Self {
+        v.into_inner()
     }
 }
 
-impl<'a> Into<&'a ::std::ffi::OsStr> for NonEmpty<&'a ::std::ffi::OsStr> {
+impl<'a> From<NonEmpty<&'a ::std::ffi::OsStr>> for &'a ::std::ffi::OsStr {
     #[inline]
-    fn into(self) -> &'a ::std::ffi::OsStr {
-        self.into_inner()
+    fn from(v: NonEmpty<&'a ::std::ffi::OsStr>) -> Self {
+        v.into_inner()
     }
 }
 
-impl<'a> Into<&'a ::std::path::Path> for NonEmpty<&'a ::std::path::Path> {
+impl From<NonEmpty<::std::ffi::OsString>> for ::std::ffi::OsString {
     #[inline]
-    fn into(self) -> &'a ::std::path::Path {
-        self.into_inner()
+    fn from(v: NonEmpty<::std::ffi::OsString>) -> Self {
+        v.into_inner()
     }
 }
 
-impl Into<::std::path::PathBuf> for NonEmpty<::std::path::PathBuf> {
+impl<'a> From<NonEmpty<&'a ::std::ffi::OsString>> for &'a ::std::ffi::OsString {
     #[inline]
-    fn into(self) -> ::std::path::PathBuf {
-        self.into_inner()
+    fn from(v: NonEmpty<&'a ::std::ffi::OsString>) -> Self {
+        v.into_inner()
     }
 }
 
-impl<'a> Into<&'a ::std::path::PathBuf> for NonEmpty<&'a ::std::path::PathBuf> {
+impl From<NonEmpty<::std::ffi::CString>> for ::std::ffi::CString {
     #[inline]
-    fn into(self) -> &'a ::std::path::PathBuf {
-        self.into_inner()
+    fn from(v: NonEmpty<::std::ffi::CString>) -> Self {
+        v.into_inner()
     }
 }
 
-impl<T> Into<Vec<T>> for NonEmpty<Vec<T>> {
+impl<'a> From<NonEmpty<&'a ::std::ffi::CStr>> for &'a ::std::ffi::CStr {
     #[inline]
-    fn into(self) -> Vec<T> {
-        self.into_inner()
+    fn from(v: NonEmpty<&'a ::std::ffi::CStr>) -> Self {
+        v.into_inner()
     }
 }
 
-impl<'a, T> Into<&'a Vec<T>> for NonEmpty<&'a Vec<T>> {
+impl<'a> From<NonEmpty<&'a ::std::path::Path>> for &'a ::std::path::Path {
     #[inline]
-    fn into(self) -> &'a Vec<T> {
-        self.into_inner()
+    fn from(v: NonEmpty<&'a ::std::path::Path>) -> Self {
+        v.into_inner()
     }
 }
 
-impl<'a, T> Into<&'a [T]> for NonEmpty<&'a [T]> {
+impl From<NonEmpty<::std::path::PathBuf>> for ::std::path::PathBuf {
     #[inline]
-    fn into(self) -> &'a [T] {
-        self.into_inner()
+    fn from(v: NonEmpty<::std::path::PathBuf>) -> Self {
+        v.into_inner()
     }
 }
 
-impl<K, V, S> Into<::std::collections::HashMap<K, V, S>> for
-    NonEmpty<::std::collections::HashMap<K, V, S>>
-{
+impl<'a> From<NonEmpty<&'a ::std::path::PathBuf>> for &'a ::std::path::PathBuf {
     #[inline]
-    fn into(self) -> ::std::collections::HashMap<K, V, S> {
-        self.into_inner()
+    fn from(v: NonEmpty<&'a ::std::path::PathBuf>) -> Self {
+        v.into_inner()
     }
 }
 
-impl<'a, K, V, S> Into<&'a ::std::collections::HashMap<K, V, S>> for
-    NonEmpty<&'a ::std::collections::HashMap<K, V, S>>
-{
+impl<T> From<NonEmpty<Vec<T>>> for Vec<T> {
     #[inline]
-    fn into(self) -> &'a ::std::collections::HashMap<K, V, S> {
-        self.into_inner()
+    fn from(v: NonEmpty<Vec<T>>) -> Self {
+        v.into_inner()
     }
 }
 
-impl<T, S> Into<::std::collections::HashSet<T, S>> for
-    NonEmpty<::std::collections::HashSet<T, S>>
-{
+impl<'a, T> From<NonEmpty<&'a Vec<T>>> for &'a Vec<T> {
     #[inline]
-    fn into(self) -> ::std::collections::HashSet<T, S> {
-        self.into_inner()
+    fn from(v: NonEmpty<&'a Vec<T>>) -> Self {
+        v.into_inner()
     }
 }
 
-impl<'a, T, S> Into<&'a ::std::collections::HashSet<T, S>> for
-    NonEmpty<&'a ::std::collections::HashSet<T, S>>
-{
+impl<'a, T> From<NonEmpty<&'a [T]>> for &'a [T] {
+    #[inline]
+    fn from(v: NonEmpty<&'a [T]>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl From<NonEmpty<Box<str>>> for Box<str> {
+    #[inline]
+    fn from(v: NonEmpty<Box<str>>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl<T> From<NonEmpty<Box<[T]>>> for Box<[T]> {
+    #[inline]
+    fn from(v: NonEmpty<Box<[T]>>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl From<NonEmpty<Box<::std::path::Path>>> for Box<::std::path::Path> {
     #[inline]
-    fn into(self) -> &'a ::std::collections::HashSet<T, S> {
-        self.into_inner()
+    fn from(v: NonEmpty<Box<::std::path::Path>>) -> Self {
+        v.into_inner()
     }
 }
 
-impl<T> Into<::std::collections::LinkedList<T>> for
-    NonEmpty<::std::collections::LinkedList<T>>
+impl From<NonEmpty<Box<::std::ffi::OsStr>>> for Box<::std::ffi::OsStr> {
+    #[inline]
+    fn from(v: NonEmpty<Box<::std::ffi::OsStr>>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl<'a, B> From<NonEmpty<::std::borrow::Cow<'a, B>>> for ::std::borrow::Cow<'a, B>
+    where B: ToOwned + ?Sized,
 {
     #[inline]
-    fn into(self) -> ::std::collections::LinkedList<T> {
-        self.into_inner()
+    fn from(v: NonEmpty<::std::borrow::Cow<'a, B>>) -> Self {
+        v.into_inner()
     }
 }
 
-impl<'a, T> Into<&'a ::std::collections::LinkedList<T>> for
-    NonEmpty<&'a ::std::collections::LinkedList<T>>
+impl<K, V, S> From<NonEmpty<::std::collections::HashMap<K, V, S>>>
+    for ::std::collections::HashMap<K, V, S>
 {
     #[inline]
-    fn into(self) -> &'a ::std::collections::LinkedList<T> {
-        self.into_inner()
+    fn from(v: NonEmpty<::std::collections::HashMap<K, V, S>>) -> Self {
+        v.into_inner()
     }
 }
 
-impl<T> Into<::std::collections::VecDeque<T>> for
-    NonEmpty<::std::collections::VecDeque<T>>
+impl<'a, K, V, S> From<NonEmpty<&'a ::std::collections::HashMap<K, V, S>>>
+    for &'a ::std::collections::HashMap<K, V, S>
 {
     #[inline]
-    fn into(self) -> ::std::collections::VecDeque<T> {
-        self.into_inner()
+    fn from(v: NonEmpty<&'a ::std::collections::HashMap<K, V, S>>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl<T, S> From<NonEmpty<::std::collections::HashSet<T, S>>> for ::std::collections::HashSet<T, S> {
+    #[inline]
+    fn from(v: NonEmpty<::std::collections::HashSet<T, S>>) -> Self {
+        v.into_inner()
     }
 }
 
-impl<'a, T> Into<&'a ::std::collections::VecDeque<T>> for
-    NonEmpty<&'a ::std::collections::VecDeque<T>>
+impl<'a, T, S> From<NonEmpty<&'a ::std::collections::HashSet<T, S>>>
+    for &'a ::std::collections::HashSet<T, S>
 {
     #[inline]
-    fn into(self) -> &'a ::std::collections::VecDeque<T> {
-        self.into_inner()
+    fn from(v: NonEmpty<&'a ::std::collections::HashSet<T, S>>) -> Self {
+        v.into_inner()
     }
 }
 
-impl<K, V> Into<::std::collections::BTreeMap<K, V>> for
-    NonEmpty<::std::collections::BTreeMap<K, V>>
+impl<T> From<NonEmpty<::std::collections::LinkedList<T>>> for ::std::collections::LinkedList<T> {
+    #[inline]
+    fn from(v: NonEmpty<::std::collections::LinkedList<T>>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl<'a, T> From<NonEmpty<&'a ::std::collections::LinkedList<T>>>
+    for &'a ::std::collections::LinkedList<T>
 {
     #[inline]
-    fn into(self) -> ::std::collections::BTreeMap<K, V> {
-        self.into_inner()
+    fn from(v: NonEmpty<&'a ::std::collections::LinkedList<T>>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl<T> From<NonEmpty<::std::collections::VecDeque<T>>> for ::std::collections::VecDeque<T> {
+    #[inline]
+    fn from(v: NonEmpty<::std::collections::VecDeque<T>>) -> Self {
+        v.into_inner()
     }
 }
 
-impl<'a, K, V> Into<&'a ::std::collections::BTreeMap<K, V>> for
-    NonEmpty<&'a ::std::collections::BTreeMap<K, V>>
+impl<'a, T> From<NonEmpty<&'a ::std::collections::VecDeque<T>>>
+    for &'a ::std::collections::VecDeque<T>
 {
     #[inline]
-    fn into(self) -> &'a ::std::collections::BTreeMap<K, V> {
-        self.into_inner()
+    fn from(v: NonEmpty<&'a ::std::collections::VecDeque<T>>) -> Self {
+        v.into_inner()
     }
 }
 
-impl<T> Into<::std::collections::BTreeSet<T>> for
-    NonEmpty<::std::collections::BTreeSet<T>>
+impl<K, V> From<NonEmpty<::std::collections::BTreeMap<K, V>>>
+    for ::std::collections::BTreeMap<K, V>
 {
     #[inline]
-    fn into(self) -> ::std::collections::BTreeSet<T> {
-        self.into_inner()
+    fn from(v: NonEmpty<::std::collections::BTreeMap<K, V>>) -> Self {
+        v.into_inner()
     }
 }
 
-impl<'a, T> Into<&'a ::std::collections::BTreeSet<T>> for
-    NonEmpty<&'a ::std::collections::BTreeSet<T>>
+impl<'a, K, V> From<NonEmpty<&'a ::std::collections::BTreeMap<K, V>>>
+    for &'a ::std::collections::BTreeMap<K, V>
 {
     #[inline]
-    fn into(self) -> &'a ::std::collections::BTreeSet<T> {
-        self.into_inner()
+    fn from(v: NonEmpty<&'a ::std::collections::BTreeMap<K, V>>) -> Self {
+        v.into_inner()
     }
 }
 
-impl<T> Into<::std::collections::BinaryHeap<T>> for
-    NonEmpty<::std::collections::BinaryHeap<T>>
+impl<T> From<NonEmpty<::std::collections::BTreeSet<T>>> for ::std::collections::BTreeSet<T> {
+    #[inline]
+    fn from(v: NonEmpty<::std::collections::BTreeSet<T>>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl<'a, T> From<NonEmpty<&'a ::std::collections::BTreeSet<T>>>
+    for &'a ::std::collections::BTreeSet<T>
 {
     #[inline]
-    fn into(self) -> ::std::collections::BinaryHeap<T> {
-        self.into_inner()
+    fn from(v: NonEmpty<&'a ::std::collections::BTreeSet<T>>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl<T> From<NonEmpty<::std::collections::BinaryHeap<T>>> for ::std::collections::BinaryHeap<T> {
+    #[inline]
+    fn from(v: NonEmpty<::std::collections::BinaryHeap<T>>) -> Self {
+        v.into_inner()
     }
 }
 
-impl<'a, T> Into<&'a ::std::collections::BinaryHeap<T>> for
-    NonEmpty<&'a ::std::collections::BinaryHeap<T>>
+impl<'a, T> From<NonEmpty<&'a ::std::collections::BinaryHeap<T>>>
+    for &'a ::std::collections::BinaryHeap<T>
 {
     #[inline]
-    fn into(self) -> &'a ::std::collections::BinaryHeap<T> {
-        self.into_inner()
+    fn from(v: NonEmpty<&'a ::std::collections::BinaryHeap<T>>) -> Self {
+        v.into_inner()
     }
 }
 
-impl Into<i8> for NonEmpty<i8> {
+impl From<NonEmpty<i8>> for i8 {
     #[inline]
-    fn into(self) -> i8 {
-        self.into_inner()
+    fn from(v: NonEmpty<i8>) -> Self {
+        v.into_inner()
     }
 }
 
-impl Into<i16> for NonEmpty<i16> {
+impl From<NonEmpty<i16>> for i16 {
     #[inline]
-    fn into(self) -> i16 {
-        self.into_inner()
+    fn from(v: NonEmpty<i16>) -> Self {
+        v.into_inner()
     }
 }
 
-impl Into<i32> for NonEmpty<i32> {
+impl From<NonEmpty<i32>> for i32 {
     #[inline]
-    fn into(self) -> i32 {
-        self.into_inner()
+    fn from(v: NonEmpty<i32>) -> Self {
+        v.into_inner()
     }
 }
 
-impl Into<i64> for NonEmpty<i64> {
+impl From<NonEmpty<i64>> for i64 {
     #[inline]
-    fn into(self) -> i64 {
-        self.into_inner()
+    fn from(v: NonEmpty<i64>) -> Self {
+        v.into_inner()
     }
 }
 
-impl Into<u8> for NonEmpty<u8> {
+impl From<NonEmpty<u8>> for u8 {
     #[inline]
-    fn into(self) -> u8 {
-        self.into_inner()
+    fn from(v: NonEmpty<u8>) -> Self {
+        v.into_inner()
     }
 }
 
-impl Into<u16> for NonEmpty<u16> {
+impl From<NonEmpty<u16>> for u16 {
     #[inline]
-    fn into(self) -> u16 {
-        self.into_inner()
+    fn from(v: NonEmpty<u16>) -> Self {
+        v.into_inner()
     }
 }
 
-impl Into<u32> for NonEmpty<u32> {
+impl From<NonEmpty<u32>> for u32 {
     #[inline]
-    fn into(self) -> u32 {
-        self.into_inner()
+    fn from(v: NonEmpty<u32>) -> Self {
+        v.into_inner()
     }
 }
 
-impl Into<u64> for NonEmpty<u64> {
+impl From<NonEmpty<u64>> for u64 {
     #[inline]
-    fn into(self) -> u64 {
-        self.into_inner()
+    fn from(v: NonEmpty<u64>) -> Self {
+        v.into_inner()
     }
 }
 
-impl Into<isize> for NonEmpty<isize> {
+impl From<NonEmpty<isize>> for isize {
     #[inline]
-    fn into(self) -> isize {
-        self.into_inner()
+    fn from(v: NonEmpty<isize>) -> Self {
+        v.into_inner()
     }
 }
 
-impl Into<usize> for NonEmpty<usize> {
+impl From<NonEmpty<usize>> for usize {
     #[inline]
-    fn into(self) -> usize {
-        self.into_inner()
+    fn from(v: NonEmpty<usize>) -> Self {
+        v.into_inner()
     }
 }
 
-impl Into<f32> for NonEmpty<f32> {
+impl From<NonEmpty<f32>> for f32 {
     #[inline]
-    fn into(self) -> f32 {
-        self.into_inner()
+    fn from(v: NonEmpty<f32>) -> Self {
+        v.into_inner()
     }
 }
 
-impl Into<f64> for NonEmpty<f64> {
+impl From<NonEmpty<f64>> for f64 {
     #[inline]
-    fn into(self) -> f64 {
-        self.into_inner()
+    fn from(v: NonEmpty<f64>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl From<NonEmpty<::std::time::Duration>> for ::std::time::Duration {
+    #[inline]
+    fn from(v: NonEmpty<::std::time::Duration>) -> Self {
+        v.into_inner()
+    }
+}
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+    use std::ffi::{CStr, CString, OsStr, OsString};
+    use std::path::{Path, PathBuf};
+
+    use super::super::TryNonEmpty;
+
+    /// Checks `X::from(ne)` and `ne.into()` for a non-empty value of type `X`.
+    macro_rules! check_from_into {
+        ($t:ty, $v:expr) => {{
+            let v: $t = $v;
+            let c: $t = Clone::clone(&v);
+            let ne = c.try_non_empty().unwrap();
+            assert_eq!(v, <$t>::from(ne.clone()));
+            let into: $t = ne.into();
+            assert_eq!(v, into);
+        }};
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn strings() {
+        let s = "a".to_string();
+        check_from_into!(String, s.clone());
+        check_from_into!(&String, &s);
+        check_from_into!(&str, "a");
+        check_from_into!(Cow<str>, Cow::Borrowed("a"));
+        check_from_into!(Box<str>, Box::from("a"));
+
+        let os = OsString::from("a");
+        check_from_into!(OsString, os.clone());
+        check_from_into!(&OsString, &os);
+        check_from_into!(&OsStr, OsStr::new("a"));
+        check_from_into!(Box<OsStr>, OsStr::new("a").into());
+
+        let c = CString::new("a").unwrap();
+        check_from_into!(CString, c.clone());
+        check_from_into!(&CStr, c.as_c_str());
+    }
+
+    #[test]
+    fn paths() {
+        let p = PathBuf::from("a");
+        check_from_into!(PathBuf, p.clone());
+        check_from_into!(&PathBuf, &p);
+        check_from_into!(&Path, Path::new("a"));
+        check_from_into!(Box<Path>, Path::new("a").into());
+    }
+
+    #[test]
+    fn collections() {
+        let v = vec![1];
+        check_from_into!(Vec<i32>, v.clone());
+        check_from_into!(&Vec<i32>, &v);
+        check_from_into!(&[i32], &v[..]);
+        check_from_into!(Box<[i32]>, v.clone().into_boxed_slice());
+
+        let m: HashMap<i32, i32> = vec![(1, 2)].into_iter().collect();
+        check_from_into!(HashMap<i32, i32>, m.clone());
+        check_from_into!(&HashMap<i32, i32>, &m);
+
+        let s: HashSet<i32> = vec![1].into_iter().collect();
+        check_from_into!(HashSet<i32>, s.clone());
+        check_from_into!(&HashSet<i32>, &s);
+
+        let l: LinkedList<i32> = vec![1].into_iter().collect();
+        check_from_into!(LinkedList<i32>, l.clone());
+        check_from_into!(&LinkedList<i32>, &l);
+
+        let d: VecDeque<i32> = vec![1].into_iter().collect();
+        check_from_into!(VecDeque<i32>, d.clone());
+        check_from_into!(&VecDeque<i32>, &d);
+
+        let m: BTreeMap<i32, i32> = vec![(1, 2)].into_iter().collect();
+        check_from_into!(BTreeMap<i32, i32>, m.clone());
+        check_from_into!(&BTreeMap<i32, i32>, &m);
+
+        let s: BTreeSet<i32> = vec![1].into_iter().collect();
+        check_from_into!(BTreeSet<i32>, s.clone());
+        check_from_into!(&BTreeSet<i32>, &s);
+
+        // BinaryHeap isn't PartialEq
+        let h: BinaryHeap<i32> = vec![1].into_iter().collect();
+        let ne = h.try_non_empty().unwrap();
+        assert_eq!(vec![1], BinaryHeap::from(ne.clone()).into_vec());
+        let into: BinaryHeap<i32> = ne.clone().into();
+        assert_eq!(vec![1], into.into_vec());
+        let r: &BinaryHeap<i32> = (&*ne).try_non_empty().unwrap().into();
+        assert_eq!(Some(&1), r.peek());
+        assert_eq!(Some(&1), <&BinaryHeap<i32>>::from((&*ne).try_non_empty().unwrap()).peek());
+    }
+
+    #[test]
+    fn numbers() {
+        check_from_into!(i8, -1);
+        check_from_into!(i16, -1);
+        check_from_into!(i32, -1);
+        check_from_into!(i64, -1);
+        check_from_into!(isize, -1);
+        check_from_into!(u8, 1);
+        check_from_into!(u16, 1);
+        check_from_into!(u32, 1);
+        check_from_into!(u64, 1);
+        check_from_into!(usize, 1);
+        check_from_into!(f32, 0.5);
+        check_from_into!(f64, 0.5);
+        check_from_into!(::std::time::Duration, ::std::time::Duration::from_secs(1));
+    }
+}
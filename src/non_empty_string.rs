@@ -0,0 +1,356 @@
+use std::borrow::Borrow;
+use std::fmt;
+use std::num::NonZeroUsize;
+use std::ops::Deref;
+use std::str::FromStr;
+
+use super::{EmptyError, NonEmpty, WouldBeEmpty};
+
+/// Non-empty string with a curated `String` API.
+///
+/// Unlike [StringNE](type.StringNE.html) the type itself is the string,
+/// mutating methods which could empty it return `Err(WouldBeEmpty)` instead.
+/// Converting from and to `NonEmpty<String>` is free.
+///
+/// # Examples
+/// ```
+/// use non_empty::{NonEmptyString, WouldBeEmpty};
+///
+/// let mut s: NonEmptyString = "ab".parse().unwrap();
+/// s.push('c');
+/// assert_eq!(Ok('c'), s.pop());
+/// assert_eq!(Ok(()), s.truncate(1));
+/// assert_eq!(Err(WouldBeEmpty), s.pop());
+/// assert_eq!("a", s.to_string());
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyString {
+    inner: String,
+}
+
+impl NonEmptyString {
+    capacity_methods!(exact);
+
+    /// Wraps `s`, returns `None` if it is empty.
+    pub fn new(s: String) -> Option<Self> {
+        if s.is_empty() {
+            return None
+        }
+        Some(NonEmptyString { inner: s })
+    }
+
+    /// Returns the length in bytes.
+    #[inline]
+    pub fn len(&self) -> NonZeroUsize {
+        NonZeroUsize::new(self.inner.len()).expect("NonEmpty is never empty")
+    }
+
+    /// Returns the capacity in bytes, like `String::capacity`.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    /// Borrows the string as a `&str`.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.inner
+    }
+
+    /// Borrows the string as a `NonEmpty<&str>`.
+    #[inline]
+    pub fn as_str_ne(&self) -> NonEmpty<&str> {
+        NonEmpty { inner: &self.inner }
+    }
+
+    /// Consumes the wrapper and returns the `String`.
+    #[inline]
+    pub fn into_string(self) -> String {
+        self.inner
+    }
+
+    /// Appends the `char` `c` to the end of the string.
+    #[inline]
+    pub fn push(&mut self, c: char) {
+        self.inner.push(c)
+    }
+
+    /// Appends the string slice `s` to the end of the string.
+    #[inline]
+    pub fn push_str(&mut self, s: &str) {
+        self.inner.push_str(s)
+    }
+
+    /// Inserts the `char` `c` at the byte position `idx`.
+    ///
+    /// # Panics
+    /// Panics if `idx` is larger than the length or doesn't lie on a
+    /// `char` boundary, like `String::insert`.
+    #[inline]
+    pub fn insert(&mut self, idx: usize, c: char) {
+        self.inner.insert(idx, c)
+    }
+
+    /// Inserts the string slice `s` at the byte position `idx`.
+    ///
+    /// # Panics
+    /// Panics if `idx` is larger than the length or doesn't lie on a
+    /// `char` boundary, like `String::insert_str`.
+    #[inline]
+    pub fn insert_str(&mut self, idx: usize, s: &str) {
+        self.inner.insert_str(idx, s)
+    }
+
+    /// Removes the last `char` and returns it.
+    ///
+    /// Returns `Err(WouldBeEmpty)` and leaves the string untouched
+    /// if it consists of a single `char`.
+    pub fn pop(&mut self) -> Result<char, WouldBeEmpty> {
+        if self.is_single_char() {
+            return Err(WouldBeEmpty)
+        }
+        Ok(self.inner.pop().expect("NonEmpty is never empty"))
+    }
+
+    /// Removes the `char` at the byte position `idx` and returns it.
+    ///
+    /// Returns `Err(WouldBeEmpty)` and leaves the string untouched
+    /// if it consists of a single `char` and `idx` is 0.
+    ///
+    /// # Panics
+    /// Panics if `idx` is not smaller than the length or doesn't lie on a
+    /// `char` boundary, like `String::remove`.
+    pub fn remove(&mut self, idx: usize) -> Result<char, WouldBeEmpty> {
+        if idx == 0 && self.is_single_char() {
+            return Err(WouldBeEmpty)
+        }
+        Ok(self.inner.remove(idx))
+    }
+
+    /// Shortens the string to `new_len` bytes, like `String::truncate`.
+    ///
+    /// Returns `Err(WouldBeEmpty)` and leaves the string untouched if `new_len` is 0.
+    ///
+    /// # Panics
+    /// Panics if `new_len` doesn't lie on a `char` boundary, like `String::truncate`.
+    pub fn truncate(&mut self, new_len: usize) -> Result<(), WouldBeEmpty> {
+        if new_len == 0 {
+            return Err(WouldBeEmpty)
+        }
+        self.inner.truncate(new_len);
+        Ok(())
+    }
+
+    fn is_single_char(&self) -> bool {
+        self.inner.chars().nth(1).is_none()
+    }
+}
+
+impl Deref for NonEmptyString {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        &self.inner
+    }
+}
+
+impl AsRef<str> for NonEmptyString {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &self.inner
+    }
+}
+
+impl Borrow<str> for NonEmptyString {
+    #[inline]
+    fn borrow(&self) -> &str {
+        &self.inner
+    }
+}
+
+/// Formats like a `StringNE`.
+impl fmt::Debug for NonEmptyString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.as_str_ne(), f)
+    }
+}
+
+impl fmt::Display for NonEmptyString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
+/// Fails with an [EmptyError](struct.EmptyError.html) on an empty string.
+impl FromStr for NonEmptyString {
+    type Err = EmptyError;
+
+    fn from_str(s: &str) -> Result<Self, EmptyError> {
+        NonEmptyString::new(s.to_string()).ok_or_else(EmptyError::new)
+    }
+}
+
+impl PartialEq<str> for NonEmptyString {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.inner == other
+    }
+}
+
+impl<'a> PartialEq<&'a str> for NonEmptyString {
+    #[inline]
+    fn eq(&self, other: &&'a str) -> bool {
+        self.inner == *other
+    }
+}
+
+impl PartialEq<String> for NonEmptyString {
+    #[inline]
+    fn eq(&self, other: &String) -> bool {
+        self.inner == *other
+    }
+}
+
+impl PartialEq<NonEmptyString> for str {
+    #[inline]
+    fn eq(&self, other: &NonEmptyString) -> bool {
+        *self == *other.inner
+    }
+}
+
+impl PartialEq<NonEmptyString> for &str {
+    #[inline]
+    fn eq(&self, other: &NonEmptyString) -> bool {
+        **self == *other.inner
+    }
+}
+
+impl PartialEq<NonEmptyString> for String {
+    #[inline]
+    fn eq(&self, other: &NonEmptyString) -> bool {
+        *self == other.inner
+    }
+}
+
+impl From<NonEmpty<String>> for NonEmptyString {
+    #[inline]
+    fn from(v: NonEmpty<String>) -> Self {
+        NonEmptyString { inner: v.inner }
+    }
+}
+
+impl<'a> From<NonEmpty<&'a str>> for NonEmptyString {
+    #[inline]
+    fn from(v: NonEmpty<&'a str>) -> Self {
+        NonEmptyString { inner: v.inner.to_string() }
+    }
+}
+
+impl From<NonEmptyString> for NonEmpty<String> {
+    #[inline]
+    fn from(v: NonEmptyString) -> Self {
+        NonEmpty { inner: v.inner }
+    }
+}
+
+impl From<NonEmptyString> for String {
+    #[inline]
+    fn from(v: NonEmptyString) -> Self {
+        v.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::panic::catch_unwind;
+
+    use super::NonEmptyString;
+    use super::super::{EmptyError, NonEmpty, StringNE, TryNonEmpty, WouldBeEmpty};
+
+    fn s(v: &str) -> NonEmptyString {
+        v.parse().unwrap()
+    }
+
+    #[test]
+    fn construction() {
+        assert_eq!(None, NonEmptyString::new(String::new()));
+        assert_eq!(Err(EmptyError::new()), "".parse::<NonEmptyString>());
+        assert_eq!("a", NonEmptyString::new("a".to_string()).unwrap());
+        assert_eq!(3, s("äb").len().get());
+    }
+
+    #[test]
+    fn growing() {
+        let mut v = s("b");
+        v.push('c');
+        v.push_str("de");
+        v.insert(0, 'a');
+        v.insert_str(5, "fg");
+        assert_eq!("abcdefg", v);
+        v.reserve(100);
+        assert!(v.capacity() >= 107);
+        v.shrink_to_fit();
+        assert_eq!("abcdefg", v.as_str());
+    }
+
+    #[test]
+    fn pop() {
+        let mut v = s("aé");
+        assert_eq!(Ok('é'), v.pop());
+        assert_eq!(Err(WouldBeEmpty), v.pop());
+        assert_eq!("a", v);
+
+        let mut v = s("é");
+        assert_eq!(Err(WouldBeEmpty), v.pop());
+        assert_eq!("é", v);
+    }
+
+    #[test]
+    fn remove() {
+        let mut v = s("abc");
+        assert_eq!(Ok('b'), v.remove(1));
+        assert_eq!(Ok('a'), v.remove(0));
+        assert_eq!(Err(WouldBeEmpty), v.remove(0));
+        assert_eq!("c", v);
+        assert!(catch_unwind(|| s("c").remove(1)).is_err());
+    }
+
+    #[test]
+    fn truncate() {
+        let mut v = s("abc");
+        assert_eq!(Err(WouldBeEmpty), v.truncate(0));
+        assert_eq!(Ok(()), v.truncate(10));
+        assert_eq!("abc", v);
+        assert_eq!(Ok(()), v.truncate(1));
+        assert_eq!("a", v);
+        assert!(catch_unwind(|| s("é").truncate(1)).is_err());
+    }
+
+    #[test]
+    fn conversions() {
+        let ne: StringNE = "abc".to_string().try_non_empty().unwrap();
+        let v = NonEmptyString::from(ne.clone());
+        assert_eq!(*ne, *v);
+        assert_eq!(ne, NonEmpty::<String>::from(v.clone()));
+        assert_eq!("abc", String::from(v.clone()));
+        assert_eq!(v, NonEmptyString::from("abc".try_non_empty().unwrap()));
+        assert_eq!(v.as_str_ne(), ne.as_str_ne());
+        assert_eq!("abc", v.into_string());
+    }
+
+    #[test]
+    fn trait_parity_with_string_ne() {
+        let ne: StringNE = "abc".to_string().try_non_empty().unwrap();
+        let v = s("abc");
+        assert_eq!(format!("{:?}", ne), format!("{:?}", v));
+        assert_eq!(ne.to_string(), v.to_string());
+        assert_eq!(ne.to_uppercase(), v.to_uppercase());
+        assert!(v < s("abd"));
+
+        let mut set = HashSet::new();
+        set.insert(v);
+        assert!(set.contains("abc"));
+    }
+}
@@ -0,0 +1,234 @@
+use std::iter::{self, Chain, Once};
+use std::num::NonZeroUsize;
+use std::slice;
+use std::vec;
+
+use super::{NonEmpty, NonEmptyIter, WouldBeEmpty};
+
+/// Non-empty vector which stores its first element separately.
+///
+/// The non-emptiness is structural, there is always a `head`, so
+/// no method ever has to check for emptiness. Use it for algorithmic code
+/// which builds and takes apart non-empty sequences, use
+/// [VecNE](type.VecNE.html) where a contiguous `Vec` is needed.
+///
+/// # Examples
+/// ```
+/// use non_empty::NonEmptyVec;
+///
+/// let mut v = NonEmptyVec::new(1);
+/// v.push(2);
+/// v.push(3);
+/// assert_eq!(&1, v.first());
+/// assert_eq!(&3, v.last());
+/// assert_eq!(vec![2, 4, 6], v.map(|n| n * 2).to_vec());
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct NonEmptyVec<T> {
+    /// The first element.
+    pub head: T,
+    /// The remaining elements, may be empty.
+    pub tail: Vec<T>,
+}
+
+impl<T> NonEmptyVec<T> {
+    /// Creates a vector with a single element.
+    pub fn new(head: T) -> Self {
+        NonEmptyVec { head, tail: Vec::new() }
+    }
+
+    /// Creates a vector from its first element and the remaining elements.
+    pub fn from_head_tail(head: T, tail: Vec<T>) -> Self {
+        NonEmptyVec { head, tail }
+    }
+
+    /// Moves the elements out of `v`, returns `None` if it is empty.
+    ///
+    /// This is O(n) like [into_head_tail](struct.NonEmpty.html#method.into_head_tail),
+    /// prefer [from_head_tail](#method.from_head_tail) when the head is at hand.
+    pub fn from_vec(v: Vec<T>) -> Option<Self> {
+        if v.is_empty() {
+            return None
+        }
+        let (head, tail) = NonEmpty { inner: v }.into_head_tail();
+        Some(NonEmptyVec { head, tail })
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> NonZeroUsize {
+        NonZeroUsize::new(self.tail.len() + 1).expect("NonEmpty is never empty")
+    }
+
+    /// Returns the first element.
+    #[inline]
+    pub fn first(&self) -> &T {
+        &self.head
+    }
+
+    /// Returns the first element mutably.
+    #[inline]
+    pub fn first_mut(&mut self) -> &mut T {
+        &mut self.head
+    }
+
+    /// Returns the last element.
+    pub fn last(&self) -> &T {
+        self.tail.last().unwrap_or(&self.head)
+    }
+
+    /// Returns the last element mutably.
+    pub fn last_mut(&mut self) -> &mut T {
+        self.tail.last_mut().unwrap_or(&mut self.head)
+    }
+
+    /// Returns the element at `index`, `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        match index {
+            0 => Some(&self.head),
+            _ => self.tail.get(index - 1),
+        }
+    }
+
+    /// Appends an element.
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        self.tail.push(value)
+    }
+
+    /// Removes the last element and returns it.
+    ///
+    /// Returns `Err(WouldBeEmpty)` if only the head is left.
+    pub fn pop(&mut self) -> Result<T, WouldBeEmpty> {
+        self.tail.pop().ok_or(WouldBeEmpty)
+    }
+
+    /// Returns an iterator over the elements, the head first.
+    pub fn iter(&self) -> Chain<Once<&T>, slice::Iter<'_, T>> {
+        iter::once(&self.head).chain(self.tail.iter())
+    }
+
+    /// Returns an iterator over the elements, guaranteed to yield at least one item.
+    pub fn iter_ne(&self) -> NonEmptyIter<Chain<Once<&T>, slice::Iter<'_, T>>> {
+        NonEmptyIter::new_unchecked(self.iter())
+    }
+
+    /// Consumes the vector into an iterator, guaranteed to yield at least one item.
+    pub fn into_iter_ne(self) -> NonEmptyIter<Chain<Once<T>, vec::IntoIter<T>>> {
+        NonEmptyIter::new_unchecked(self.into_iter())
+    }
+
+    /// Applies `f` to every element, the head first.
+    pub fn map<U, F>(self, mut f: F) -> NonEmptyVec<U>
+        where F: FnMut(T) -> U,
+    {
+        let head = f(self.head);
+        NonEmptyVec { head, tail: self.tail.into_iter().map(f).collect() }
+    }
+
+    /// Copies the elements into a `Vec`.
+    pub fn to_vec(&self) -> Vec<T>
+        where T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T> IntoIterator for NonEmptyVec<T> {
+    type Item = T;
+    type IntoIter = Chain<Once<T>, vec::IntoIter<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        iter::once(self.head).chain(self.tail)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a NonEmptyVec<T> {
+    type Item = &'a T;
+    type IntoIter = Chain<Once<&'a T>, slice::Iter<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> Extend<T> for NonEmptyVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.tail.extend(iter)
+    }
+}
+
+/// Moves the first element out of the vector, which shifts the remaining elements, O(n).
+impl<T> From<NonEmpty<Vec<T>>> for NonEmptyVec<T> {
+    fn from(v: NonEmpty<Vec<T>>) -> Self {
+        let (head, tail) = v.into_head_tail();
+        NonEmptyVec { head, tail }
+    }
+}
+
+impl<T> From<NonEmptyVec<T>> for NonEmpty<Vec<T>> {
+    fn from(v: NonEmptyVec<T>) -> Self {
+        NonEmpty::from_head_tail(v.head, v.tail)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NonEmptyVec;
+    use super::super::{TryNonEmpty, VecNE, WouldBeEmpty};
+
+    #[test]
+    fn single_element() {
+        let mut v = NonEmptyVec::new("a");
+        assert_eq!(1, v.len().get());
+        assert_eq!(&"a", v.first());
+        assert_eq!(&"a", v.last());
+        assert_eq!(Err(WouldBeEmpty), v.pop());
+        *v.last_mut() = "b";
+        assert_eq!("b", v.head);
+        assert_eq!(None, v.get(1));
+    }
+
+    #[test]
+    fn push_and_pop() {
+        let mut v = NonEmptyVec::new(1);
+        v.push(2);
+        v.extend(vec![3, 4]);
+        assert_eq!(4, v.len().get());
+        assert_eq!(&4, v.last());
+        assert_eq!(Some(&3), v.get(2));
+        assert_eq!(Ok(4), v.pop());
+        assert_eq!(Ok(3), v.pop());
+        assert_eq!(Ok(2), v.pop());
+        assert_eq!(Err(WouldBeEmpty), v.pop());
+        assert_eq!(NonEmptyVec::new(1), v);
+    }
+
+    #[test]
+    fn iteration_order() {
+        let v = NonEmptyVec::from_head_tail(1, vec![2, 3]);
+        assert_eq!(vec![&1, &2, &3], v.iter().collect::<Vec<_>>());
+        assert_eq!(3, *v.iter_ne().max());
+        let mut sum = 0;
+        for n in &v {
+            sum = sum * 10 + n;
+        }
+        assert_eq!(123, sum);
+        assert_eq!(vec!["1", "2", "3"], v.clone().map(|n| n.to_string()).to_vec());
+        assert_eq!(3, v.clone().into_iter_ne().last());
+        assert_eq!(vec![1, 2, 3], v.into_iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(None, NonEmptyVec::<i32>::from_vec(vec![]));
+        assert_eq!(Some(NonEmptyVec::from_head_tail(1, vec![2])), NonEmptyVec::from_vec(vec![1, 2]));
+
+        let ne: VecNE<i32> = vec![1, 2, 3].try_non_empty().unwrap();
+        let v = NonEmptyVec::from(ne.clone());
+        assert_eq!((1, vec![2, 3]), (v.head, v.tail.clone()));
+        assert_eq!(ne, VecNE::from(v));
+
+        let one = NonEmptyVec::new('x');
+        assert_eq!(one, NonEmptyVec::from(VecNE::from(one.clone())));
+    }
+}
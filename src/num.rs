@@ -0,0 +1,407 @@
+use std::iter::{Product, Sum};
+use std::ops::Neg;
+
+use super::{ArithmeticError, NonEmpty};
+
+/////////////////////////////////////////////////////////////////////////
+// Checked arithmetic on non-zero integers
+/////////////////////////////////////////////////////////////////////////
+
+macro_rules! int_methods {
+    ($($t:ty)*) => ($(
+        impl NonEmpty<$t> {
+            /// Checked integer addition.
+            ///
+            /// Returns `Err(ArithmeticError::Overflow)` on overflow and
+            /// `Err(ArithmeticError::Zero)` if the result is zero.
+            pub fn checked_add(self, rhs: $t) -> Result<NonEmpty<$t>, ArithmeticError> {
+                non_zero(self.inner.checked_add(rhs))
+            }
+
+            /// Checked integer subtraction.
+            ///
+            /// Returns `Err(ArithmeticError::Overflow)` on overflow and
+            /// `Err(ArithmeticError::Zero)` if the result is zero.
+            pub fn checked_sub(self, rhs: $t) -> Result<NonEmpty<$t>, ArithmeticError> {
+                non_zero(self.inner.checked_sub(rhs))
+            }
+
+            /// Checked addition of two non-zero integers, see [checked_add](#method.checked_add).
+            pub fn checked_add_ne(self, rhs: NonEmpty<$t>) -> Result<NonEmpty<$t>, ArithmeticError> {
+                self.checked_add(rhs.inner)
+            }
+
+            /// Checked subtraction of two non-zero integers, see [checked_sub](#method.checked_sub).
+            pub fn checked_sub_ne(self, rhs: NonEmpty<$t>) -> Result<NonEmpty<$t>, ArithmeticError> {
+                self.checked_sub(rhs.inner)
+            }
+
+            /// Checked multiplication of two non-zero integers.
+            ///
+            /// Returns `None` only on overflow, the product of two
+            /// non-zero integers is never zero.
+            pub fn checked_mul(self, rhs: NonEmpty<$t>) -> Option<NonEmpty<$t>> {
+                self.inner.checked_mul(rhs.inner).map(|inner| NonEmpty { inner })
+            }
+
+            /// Saturating multiplication of two non-zero integers.
+            ///
+            /// Saturates at `MIN` or `MAX`, which are both non-zero.
+            pub fn saturating_mul_ne(self, rhs: NonEmpty<$t>) -> NonEmpty<$t> {
+                NonEmpty { inner: self.inner.saturating_mul(rhs.inner) }
+            }
+
+            /// Checked exponentiation, returns `None` only on overflow.
+            ///
+            /// An exponent of zero results in one.
+            pub fn pow_ne(self, exp: u32) -> Option<NonEmpty<$t>> {
+                self.inner.checked_pow(exp).map(|inner| NonEmpty { inner })
+            }
+        }
+    )*)
+}
+
+int_methods! { i8 i16 i32 i64 isize u8 u16 u32 u64 usize }
+
+macro_rules! signed_methods {
+    ($($t:ty)*) => ($(
+        impl NonEmpty<$t> {
+            /// Checked negation, returns `None` only for `MIN`, which can't be negated.
+            pub fn checked_neg(self) -> Option<NonEmpty<$t>> {
+                self.inner.checked_neg().map(|inner| NonEmpty { inner })
+            }
+        }
+    )*)
+}
+
+signed_methods! { i8 i16 i32 i64 isize }
+
+macro_rules! neg_impls {
+    ($($t:ty)*) => ($(
+        /// Negating a non-zero number never results in zero.
+        ///
+        /// Like the primitive `-` operator, negating an integer `MIN` panics in debug mode.
+        impl Neg for NonEmpty<$t> {
+            type Output = NonEmpty<$t>;
+
+            #[inline]
+            fn neg(self) -> NonEmpty<$t> {
+                NonEmpty { inner: -self.inner }
+            }
+        }
+    )*)
+}
+
+neg_impls! { i8 i16 i32 i64 isize f32 f64 }
+
+/////////////////////////////////////////////////////////////////////////
+// Division by non-zero integers
+/////////////////////////////////////////////////////////////////////////
+
+/// Division and remainder which can't panic on a zero divisor,
+/// because the divisor is a non-zero integer.
+///
+/// # Examples
+/// ```
+/// use non_empty::{DivNe, TryNonEmpty, u32NE};
+///
+/// let divisor: u32NE = 3.try_non_empty().unwrap();
+/// assert_eq!(3, 10_u32.div_ne(divisor.clone()));
+/// assert_eq!(1, 10_u32.rem_ne(divisor));
+///
+/// // the `/` operator can't rule out a zero divisor at compile time
+/// let zero = "0".parse::<u32>().unwrap();
+/// assert!(std::panic::catch_unwind(|| 10_u32 / zero).is_err());
+///
+/// // and a zero divisor is rejected before dividing
+/// assert!(zero.try_non_empty().is_none());
+/// ```
+pub trait DivNe: Sized {
+    /// Divides by a non-zero integer.
+    ///
+    /// # Panics
+    /// Panics for signed integers if `self` is `MIN` and the divisor is `-1`,
+    /// use [CheckedDivNe](trait.CheckedDivNe.html) to handle this case.
+    fn div_ne(self, divisor: NonEmpty<Self>) -> Self;
+
+    /// Calculates the remainder of a division by a non-zero integer.
+    ///
+    /// # Panics
+    /// Panics for signed integers if `self` is `MIN` and the divisor is `-1`.
+    fn rem_ne(self, divisor: NonEmpty<Self>) -> Self;
+}
+
+/// Checked division of signed integers by a non-zero integer.
+///
+/// The only case left to check is `MIN / -1`, which overflows.
+pub trait CheckedDivNe: Sized {
+    /// Divides by a non-zero integer, returns `None` only on overflow.
+    fn checked_div_ne(self, divisor: NonEmpty<Self>) -> Option<Self>;
+
+    /// Calculates the remainder of a division by a non-zero integer,
+    /// returns `None` only on overflow.
+    fn checked_rem_ne(self, divisor: NonEmpty<Self>) -> Option<Self>;
+}
+
+macro_rules! div_impls {
+    ($($t:ty)*) => ($(
+        impl DivNe for $t {
+            #[inline]
+            fn div_ne(self, divisor: NonEmpty<$t>) -> $t {
+                self / divisor.inner
+            }
+
+            #[inline]
+            fn rem_ne(self, divisor: NonEmpty<$t>) -> $t {
+                self % divisor.inner
+            }
+        }
+    )*)
+}
+
+div_impls! { i8 i16 i32 i64 isize u8 u16 u32 u64 usize }
+
+macro_rules! checked_div_impls {
+    ($($t:ty)*) => ($(
+        impl CheckedDivNe for $t {
+            #[inline]
+            fn checked_div_ne(self, divisor: NonEmpty<$t>) -> Option<$t> {
+                self.checked_div(divisor.inner)
+            }
+
+            #[inline]
+            fn checked_rem_ne(self, divisor: NonEmpty<$t>) -> Option<$t> {
+                self.checked_rem(divisor.inner)
+            }
+        }
+    )*)
+}
+
+checked_div_impls! { i8 i16 i32 i64 isize }
+
+/////////////////////////////////////////////////////////////////////////
+// Sum and Product into plain numbers
+/////////////////////////////////////////////////////////////////////////
+
+macro_rules! sum_product_impls {
+    ($($t:ty)*) => ($(
+        /// Sums into a plain number, because the sum of non-zero
+        /// signed numbers can be zero, e.g. `1 + -1`, and an empty
+        /// iterator sums to zero as well.
+        impl Sum<NonEmpty<$t>> for $t {
+            #[inline]
+            fn sum<I: Iterator<Item = NonEmpty<$t>>>(iter: I) -> $t {
+                iter.map(|v| v.inner).sum()
+            }
+        }
+
+        /// Sums into a plain number, see the impl for owned values.
+        impl<'a> Sum<&'a NonEmpty<$t>> for $t {
+            #[inline]
+            fn sum<I: Iterator<Item = &'a NonEmpty<$t>>>(iter: I) -> $t {
+                iter.map(|v| v.inner).sum()
+            }
+        }
+
+        /// Multiplies into a plain number, because an empty iterator
+        /// results in one and integer products may overflow.
+        impl Product<NonEmpty<$t>> for $t {
+            #[inline]
+            fn product<I: Iterator<Item = NonEmpty<$t>>>(iter: I) -> $t {
+                iter.map(|v| v.inner).product()
+            }
+        }
+
+        /// Multiplies into a plain number, see the impl for owned values.
+        impl<'a> Product<&'a NonEmpty<$t>> for $t {
+            #[inline]
+            fn product<I: Iterator<Item = &'a NonEmpty<$t>>>(iter: I) -> $t {
+                iter.map(|v| v.inner).product()
+            }
+        }
+    )*)
+}
+
+sum_product_impls! { i8 i16 i32 i64 isize u8 u16 u32 u64 usize f32 f64 }
+
+/// Wraps the result of a checked operation, rejecting overflow and zero.
+#[inline]
+pub(crate) fn non_zero<T: PartialEq + Default>(v: Option<T>) -> Result<NonEmpty<T>, ArithmeticError> {
+    match v {
+        None => Err(ArithmeticError::Overflow),
+        Some(inner) => {
+            if inner == T::default() {
+                return Err(ArithmeticError::Zero)
+            }
+            Ok(NonEmpty { inner })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CheckedDivNe, DivNe};
+    use super::super::{ArithmeticError, TryNonEmpty, f64NE, i32NE, i8NE, u8NE, u64NE};
+
+    #[test]
+    fn checked_add() {
+        let a: i32NE = 5.try_non_empty().unwrap();
+        assert_eq!(8, *a.clone().checked_add(3).unwrap());
+        assert_eq!(Err(ArithmeticError::Zero), a.clone().checked_add(-5));
+        assert_eq!(-2, *a.checked_add_ne((-7).try_non_empty().unwrap()).unwrap());
+
+        let a: i8NE = 100.try_non_empty().unwrap();
+        assert_eq!(Err(ArithmeticError::Overflow), a.checked_add(100));
+
+        let a: u8NE = 200.try_non_empty().unwrap();
+        assert_eq!(Err(ArithmeticError::Overflow), a.clone().checked_add(56));
+        assert_eq!(255, *a.checked_add(55).unwrap());
+    }
+
+    #[test]
+    fn checked_sub() {
+        let a: u64NE = 5.try_non_empty().unwrap();
+        assert_eq!(2, *a.clone().checked_sub(3).unwrap());
+        assert_eq!(Err(ArithmeticError::Zero), a.clone().checked_sub(5));
+        assert_eq!(Err(ArithmeticError::Overflow), a.clone().checked_sub(6));
+        assert_eq!(Err(ArithmeticError::Zero), a.clone().checked_sub_ne(a));
+
+        let a: i8NE = (-100).try_non_empty().unwrap();
+        assert_eq!(Err(ArithmeticError::Overflow), a.clone().checked_sub(100));
+        assert_eq!(-128, *a.checked_sub(28).unwrap());
+    }
+
+    #[test]
+    fn checked_mul() {
+        let a: i32NE = 6.try_non_empty().unwrap();
+        let b: i32NE = (-7).try_non_empty().unwrap();
+        assert_eq!(-42, *a.checked_mul(b).unwrap());
+
+        // 2^4 * 2^4 wraps to zero, checked_mul reports the overflow instead
+        let a: u8NE = 16.try_non_empty().unwrap();
+        assert_eq!(0, 16_u8.wrapping_mul(16));
+        assert!(a.clone().checked_mul(a).is_none());
+
+        let a: i8NE = (-128).try_non_empty().unwrap();
+        let b: i8NE = (-1).try_non_empty().unwrap();
+        assert!(a.checked_mul(b).is_none());
+    }
+
+    #[test]
+    fn saturating_mul_ne() {
+        let a: u8NE = 16.try_non_empty().unwrap();
+        assert_eq!(255, *a.clone().saturating_mul_ne(a));
+
+        let a: i8NE = 100.try_non_empty().unwrap();
+        let b: i8NE = (-2).try_non_empty().unwrap();
+        assert_eq!(i8::MIN, *a.clone().saturating_mul_ne(b.clone()));
+        assert_eq!(i8::MAX, *a.clone().saturating_mul_ne(a));
+
+        let a: i8NE = 3.try_non_empty().unwrap();
+        assert_eq!(-6, *a.saturating_mul_ne(b));
+    }
+
+    #[test]
+    fn pow_ne() {
+        let a: i32NE = (-3).try_non_empty().unwrap();
+        assert_eq!(1, *a.clone().pow_ne(0).unwrap());
+        assert_eq!(-3, *a.clone().pow_ne(1).unwrap());
+        assert_eq!(-27, *a.pow_ne(3).unwrap());
+
+        // 2^8 wraps to zero for u8
+        let a: u8NE = 2.try_non_empty().unwrap();
+        assert_eq!(128, *a.clone().pow_ne(7).unwrap());
+        assert!(a.pow_ne(8).is_none());
+    }
+
+    #[test]
+    fn div_rem_ne() {
+        let d: u64NE = 4.try_non_empty().unwrap();
+        assert_eq!(2, 10_u64.div_ne(d.clone()));
+        assert_eq!(2, 10_u64.rem_ne(d.clone()));
+        assert_eq!(0, 0_u64.div_ne(d));
+
+        let d: i32NE = (-3).try_non_empty().unwrap();
+        assert_eq!(-3, 10_i32.div_ne(d.clone()));
+        assert_eq!(1, 10_i32.rem_ne(d));
+    }
+
+    #[test]
+    #[should_panic]
+    fn div_ne_min_by_minus_one() {
+        let d: i32NE = (-1).try_non_empty().unwrap();
+        i32::MIN.div_ne(d);
+    }
+
+    #[test]
+    fn checked_div_ne() {
+        let d: i32NE = (-1).try_non_empty().unwrap();
+        assert_eq!(None, i32::MIN.checked_div_ne(d.clone()));
+        assert_eq!(None, i32::MIN.checked_rem_ne(d.clone()));
+        assert_eq!(Some(-i32::MAX), i32::MAX.checked_div_ne(d.clone()));
+        assert_eq!(Some(0), i32::MAX.checked_rem_ne(d));
+
+        let d: i8NE = 2.try_non_empty().unwrap();
+        assert_eq!(Some(-64), i8::MIN.checked_div_ne(d));
+    }
+
+    #[test]
+    fn sum() {
+        let counts: Vec<u64NE> = vec![512, 1024, 8].into_iter()
+            .map(|c| c.try_non_empty().unwrap())
+            .collect();
+        assert_eq!(1544, counts.iter().sum::<u64>());
+        assert_eq!(1544, counts.into_iter().sum::<u64>());
+
+        let v: Vec<i32NE> = vec![3, -1, -2].into_iter()
+            .map(|c| c.try_non_empty().unwrap())
+            .collect();
+        assert_eq!(0, v.iter().sum::<i32>());
+        assert_eq!(0, v.into_iter().sum::<i32>());
+        assert_eq!(0, Vec::<i32NE>::new().into_iter().sum::<i32>());
+    }
+
+    #[test]
+    fn product() {
+        let v: Vec<f64NE> = vec![0.5, -4.0, 3.0].into_iter()
+            .map(|c| c.try_non_empty().unwrap())
+            .collect();
+        assert_eq!(-6.0, v.iter().product::<f64>());
+        assert_eq!(-6.0, v.into_iter().product::<f64>());
+        assert_eq!(1.0, Vec::<f64NE>::new().into_iter().product::<f64>());
+    }
+
+    #[test]
+    fn neg() {
+        let a: i32NE = 5.try_non_empty().unwrap();
+        assert_eq!(-5, *-a.clone());
+        assert_eq!(5, *-(-a.clone()));
+        assert_eq!(-5, *a.checked_neg().unwrap());
+
+        let a: i8NE = i8::MIN.try_non_empty().unwrap();
+        assert!(a.checked_neg().is_none());
+        let a: i8NE = (-127).try_non_empty().unwrap();
+        assert_eq!(127, *a.checked_neg().unwrap());
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn neg_min_overflows() {
+        let a: i32NE = i32::MIN.try_non_empty().unwrap();
+        let _ = -a;
+    }
+
+    #[test]
+    fn neg_float() {
+        let a: f64NE = 1.5.try_non_empty().unwrap();
+        assert_eq!(-1.5, *-a);
+
+        // both zeros are empty, so they never need to be negated
+        assert!(0.0_f64.try_non_empty().is_none());
+        assert!((-0.0_f64).try_non_empty().is_none());
+
+        let nan: f64NE = f64::NAN.try_non_empty().unwrap();
+        assert!((-nan).is_nan());
+    }
+}
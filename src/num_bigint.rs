@@ -0,0 +1,84 @@
+extern crate num_bigint;
+extern crate num_traits;
+
+use self::num_bigint::{BigInt, BigUint};
+use self::num_traits::Zero;
+
+use super::{DivNe, IsEmpty, NonEmpty};
+
+/// Non-empty `BigInt`, number != 0
+pub type BigIntNE = NonEmpty<BigInt>;
+
+/// Non-empty `BigUint`, number != 0
+pub type BigUintNE = NonEmpty<BigUint>;
+
+macro_rules! big_impls {
+    ($($t:ty)*) => ($(
+        impl IsEmpty for $t {
+            #[inline]
+            fn is_empty(&self) -> bool {
+                self.is_zero()
+            }
+        }
+
+        impl From<NonEmpty<$t>> for $t {
+            #[inline]
+            fn from(v: NonEmpty<$t>) -> Self {
+                v.into_inner()
+            }
+        }
+
+        /// Big integers can't overflow, so dividing by a non-zero
+        /// divisor never panics.
+        impl DivNe for $t {
+            #[inline]
+            fn div_ne(self, divisor: NonEmpty<$t>) -> $t {
+                self / divisor.inner
+            }
+
+            #[inline]
+            fn rem_ne(self, divisor: NonEmpty<$t>) -> $t {
+                self % divisor.inner
+            }
+        }
+    )*)
+}
+
+big_impls! { BigInt BigUint }
+
+#[cfg(test)]
+mod tests {
+    use super::num_bigint::{BigInt, BigUint};
+    use super::{BigIntNE, BigUintNE};
+    use super::super::{DivNe, IsEmpty, TryNonEmpty};
+
+    #[test]
+    fn zero() {
+        assert!(BigUint::default().is_empty());
+        assert!(BigUint::from(0_u8).is_empty());
+        assert!((BigUint::from(7_u8) - BigUint::from(7_u8)).try_non_empty().is_none());
+        assert!(BigInt::default().is_empty());
+        assert!((BigInt::from(-3) + BigInt::from(3)).try_non_empty().is_none());
+        assert!(BigInt::from(-3).try_non_empty().is_some());
+    }
+
+    #[test]
+    fn large_values() {
+        let big: BigUintNE = BigUint::from(u64::MAX).pow(4).try_non_empty().unwrap();
+        assert_eq!(256, big.bits());
+        let neg: BigIntNE = (-BigInt::from(u128::MAX)).try_non_empty().unwrap();
+        let inner: BigInt = neg.into();
+        assert!(inner < BigInt::from(i128::MIN));
+    }
+
+    #[test]
+    fn division() {
+        let divisor: BigUintNE = BigUint::from(7_u8).try_non_empty().unwrap();
+        assert_eq!(BigUint::from(14_u8), BigUint::from(100_u8).div_ne(divisor.clone()));
+        assert_eq!(BigUint::from(2_u8), BigUint::from(100_u8).rem_ne(divisor));
+
+        let divisor: BigIntNE = BigInt::from(-7).try_non_empty().unwrap();
+        assert_eq!(BigInt::from(-14), BigInt::from(100).div_ne(divisor.clone()));
+        assert_eq!(BigInt::from(2), BigInt::from(100).rem_ne(divisor));
+    }
+}
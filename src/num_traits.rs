@@ -0,0 +1,119 @@
+extern crate num_traits;
+
+use std::ops::Deref;
+
+use self::num_traits::Zero;
+
+use super::{IsEmpty, NonEmpty};
+
+/// Adapter implementing `IsEmpty` for any `T: Zero`, a value is empty if it's zero.
+///
+/// A blanket `impl<T: Zero> IsEmpty for T` would overlap with the impls
+/// of this crate, so the value is wrapped instead. The price is a second
+/// layer, e.g. `**v` to reach the number. A manual
+/// `IsEmpty` impl avoids that layer and should be preferred for types
+/// used often, the adapter is handy for many newtypes or foreign types.
+///
+/// # Examples
+/// ```
+/// extern crate non_empty;
+/// extern crate num_traits;
+///
+/// use std::num::Wrapping;
+/// use non_empty::{NonZeroOf, TryNonEmpty, ZeroIsEmpty};
+///
+/// # fn main() {
+/// assert!(ZeroIsEmpty::from(Wrapping(0_u8)).try_non_empty().is_none());
+///
+/// let v: NonZeroOf<Wrapping<u8>> = ZeroIsEmpty(Wrapping(3)).try_non_empty().unwrap();
+/// assert_eq!(Wrapping(3), **v);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct ZeroIsEmpty<T>(pub T);
+
+/// Non-zero `T`, see [ZeroIsEmpty](struct.ZeroIsEmpty.html).
+pub type NonZeroOf<T> = NonEmpty<ZeroIsEmpty<T>>;
+
+impl<T> ZeroIsEmpty<T> {
+    /// Returns the wrapped value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Zero> IsEmpty for ZeroIsEmpty<T> {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<T> Deref for ZeroIsEmpty<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> From<T> for ZeroIsEmpty<T> {
+    #[inline]
+    fn from(v: T) -> Self {
+        ZeroIsEmpty(v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::Wrapping;
+    use std::ops::Add;
+
+    use super::num_traits::Zero;
+    use super::{NonZeroOf, ZeroIsEmpty};
+    use super::super::TryNonEmpty;
+
+    /// Fixed-point number with two decimal places.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Cents(i64);
+
+    impl Add for Cents {
+        type Output = Cents;
+
+        fn add(self, rhs: Cents) -> Cents {
+            Cents(self.0 + rhs.0)
+        }
+    }
+
+    impl Zero for Cents {
+        fn zero() -> Cents {
+            Cents(0)
+        }
+
+        fn is_zero(&self) -> bool {
+            self.0 == 0
+        }
+    }
+
+    #[test]
+    fn fixed_point_newtype() {
+        assert!(ZeroIsEmpty(Cents(0)).try_non_empty().is_none());
+        assert!(ZeroIsEmpty(Cents(5) + Cents(-5)).try_non_empty().is_none());
+
+        let v: NonZeroOf<Cents> = ZeroIsEmpty(Cents(-250)).try_non_empty().unwrap();
+        assert_eq!(-250, v.0 .0);
+        assert_eq!(Cents(-250), v.into_inner().into_inner());
+    }
+
+    #[test]
+    fn foreign_type() {
+        assert!(ZeroIsEmpty::from(Wrapping(0_i32)).try_non_empty().is_none());
+        assert!(ZeroIsEmpty::from(0.0_f64).try_non_empty().is_none());
+
+        let v: NonZeroOf<Wrapping<i32>> = ZeroIsEmpty::from(Wrapping(i32::MAX) + Wrapping(1)).try_non_empty().unwrap();
+        assert_eq!(Wrapping(i32::MIN), **v);
+    }
+}
@@ -0,0 +1,102 @@
+use std::ops::{Add, AddAssign};
+
+use super::NonEmpty;
+
+/////////////////////////////////////////////////////////////////////////
+// Concatenation of non-empty strings and vectors
+/////////////////////////////////////////////////////////////////////////
+
+impl Add<&str> for NonEmpty<String> {
+    type Output = NonEmpty<String>;
+
+    #[inline]
+    fn add(mut self, rhs: &str) -> NonEmpty<String> {
+        self.inner.push_str(rhs);
+        self
+    }
+}
+
+impl Add<NonEmpty<&str>> for NonEmpty<String> {
+    type Output = NonEmpty<String>;
+
+    #[inline]
+    fn add(self, rhs: NonEmpty<&str>) -> NonEmpty<String> {
+        self + rhs.inner
+    }
+}
+
+impl Add<NonEmpty<String>> for NonEmpty<String> {
+    type Output = NonEmpty<String>;
+
+    #[inline]
+    fn add(self, rhs: NonEmpty<String>) -> NonEmpty<String> {
+        self + rhs.inner.as_str()
+    }
+}
+
+impl AddAssign<&str> for NonEmpty<String> {
+    #[inline]
+    fn add_assign(&mut self, rhs: &str) {
+        self.inner.push_str(rhs)
+    }
+}
+
+impl AddAssign<NonEmpty<&str>> for NonEmpty<String> {
+    #[inline]
+    fn add_assign(&mut self, rhs: NonEmpty<&str>) {
+        self.inner.push_str(rhs.inner)
+    }
+}
+
+impl AddAssign<NonEmpty<String>> for NonEmpty<String> {
+    #[inline]
+    fn add_assign(&mut self, rhs: NonEmpty<String>) {
+        self.inner.push_str(&rhs.inner)
+    }
+}
+
+impl<T> Add<Vec<T>> for NonEmpty<Vec<T>> {
+    type Output = NonEmpty<Vec<T>>;
+
+    #[inline]
+    fn add(mut self, rhs: Vec<T>) -> NonEmpty<Vec<T>> {
+        self.inner.extend(rhs);
+        self
+    }
+}
+
+impl<T> Add<NonEmpty<Vec<T>>> for NonEmpty<Vec<T>> {
+    type Output = NonEmpty<Vec<T>>;
+
+    #[inline]
+    fn add(self, rhs: NonEmpty<Vec<T>>) -> NonEmpty<Vec<T>> {
+        self + rhs.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{StringNE, VecNE, ne};
+
+    #[test]
+    fn add_strings() {
+        let s: StringNE = ne("a".to_string()) + "b" + ne("c") + ne("d".to_string()) + "";
+        assert_eq!("abcd", *s);
+    }
+
+    #[test]
+    fn add_assign_strings() {
+        let mut s = ne("a".to_string());
+        s += "b";
+        s += ne("c");
+        s += ne("d".to_string());
+        s += "";
+        assert_eq!("abcd", *s);
+    }
+
+    #[test]
+    fn add_vecs() {
+        let v: VecNE<i32> = ne(vec![1]) + vec![2, 3] + ne(vec![4]) + vec![];
+        assert_eq!(vec![1, 2, 3, 4], *v);
+    }
+}
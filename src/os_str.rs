@@ -0,0 +1,88 @@
+use std::borrow::Cow;
+use std::ffi::{OsStr, OsString};
+
+use super::NonEmpty;
+
+impl NonEmpty<OsString> {
+    /// Converts into a `NonEmpty<String>` if the content is valid Unicode,
+    /// like `OsString::into_string`.
+    ///
+    /// On failure the original value is returned, still wrapped.
+    pub fn into_string_ne(self) -> Result<NonEmpty<String>, NonEmpty<OsString>> {
+        self.inner.into_string()
+            .map(|inner| NonEmpty { inner })
+            .map_err(|inner| NonEmpty { inner })
+    }
+
+    /// Converts into a string, replacing invalid Unicode with
+    /// `U+FFFD REPLACEMENT CHARACTER`, like `OsStr::to_string_lossy`.
+    ///
+    /// The result is never empty, invalid content is replaced
+    /// by at least one replacement character.
+    pub fn to_string_lossy_ne(&self) -> NonEmpty<Cow<'_, str>> {
+        NonEmpty { inner: self.inner.to_string_lossy() }
+    }
+}
+
+impl NonEmpty<&OsStr> {
+    /// Converts into a string, replacing invalid Unicode with
+    /// `U+FFFD REPLACEMENT CHARACTER`, like `OsStr::to_string_lossy`.
+    ///
+    /// The result is never empty, invalid content is replaced
+    /// by at least one replacement character.
+    pub fn to_string_lossy_ne(&self) -> NonEmpty<Cow<'_, str>> {
+        NonEmpty { inner: self.inner.to_string_lossy() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::ffi::{OsStr, OsString};
+
+    use super::super::{OsStringNE, TryNonEmpty};
+
+    #[test]
+    fn empty() {
+        assert!(OsString::new().try_non_empty().is_none());
+        assert!(OsStr::new("").try_non_empty().is_none());
+        assert!(Cow::Borrowed("").try_non_empty().is_none());
+    }
+
+    #[test]
+    fn into_string_ne() {
+        let s: OsStringNE = OsString::from("hello").try_non_empty().unwrap();
+        assert_eq!("hello", *s.into_string_ne().unwrap());
+    }
+
+    #[test]
+    fn to_string_lossy_ne() {
+        let s: OsStringNE = OsString::from("hello").try_non_empty().unwrap();
+        assert_eq!("hello", *s.to_string_lossy_ne());
+        let s = OsStr::new("hi").try_non_empty().unwrap();
+        assert_eq!("hi", *s.to_string_lossy_ne());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn invalid_unicode() {
+        use std::os::unix::ffi::OsStringExt;
+
+        let s: OsStringNE = OsString::from_vec(vec![0xff]).try_non_empty().unwrap();
+        assert_eq!("\u{fffd}", *s.to_string_lossy_ne());
+
+        let s = s.into_string_ne().unwrap_err();
+        assert_eq!(vec![0xff], s.into_inner().into_vec());
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn invalid_unicode() {
+        use std::os::windows::ffi::OsStringExt;
+
+        // unpaired surrogate
+        let s: OsStringNE = OsString::from_wide(&[0xd800]).try_non_empty().unwrap();
+        assert_eq!("\u{fffd}", *s.to_string_lossy_ne());
+        assert!(s.into_string_ne().is_err());
+    }
+}
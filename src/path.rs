@@ -0,0 +1,267 @@
+use std::ffi::OsStr;
+use std::io;
+use std::path::{self, Path, PathBuf};
+
+use super::{NonEmpty, WouldBeEmpty};
+
+/////////////////////////////////////////////////////////////////////////
+// Methods shared by NonEmpty<PathBuf> and NonEmpty<&Path>
+/////////////////////////////////////////////////////////////////////////
+
+macro_rules! path_methods {
+    () => {
+        /// Returns the canonical, absolute form of the path,
+        /// like `Path::canonicalize`.
+        ///
+        /// # Errors
+        /// Returns the I/O error of `Path::canonicalize`, e.g. if the path doesn't exist.
+        pub fn canonicalize_ne(&self) -> io::Result<NonEmpty<PathBuf>> {
+            self.inner.canonicalize().map(|inner| NonEmpty { inner })
+        }
+
+        /// Returns the absolute form of the path without accessing the
+        /// filesystem, like `std::path::absolute`.
+        ///
+        /// # Errors
+        /// Returns the I/O error of `std::path::absolute`, e.g. if the
+        /// current directory can't be determined.
+        pub fn absolutize_ne(&self) -> io::Result<NonEmpty<PathBuf>> {
+            path::absolute(&self.inner).map(|inner| NonEmpty { inner })
+        }
+    };
+}
+
+impl NonEmpty<PathBuf> {
+    path_methods!();
+
+    /// Borrows the path as a `NonEmpty<&Path>`.
+    #[inline]
+    pub fn as_path_ne(&self) -> NonEmpty<&Path> {
+        NonEmpty { inner: self.inner.as_path() }
+    }
+
+    /// Extends the path with `path`, like `PathBuf::push`.
+    ///
+    /// An absolute `path` replaces the current path, which is still non-empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::path::{Path, PathBuf};
+    /// use non_empty::{PathBufNE, TryNonEmpty};
+    ///
+    /// let mut p: PathBufNE = PathBuf::from("usr").try_non_empty().unwrap();
+    /// p.push("bin");
+    /// assert_eq!(Path::new("usr").join("bin"), *p);
+    /// ```
+    #[inline]
+    pub fn push<P: AsRef<Path>>(&mut self, path: P) {
+        self.inner.push(path)
+    }
+
+    /// Creates a new `NonEmpty<PathBuf>` with `path` adjoined, like `Path::join`.
+    #[inline]
+    pub fn join_ne<P: AsRef<Path>>(&self, path: P) -> NonEmpty<PathBuf> {
+        NonEmpty { inner: self.inner.join(path) }
+    }
+
+    /// Updates the extension, like `PathBuf::set_extension`.
+    ///
+    /// Returns `false` and does nothing if the path has no file name.
+    #[inline]
+    pub fn set_extension<S: AsRef<OsStr>>(&mut self, extension: S) -> bool {
+        self.inner.set_extension(extension)
+    }
+
+    /// Truncates the path to its parent, like `PathBuf::pop`.
+    ///
+    /// Returns `Ok(false)` and does nothing if there is no parent, e.g. for `/`.
+    /// Returns `Err(WouldBeEmpty)` and does nothing if the parent is empty,
+    /// e.g. for a single relative component like `file`.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::path::{Path, PathBuf};
+    /// use non_empty::{TryNonEmpty, WouldBeEmpty};
+    ///
+    /// let mut p = PathBuf::from("dir/file").try_non_empty().unwrap();
+    /// assert_eq!(Ok(true), p.pop());
+    /// assert_eq!(Path::new("dir"), *p);
+    /// assert_eq!(Err(WouldBeEmpty), p.pop());
+    /// assert_eq!(Path::new("dir"), *p);
+    /// ```
+    pub fn pop(&mut self) -> Result<bool, WouldBeEmpty> {
+        match self.inner.parent() {
+            None => Ok(false),
+            Some(parent) if parent.as_os_str().is_empty() => Err(WouldBeEmpty),
+            Some(_) => Ok(self.inner.pop()),
+        }
+    }
+
+    /// Returns the parent path, or `None` if there is no non-empty parent.
+    pub fn parent_ne(&self) -> Option<NonEmpty<&Path>> {
+        match self.inner.parent() {
+            Some(inner) if !inner.as_os_str().is_empty() => Some(NonEmpty { inner }),
+            _ => None,
+        }
+    }
+
+    /// Returns the final component of the path, or `None` if there is none.
+    pub fn file_name_ne(&self) -> Option<NonEmpty<&OsStr>> {
+        match self.inner.file_name() {
+            Some(inner) if !inner.is_empty() => Some(NonEmpty { inner }),
+            _ => None,
+        }
+    }
+}
+
+impl NonEmpty<&Path> {
+    path_methods!();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::ffi::OsStr;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    use super::super::{NonEmpty, PathBufNE, TryNonEmpty, WouldBeEmpty};
+
+    fn ne(p: &str) -> PathBufNE {
+        PathBuf::from(p).try_non_empty().unwrap()
+    }
+
+    #[test]
+    fn as_path_ne() {
+        let p = ne("dir/file");
+        let borrowed: NonEmpty<&Path> = p.as_path_ne();
+        assert_eq!(Path::new("dir/file"), borrowed.into_inner());
+    }
+
+    #[test]
+    fn push() {
+        let mut p = ne("a");
+        p.push("b");
+        p.push(Path::new("c").join("d"));
+        assert_eq!(Path::new("a").join("b").join("c").join("d"), *p);
+
+        p.push("");
+        assert_eq!(vec!["a", "b", "c", "d"], p.iter().map(|c| c.to_str().unwrap()).collect::<Vec<_>>());
+        assert!(!p.as_os_str().is_empty());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn push_absolute() {
+        let mut p = ne("a/b");
+        p.push("/etc");
+        assert_eq!(Path::new("/etc"), *p);
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn push_absolute() {
+        let mut p = ne(r"a\b");
+        p.push(r"C:\Windows");
+        assert_eq!(Path::new(r"C:\Windows"), *p);
+    }
+
+    #[test]
+    fn join_ne() {
+        let p = ne("a");
+        let joined: PathBufNE = p.join_ne("b").join_ne("c");
+        assert_eq!(Path::new("a").join("b").join("c"), *joined);
+        assert_eq!(Path::new("a"), *p);
+    }
+
+    #[test]
+    fn set_extension() {
+        let mut p = ne("dir/file");
+        assert!(p.set_extension("txt"));
+        assert_eq!(Path::new("dir/file.txt"), *p);
+        assert!(p.set_extension(""));
+        assert_eq!(Path::new("dir/file"), *p);
+
+        let mut p = ne("..");
+        assert!(!p.set_extension("txt"));
+        assert_eq!(Path::new(".."), *p);
+    }
+
+    #[test]
+    fn pop() {
+        let mut p = ne("file");
+        assert_eq!(Err(WouldBeEmpty), p.pop());
+        assert_eq!(Path::new("file"), *p);
+
+        let mut p = ne("dir/file");
+        assert_eq!(Ok(true), p.pop());
+        assert_eq!(Path::new("dir"), *p);
+        assert_eq!(Err(WouldBeEmpty), p.pop());
+
+        let mut p = ne(".");
+        assert_eq!(Err(WouldBeEmpty), p.pop());
+        assert_eq!(Path::new("."), *p);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn pop_root() {
+        let mut p = ne("/");
+        assert_eq!(Ok(false), p.pop());
+        assert_eq!(Path::new("/"), *p);
+
+        let mut p = ne("/etc");
+        assert_eq!(Ok(true), p.pop());
+        assert_eq!(Path::new("/"), *p);
+    }
+
+    #[test]
+    fn parent_ne() {
+        assert!(ne("file").parent_ne().is_none());
+        assert!(ne(".").parent_ne().is_none());
+        assert_eq!(Path::new("dir"), ne("dir/file").parent_ne().unwrap().into_inner());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn parent_ne_root() {
+        assert!(ne("/").parent_ne().is_none());
+        assert_eq!(Path::new("/"), ne("/etc").parent_ne().unwrap().into_inner());
+    }
+
+    #[test]
+    fn file_name_ne() {
+        assert_eq!(OsStr::new("file"), ne("dir/file").file_name_ne().unwrap().into_inner());
+        assert_eq!(OsStr::new("file"), ne("file").file_name_ne().unwrap().into_inner());
+        assert!(ne("..").file_name_ne().is_none());
+    }
+
+    #[test]
+    fn canonicalize_ne() {
+        let dir = env::temp_dir().join(format!("non_empty_{}_canonicalize_ne", std::process::id()));
+        fs::create_dir_all(dir.join("sub")).unwrap();
+
+        let p = dir.join("sub").join("..").join("sub").try_non_empty().unwrap();
+        let canonical: PathBufNE = p.canonicalize_ne().unwrap();
+        assert!(canonical.is_absolute());
+        assert_eq!(dir.join("sub").canonicalize().unwrap(), *canonical);
+
+        // relative to the crate root, the working directory of cargo test
+        let canonical = Path::new("src").try_non_empty().unwrap().canonicalize_ne().unwrap();
+        assert!(canonical.is_absolute());
+        assert!(canonical.ends_with("src"));
+
+        assert!(ne("does/not/exist").canonicalize_ne().is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn absolutize_ne() {
+        let p = ne("does/not/exist").absolutize_ne().unwrap();
+        assert!(p.is_absolute());
+        assert!(p.ends_with("does/not/exist"));
+
+        let p = Path::new("file").try_non_empty().unwrap().absolutize_ne().unwrap();
+        assert_eq!(env::current_dir().unwrap().join("file"), *p);
+    }
+}
@@ -0,0 +1,147 @@
+//! [proptest](https://docs.rs/proptest) strategies producing `NonEmpty` values.
+//!
+//! The strategies generate non-empty values directly, instead of filtering
+//! empty ones out, and shrink toward the minimal non-empty case:
+//! one element, one char or the value `1`.
+//!
+//! # Examples
+//! ```
+//! extern crate proptest;
+//! extern crate non_empty;
+//!
+//! use proptest::prelude::*;
+//! use proptest::test_runner::TestRunner;
+//! use non_empty::proptest::vec_ne;
+//!
+//! # fn main() {
+//! let mut runner = TestRunner::default();
+//! runner.run(&vec_ne(any::<u8>(), 10), |v| {
+//!     prop_assert!(!v.is_empty() && v.len() <= 10);
+//!     Ok(())
+//! }).unwrap();
+//! # }
+//! ```
+
+extern crate proptest;
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use self::proptest::collection;
+use self::proptest::prelude::{any, Strategy};
+
+use super::NonEmpty;
+
+/// Maximum number of chars generated by [string_ne](fn.string_ne.html).
+const STRING_MAX_LEN: usize = 32;
+
+/// Strategy for non-empty strings of up to 32 chars.
+pub fn string_ne() -> impl Strategy<Value = NonEmpty<String>> {
+    collection::vec(any::<char>(), 1..=STRING_MAX_LEN)
+        .prop_map(|chars| NonEmpty { inner: chars.into_iter().collect() })
+}
+
+/// Strategy for non-empty vectors of up to `max_len` elements.
+///
+/// # Panics
+/// Panics if `max_len` is zero.
+pub fn vec_ne<S: Strategy>(element: S, max_len: usize) -> impl Strategy<Value = NonEmpty<Vec<S::Value>>> {
+    assert!(max_len > 0, "max_len must be greater than zero");
+    collection::vec(element, 1..=max_len)
+        .prop_map(|inner| NonEmpty { inner })
+}
+
+/// Strategy for non-empty hash maps of up to `max_len` entries.
+///
+/// # Panics
+/// Panics if `max_len` is zero.
+pub fn hash_map_ne<K, V>(key: K, value: V, max_len: usize) -> impl Strategy<Value = NonEmpty<HashMap<K::Value, V::Value>>>
+    where K: Strategy,
+          K::Value: Eq + Hash,
+          V: Strategy,
+{
+    assert!(max_len > 0, "max_len must be greater than zero");
+    collection::hash_map(key, value, 1..=max_len)
+        .prop_map(|inner| NonEmpty { inner })
+}
+
+macro_rules! signed_strategies {
+    ($($name:ident: $t:ident),*) => ($(
+        /// Strategy for non-zero integers, shrinking toward `1` or `-1`.
+        pub fn $name() -> impl Strategy<Value = NonEmpty<$t>> {
+            (1..=$t::MAX).prop_union($t::MIN..=-1)
+                .prop_map(|inner| NonEmpty { inner })
+        }
+    )*)
+}
+
+signed_strategies! { i8_ne: i8, i16_ne: i16, i32_ne: i32, i64_ne: i64, isize_ne: isize }
+
+macro_rules! unsigned_strategies {
+    ($($name:ident: $t:ident),*) => ($(
+        /// Strategy for non-zero integers, shrinking toward `1`.
+        pub fn $name() -> impl Strategy<Value = NonEmpty<$t>> {
+            (1..=$t::MAX).prop_map(|inner| NonEmpty { inner })
+        }
+    )*)
+}
+
+unsigned_strategies! { u8_ne: u8, u16_ne: u16, u32_ne: u32, u64_ne: u64, usize_ne: usize }
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Debug;
+
+    use super::proptest::prelude::{any, Strategy};
+    use super::proptest::test_runner::{TestCaseError, TestError, TestRunner};
+    use super::{hash_map_ne, i32_ne, string_ne, u8_ne, vec_ne};
+    use super::super::{IsEmpty, NonEmpty};
+
+    fn assert_non_empty<S, T>(strategy: S)
+        where S: Strategy<Value = NonEmpty<T>>,
+              T: IsEmpty + Debug,
+    {
+        TestRunner::default()
+            .run(&strategy, |v| {
+                assert!(!v.inner.is_empty());
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn never_empty() {
+        assert_non_empty(string_ne());
+        assert_non_empty(vec_ne(any::<u8>(), 5));
+        assert_non_empty(hash_map_ne(any::<u16>(), any::<bool>(), 5));
+        assert_non_empty(i32_ne());
+        assert_non_empty(u8_ne());
+    }
+
+    #[test]
+    fn vec_shrinks_to_single_element() {
+        let result = TestRunner::default().run(&vec_ne(any::<u8>(), 20), |_| Err(TestCaseError::fail("always fails")));
+        match result {
+            Err(TestError::Fail(_, v)) => assert_eq!(vec![0], *v),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn string_shrinks_to_single_char() {
+        let result = TestRunner::default().run(&string_ne(), |_| Err(TestCaseError::fail("always fails")));
+        match result {
+            Err(TestError::Fail(_, s)) => assert_eq!(1, s.chars().count()),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn int_shrinks_to_one() {
+        let result = TestRunner::default().run(&i32_ne(), |_| Err(TestCaseError::fail("always fails")));
+        match result {
+            Err(TestError::Fail(_, v)) => assert_eq!(1, v.abs()),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,119 @@
+extern crate quickcheck;
+
+use self::quickcheck::{Arbitrary, Gen};
+
+use super::NonEmpty;
+
+/////////////////////////////////////////////////////////////////////////
+// quickcheck values, generated and shrunk without ever being empty
+/////////////////////////////////////////////////////////////////////////
+
+/// Generates the first element directly and the rest like `Vec<T>` does.
+/// Shrinking stops at a single element.
+impl<T: Arbitrary> Arbitrary for NonEmpty<Vec<T>> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        NonEmpty::from_head_tail(T::arbitrary(g), Vec::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.inner.shrink()
+            .filter(|v| !v.is_empty())
+            .map(|inner| NonEmpty { inner }))
+    }
+}
+
+/// Generates the first char directly and the rest like `String` does.
+/// Shrinking stops at a single char.
+impl Arbitrary for NonEmpty<String> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let mut inner = char::arbitrary(g).to_string();
+        inner.push_str(&String::arbitrary(g));
+        NonEmpty { inner }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.inner.shrink()
+            .filter(|s| !s.is_empty())
+            .map(|inner| NonEmpty { inner }))
+    }
+}
+
+macro_rules! num_impls {
+    ($($t:ty)*) => ($(
+        /// A generated zero is replaced by one, shrinking never reaches zero.
+        impl Arbitrary for NonEmpty<$t> {
+            fn arbitrary(g: &mut Gen) -> Self {
+                match <$t>::arbitrary(g) {
+                    0 => NonEmpty { inner: 1 },
+                    inner => NonEmpty { inner },
+                }
+            }
+
+            fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                Box::new(self.inner.shrink()
+                    .filter(|x| *x != 0)
+                    .map(|inner| NonEmpty { inner }))
+            }
+        }
+    )*)
+}
+
+num_impls! { i8 i16 i32 i64 isize u8 u16 u32 u64 usize }
+
+#[cfg(test)]
+mod tests {
+    use super::quickcheck::{Arbitrary, QuickCheck};
+    use super::super::{StringNE, TryNonEmpty, VecNE, i32NE, u64NE};
+
+    #[test]
+    fn properties() {
+        fn vec_prop(v: VecNE<u8>) -> bool {
+            !v.inner.is_empty()
+        }
+        fn string_prop(s: StringNE) -> bool {
+            !s.inner.is_empty()
+        }
+        fn int_prop(a: i32NE, b: u64NE) -> bool {
+            *a != 0 && *b != 0
+        }
+        QuickCheck::new().quickcheck(vec_prop as fn(VecNE<u8>) -> bool);
+        QuickCheck::new().quickcheck(string_prop as fn(StringNE) -> bool);
+        QuickCheck::new().quickcheck(int_prop as fn(i32NE, u64NE) -> bool);
+    }
+
+    #[test]
+    fn shrinking_stays_non_empty() {
+        // fails for more than one element, the shrunk inputs panic if empty
+        fn prop(v: VecNE<u8>) -> bool {
+            assert!(!v.inner.is_empty());
+            v.len() < 2
+        }
+        let res = QuickCheck::new().quicktest(prop as fn(VecNE<u8>) -> bool).unwrap_err();
+        assert!(res.is_failure());
+        assert!(!res.is_error());
+
+        fn string_prop(s: StringNE) -> bool {
+            assert!(!s.inner.is_empty());
+            s.chars().count() < 2
+        }
+        let res = QuickCheck::new().quicktest(string_prop as fn(StringNE) -> bool).unwrap_err();
+        assert!(!res.is_error());
+    }
+
+    #[test]
+    fn shrink() {
+        let v: VecNE<u8> = vec![10, 20, 30].try_non_empty().unwrap();
+        assert!(v.shrink().count() > 0);
+        assert!(v.shrink().all(|v| !v.is_empty()));
+        assert!(VecNE::from_head_tail(7_u8, vec![]).shrink().all(|v| v.len() == 1));
+
+        let s: StringNE = "abc".to_string().try_non_empty().unwrap();
+        assert!(s.shrink().all(|s| !s.is_empty()));
+
+        let n: i32NE = (-5).try_non_empty().unwrap();
+        assert!(n.shrink().count() > 0);
+        assert!(n.shrink().all(|n| *n != 0));
+        let n: i32NE = 1.try_non_empty().unwrap();
+        assert_eq!(0, n.shrink().count());
+    }
+}
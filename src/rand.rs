@@ -0,0 +1,100 @@
+extern crate rand;
+
+use self::rand::seq::{IndexedRandom, SliceRandom};
+use self::rand::Rng;
+
+use super::NonEmpty;
+
+macro_rules! choose_methods {
+    () => {
+        /// Returns a random element, like `IndexedRandom::choose` without the `Option`.
+        pub fn choose_ne<R: Rng + ?Sized>(&self, rng: &mut R) -> &T {
+            self.inner.choose(rng).expect("NonEmpty is never empty")
+        }
+
+        /// Returns `amount` distinct random elements, like `IndexedRandom::choose_multiple`.
+        ///
+        /// Returns all elements in random order if there are fewer than `amount`.
+        pub fn choose_multiple_ne<R: Rng + ?Sized>(&self, rng: &mut R, amount: usize) -> Vec<&T> {
+            self.inner.choose_multiple(rng, amount).collect()
+        }
+    };
+}
+
+macro_rules! shuffle_methods {
+    () => {
+        /// Shuffles the elements in place, like `SliceRandom::shuffle`.
+        #[inline]
+        pub fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+            self.inner.shuffle(rng)
+        }
+    };
+}
+
+impl<T> NonEmpty<Vec<T>> {
+    choose_methods!();
+    shuffle_methods!();
+}
+
+impl<T> NonEmpty<Box<[T]>> {
+    choose_methods!();
+    shuffle_methods!();
+}
+
+impl<T> NonEmpty<&[T]> {
+    choose_methods!();
+}
+
+impl<T> NonEmpty<[T]> {
+    choose_methods!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::rand::rngs::StdRng;
+    use super::rand::SeedableRng;
+    use super::super::{NonEmpty, TryNonEmpty, VecNE};
+
+    #[test]
+    fn choose() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let v: VecNE<i32> = vec![1, 2, 3].try_non_empty().unwrap();
+        for _ in 0..20 {
+            assert!(v.contains(v.choose_ne(&mut rng)));
+        }
+
+        let s: NonEmpty<&[i32]> = (&[4][..]).try_non_empty().unwrap();
+        assert_eq!(4, *s.choose_ne(&mut rng));
+        let s: &NonEmpty<[i32]> = NonEmpty::from_ref(&[5, 6][..]).unwrap();
+        assert!(*s.choose_ne(&mut rng) >= 5);
+    }
+
+    #[test]
+    fn choose_multiple() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let v: VecNE<i32> = vec![1, 2, 3].try_non_empty().unwrap();
+        let mut picked = v.choose_multiple_ne(&mut rng, 2);
+        assert_eq!(2, picked.len());
+        picked.dedup();
+        assert_eq!(2, picked.len());
+
+        let mut all = v.choose_multiple_ne(&mut rng, 10);
+        all.sort();
+        assert_eq!(vec![&1, &2, &3], all);
+    }
+
+    #[test]
+    fn shuffle() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut v: VecNE<i32> = (0..20).collect::<Vec<_>>().try_non_empty().unwrap();
+        v.shuffle(&mut rng);
+        assert_ne!((0..20).collect::<Vec<_>>(), *v);
+        let mut sorted = v.into_inner();
+        sorted.sort();
+        assert_eq!((0..20).collect::<Vec<_>>(), sorted);
+
+        let mut one: VecNE<i32> = vec![1].try_non_empty().unwrap();
+        one.shuffle(&mut rng);
+        assert_eq!(vec![1], *one);
+    }
+}
@@ -0,0 +1,170 @@
+extern crate rayon;
+
+use std::mem::ManuallyDrop;
+
+use self::rayon::iter::{IntoParallelIterator, ParallelIterator};
+use self::rayon::slice::ParallelSlice;
+
+use super::{IsEmpty, NonEmpty};
+
+/// Inputs shorter than this are checked sequentially by
+/// [par_try_non_empty_all](fn.par_try_non_empty_all.html), for them
+/// spawning tasks costs more than the scan itself.
+pub const PAR_THRESHOLD: usize = 4096;
+
+/////////////////////////////////////////////////////////////////////////
+// Parallel iteration, delegating to the inner collection
+/////////////////////////////////////////////////////////////////////////
+
+/// Enables `into_par_iter()` on every `NonEmpty` collection supported by rayon.
+impl<T: IntoParallelIterator> IntoParallelIterator for NonEmpty<T> {
+    type Iter = T::Iter;
+    type Item = T::Item;
+
+    #[inline]
+    fn into_par_iter(self) -> T::Iter {
+        self.inner.into_par_iter()
+    }
+}
+
+/// Enables `par_iter()` on every `NonEmpty` collection supported by rayon.
+impl<'a, T> IntoParallelIterator for &'a NonEmpty<T>
+    where &'a T: IntoParallelIterator,
+{
+    type Iter = <&'a T as IntoParallelIterator>::Iter;
+    type Item = <&'a T as IntoParallelIterator>::Item;
+
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        (&self.inner).into_par_iter()
+    }
+}
+
+/// Enables `par_iter_mut()`, parallel iterators can't add or remove
+/// elements, so the collection stays non-empty.
+impl<'a, T> IntoParallelIterator for &'a mut NonEmpty<T>
+    where &'a mut T: IntoParallelIterator,
+{
+    type Iter = <&'a mut T as IntoParallelIterator>::Iter;
+    type Item = <&'a mut T as IntoParallelIterator>::Item;
+
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        (&mut self.inner).into_par_iter()
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////
+// Parallel validation
+/////////////////////////////////////////////////////////////////////////
+
+/// Converts all values to `NonEmpty`'s or fails, scanning for empty values in parallel.
+///
+/// Returns the same result as [try_non_emptyN](fn.try_non_emptyN.html).
+/// The scan stops early once any empty value is found, inputs shorter than
+/// [PAR_THRESHOLD](constant.PAR_THRESHOLD.html) are scanned sequentially.
+/// On success the values are wrapped in place, reusing the allocation of `v`.
+///
+/// # Examples
+/// ```
+/// use non_empty::par_try_non_empty_all;
+///
+/// let names: Vec<String> = (0..10_000).map(|i| i.to_string()).collect();
+/// assert_eq!(10_000, par_try_non_empty_all(names.clone()).unwrap().len());
+///
+/// let mut names = names;
+/// names[5_000].clear();
+/// assert!(par_try_non_empty_all(names).is_none());
+/// ```
+pub fn par_try_non_empty_all<T: IsEmpty + Sync>(v: Vec<T>) -> Option<Vec<NonEmpty<T>>> {
+    let any_empty = if v.len() < PAR_THRESHOLD {
+        v.iter().any(IsEmpty::is_empty)
+    } else {
+        v.par_chunks(PAR_THRESHOLD).any(|c| c.iter().any(IsEmpty::is_empty))
+    };
+    if any_empty {
+        return None
+    }
+    let mut v = ManuallyDrop::new(v);
+    // SAFETY: NonEmpty<T> is repr(transparent), so the buffer has the layout of
+    // a Vec<NonEmpty<T>>, and no value is empty. `v` is not dropped, the new Vec owns the buffer.
+    Some(unsafe { Vec::from_raw_parts(v.as_mut_ptr() as *mut NonEmpty<T>, v.len(), v.capacity()) })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashSet};
+
+    use super::rayon::prelude::*;
+    use super::{par_try_non_empty_all, PAR_THRESHOLD};
+    use super::super::{try_non_emptyN, NonEmpty, TryNonEmpty, VecNE};
+
+    fn numbers() -> VecNE<u64> {
+        (1..=10_000).collect::<Vec<u64>>().try_non_empty().unwrap()
+    }
+
+    #[test]
+    fn par_sum() {
+        let v = numbers();
+        assert_eq!(50_005_000, v.par_iter().sum::<u64>());
+        assert_eq!(50_005_000, v.into_par_iter().sum::<u64>());
+    }
+
+    #[test]
+    fn par_iter_mut() {
+        let mut v = numbers();
+        v.par_iter_mut().for_each(|x| *x *= 2);
+        assert_eq!(10_000, v.len());
+        assert_eq!(2, v[0]);
+        assert_eq!(100_010_000, v.par_iter().sum::<u64>());
+    }
+
+    #[test]
+    fn maps_and_sets() {
+        let s: NonEmpty<HashSet<u64>> = numbers().iter().cloned().collect::<HashSet<_>>().try_non_empty().unwrap();
+        assert_eq!(10_000, s.par_iter().count());
+
+        let mut m: NonEmpty<BTreeMap<u64, u64>> = numbers().iter().map(|x| (*x, 0)).collect::<BTreeMap<_, _>>().try_non_empty().unwrap();
+        m.par_iter_mut().for_each(|(k, v)| *v = *k);
+        assert_eq!(50_005_000, m.into_par_iter().map(|(_, v)| v).sum::<u64>());
+    }
+
+    /// Pseudo-random strings from a linear congruential generator,
+    /// on average one in `empty_one_in` is empty.
+    fn random_strings(seed: u64, n: usize, empty_one_in: u64) -> Vec<String> {
+        let mut x = seed;
+        (0..n).map(|_| {
+            x = x.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            let r = x >> 33;
+            match r % empty_one_in {
+                0 => String::new(),
+                _ => "a".repeat(1 + (r % 4) as usize),
+            }
+        }).collect()
+    }
+
+    #[test]
+    fn par_try_non_empty_all_matches_sequential() {
+        for &(seed, n, empty_one_in) in &[(1, 10, 3), (2, 100, 500), (3, 50_000, 100_000), (4, 20_000, 5)] {
+            let v = random_strings(seed, n, empty_one_in);
+            assert_eq!(try_non_emptyN(v.clone()), par_try_non_empty_all(v));
+        }
+        let all = random_strings(5, 3 * PAR_THRESHOLD, u64::MAX);
+        let wrapped = par_try_non_empty_all(all.clone()).unwrap();
+        assert_eq!(try_non_emptyN(all), Some(wrapped));
+        assert_eq!(Some(vec![]), par_try_non_empty_all(Vec::<String>::new()));
+    }
+
+    #[test]
+    fn par_try_non_empty_all_early_exit() {
+        let mut v = vec![1u32; 10 * PAR_THRESHOLD];
+        v[7 * PAR_THRESHOLD + 3] = 0;
+        assert_eq!(None, par_try_non_empty_all(v.clone()));
+        v[7 * PAR_THRESHOLD + 3] = 2;
+        let ptr = v.as_ptr() as usize;
+        let wrapped = par_try_non_empty_all(v).unwrap();
+        assert_eq!(2, *wrapped[7 * PAR_THRESHOLD + 3]);
+        // wrapped in place, without a new allocation
+        assert_eq!(ptr, wrapped.as_ptr() as usize);
+    }
+}
@@ -0,0 +1,119 @@
+extern crate rust_decimal;
+
+use std::ops::Neg;
+
+use self::rust_decimal::Decimal;
+
+use super::num::non_zero;
+use super::{ArithmeticError, IsEmpty, NonEmpty};
+
+/// Non-empty `Decimal`, number != 0
+pub type DecimalNE = NonEmpty<Decimal>;
+
+/// Zero is empty at any scale, e.g. `0.00`.
+///
+/// Negative numbers are not empty.
+impl IsEmpty for Decimal {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.is_zero()
+    }
+}
+
+impl From<NonEmpty<Decimal>> for Decimal {
+    #[inline]
+    fn from(v: NonEmpty<Decimal>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl NonEmpty<Decimal> {
+    /// Checked decimal addition.
+    ///
+    /// Returns `Err(ArithmeticError::Overflow)` on overflow and
+    /// `Err(ArithmeticError::Zero)` if the amounts cancel out.
+    pub fn checked_add(self, rhs: Decimal) -> Result<NonEmpty<Decimal>, ArithmeticError> {
+        non_zero(self.inner.checked_add(rhs))
+    }
+
+    /// Checked decimal subtraction.
+    ///
+    /// Returns `Err(ArithmeticError::Overflow)` on overflow and
+    /// `Err(ArithmeticError::Zero)` if the result is zero.
+    pub fn checked_sub(self, rhs: Decimal) -> Result<NonEmpty<Decimal>, ArithmeticError> {
+        non_zero(self.inner.checked_sub(rhs))
+    }
+
+    /// Checked addition of two non-zero decimals, see [checked_add](#method.checked_add).
+    pub fn checked_add_ne(self, rhs: NonEmpty<Decimal>) -> Result<NonEmpty<Decimal>, ArithmeticError> {
+        self.checked_add(rhs.inner)
+    }
+
+    /// Checked subtraction of two non-zero decimals, see [checked_sub](#method.checked_sub).
+    pub fn checked_sub_ne(self, rhs: NonEmpty<Decimal>) -> Result<NonEmpty<Decimal>, ArithmeticError> {
+        self.checked_sub(rhs.inner)
+    }
+
+    /// Checked multiplication of two non-zero decimals.
+    ///
+    /// Unlike for integers the product can be zero,
+    /// if it is rounded below the smallest representable decimal.
+    pub fn checked_mul(self, rhs: NonEmpty<Decimal>) -> Result<NonEmpty<Decimal>, ArithmeticError> {
+        non_zero(self.inner.checked_mul(rhs.inner))
+    }
+
+    /// Checked division by a non-zero decimal.
+    ///
+    /// Returns `Err(ArithmeticError::Zero)` if the quotient is
+    /// rounded below the smallest representable decimal.
+    pub fn checked_div(self, rhs: NonEmpty<Decimal>) -> Result<NonEmpty<Decimal>, ArithmeticError> {
+        non_zero(self.inner.checked_div(rhs.inner))
+    }
+}
+
+/// Negating a non-zero decimal never results in zero.
+impl Neg for NonEmpty<Decimal> {
+    type Output = NonEmpty<Decimal>;
+
+    #[inline]
+    fn neg(self) -> NonEmpty<Decimal> {
+        NonEmpty { inner: -self.inner }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::rust_decimal::Decimal;
+    use super::DecimalNE;
+    use super::super::{ArithmeticError, IsEmpty, TryNonEmpty};
+
+    fn dec(s: &str) -> Decimal {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn zero_at_any_scale() {
+        assert!(Decimal::ZERO.is_empty());
+        assert!(dec("0.000").is_empty());
+        assert!(dec("-0.00").try_non_empty().is_none());
+        assert!(!dec("-0.01").is_empty());
+        assert!(dec("-0.01").try_non_empty().is_some());
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a: DecimalNE = dec("10.50").try_non_empty().unwrap();
+        let b: DecimalNE = dec("-10.5").try_non_empty().unwrap();
+        assert_eq!(Err(ArithmeticError::Zero), a.clone().checked_add_ne(b.clone()));
+        assert_eq!(Err(ArithmeticError::Zero), a.clone().checked_sub(dec("10.500")));
+        assert_eq!(dec("21"), *a.clone().checked_sub_ne(b.clone()).unwrap());
+        assert_eq!(Err(ArithmeticError::Overflow), a.clone().checked_add(Decimal::MAX));
+        assert_eq!(a.clone(), -b.clone());
+
+        assert_eq!(dec("-110.25"), *a.clone().checked_mul(b.clone()).unwrap());
+        assert_eq!(dec("-1"), *a.checked_div(b).unwrap());
+
+        let tiny: DecimalNE = dec("0.0000000000000000000000000001").try_non_empty().unwrap();
+        assert_eq!(Err(ArithmeticError::Zero), tiny.clone().checked_mul(tiny));
+    }
+}
@@ -0,0 +1,152 @@
+extern crate serde;
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use self::serde::de::{Error, SeqAccess, Visitor};
+use self::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{IsEmpty, NonEmpty, NonEmptyString, NonEmptyVec};
+
+/// Serializes the inner value.
+impl<T: Serialize> Serialize for NonEmpty<T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.inner.serialize(serializer)
+    }
+}
+
+/// Deserializes the inner value and fails if it is empty.
+///
+/// # Examples
+/// ```
+/// extern crate serde_json;
+/// extern crate non_empty;
+///
+/// use non_empty::StringNE;
+///
+/// # fn main() {
+/// let s: StringNE = serde_json::from_str(r#""abc""#).unwrap();
+/// assert_eq!("abc", *s);
+///
+/// let err = serde_json::from_str::<StringNE>(r#""""#).unwrap_err();
+/// assert!(err.to_string().starts_with("invalid empty value, expected a non-empty value"));
+/// # }
+/// ```
+impl<'de, T> Deserialize<'de> for NonEmpty<T>
+    where T: Deserialize<'de> + IsEmpty,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let inner = T::deserialize(deserializer)?;
+        if inner.is_empty() {
+            return Err(D::Error::custom("invalid empty value, expected a non-empty value"))
+        }
+        Ok(NonEmpty { inner })
+    }
+}
+
+impl Serialize for NonEmptyString {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_str().serialize(serializer)
+    }
+}
+
+/// Deserializes a string and fails if it is empty, like `StringNE`.
+impl<'de> Deserialize<'de> for NonEmptyString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        NonEmpty::<String>::deserialize(deserializer).map(NonEmptyString::from)
+    }
+}
+
+/// Serializes the elements as a sequence, the head first.
+impl<T: Serialize> Serialize for NonEmptyVec<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self)
+    }
+}
+
+/// Deserializes a sequence and fails if it is empty, like `VecNE`.
+///
+/// The head and the tail are filled directly, no element is moved afterwards.
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for NonEmptyVec<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(NonEmptyVecVisitor(PhantomData))
+    }
+}
+
+struct NonEmptyVecVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for NonEmptyVecVisitor<T> {
+    type Value = NonEmptyVec<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a non-empty sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let head = match seq.next_element()? {
+            Some(head) => head,
+            None => return Err(A::Error::custom("invalid empty value, expected a non-empty value")),
+        };
+        let mut tail = Vec::with_capacity(seq.size_hint().unwrap_or(0).saturating_sub(1).min(4096));
+        while let Some(v) = seq.next_element()? {
+            tail.push(v);
+        }
+        Ok(NonEmptyVec { head, tail })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate serde_json as json;
+
+    use std::collections::HashMap;
+
+    use super::super::{NonEmpty, NonEmptyString, NonEmptyVec, StringNE, TryNonEmpty, VecNE, u32NE};
+
+    #[test]
+    fn round_trip() {
+        let v: VecNE<u32> = vec![1, 2].try_non_empty().unwrap();
+        let s = json::to_string(&v).unwrap();
+        assert_eq!("[1,2]", s);
+        assert_eq!(v, json::from_str::<VecNE<u32>>(&s).unwrap());
+
+        let n: u32NE = json::from_str("7").unwrap();
+        assert_eq!(7, *n);
+    }
+
+    #[test]
+    fn rejects_empty() {
+        assert!(json::from_str::<StringNE>(r#""""#).is_err());
+        assert!(json::from_str::<VecNE<u32>>("[]").is_err());
+        assert!(json::from_str::<NonEmpty<HashMap<String, u32>>>("{}").is_err());
+        let err = json::from_str::<u32NE>("0").unwrap_err();
+        assert!(err.to_string().starts_with("invalid empty value"), "{}", err);
+    }
+
+    #[test]
+    fn non_empty_string() {
+        let s: NonEmptyString = json::from_str(r#""abc""#).unwrap();
+        assert_eq!("abc", s);
+        assert_eq!(r#""abc""#, json::to_string(&s).unwrap());
+
+        let ne: StringNE = json::from_str(r#""abc""#).unwrap();
+        assert_eq!(json::to_string(&ne).unwrap(), json::to_string(&s).unwrap());
+        let err = json::from_str::<NonEmptyString>(r#""""#).unwrap_err();
+        assert_eq!(json::from_str::<StringNE>(r#""""#).unwrap_err().to_string(), err.to_string());
+    }
+
+    #[test]
+    fn non_empty_vec() {
+        let v = NonEmptyVec::from_head_tail(1, vec![2, 3]);
+        assert_eq!("[1,2,3]", json::to_string(&v).unwrap());
+        assert_eq!(v, json::from_str::<NonEmptyVec<i32>>("[1,2,3]").unwrap());
+        assert_eq!(NonEmptyVec::new(1), json::from_str::<NonEmptyVec<i32>>("[1]").unwrap());
+        let err = json::from_str::<NonEmptyVec<i32>>("[]").unwrap_err();
+        assert!(err.to_string().starts_with("invalid empty value, expected a non-empty value"));
+        let err = json::from_str::<NonEmptyVec<i32>>("1").unwrap_err();
+        assert!(err.to_string().contains("expected a non-empty sequence"), "{}", err);
+        assert!(json::from_str::<NonEmptyVec<i32>>("[1,\"a\"]").is_err());
+    }
+}
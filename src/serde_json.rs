@@ -0,0 +1,127 @@
+extern crate serde_json;
+
+use self::serde_json::{Map, Value};
+
+use super::{IsEmpty, NonEmpty};
+
+/// A JSON value which is neither `null`, `""`, `[]` nor `{}`.
+pub type JsonValueNE = NonEmpty<Value>;
+
+/// `Null`, an empty string, an empty array and an empty object are empty.
+///
+/// Numbers and booleans are never empty, `0` and `false` are meaningful values.
+impl IsEmpty for Value {
+    fn is_empty(&self) -> bool {
+        match *self {
+            Value::Null => true,
+            Value::String(ref s) => s.is_empty(),
+            Value::Array(ref a) => a.is_empty(),
+            Value::Object(ref o) => o.is_empty(),
+            Value::Bool(_) | Value::Number(_) => false,
+        }
+    }
+}
+
+impl IsEmpty for &Value {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        Value::is_empty(self)
+    }
+}
+
+impl IsEmpty for Map<String, Value> {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        Map::is_empty(self)
+    }
+}
+
+/// Recursively removes empty array elements and object members,
+/// returns `None` if the pruned value is empty itself.
+///
+/// A member is removed if it is empty after its own members were pruned,
+/// e.g. `{"a": {"b": null}}` is pruned to nothing.
+///
+/// # Examples
+/// ```
+/// extern crate serde_json;
+/// extern crate non_empty;
+///
+/// use non_empty::prune_empty;
+///
+/// # fn main() {
+/// let v = serde_json::json!({"a": 1, "b": "", "c": [null, {}, false]});
+/// assert_eq!(serde_json::json!({"a": 1, "c": [false]}), *prune_empty(v).unwrap());
+///
+/// assert!(prune_empty(serde_json::json!({"a": [[]]})).is_none());
+/// # }
+/// ```
+pub fn prune_empty(value: Value) -> Option<JsonValueNE> {
+    let inner = match value {
+        Value::Array(a) => Value::Array(a.into_iter()
+            .filter_map(prune_empty)
+            .map(NonEmpty::into_inner)
+            .collect()),
+        Value::Object(o) => Value::Object(o.into_iter()
+            .filter_map(|(k, v)| prune_empty(v).map(|v| (k, v.into_inner())))
+            .collect()),
+        v => v,
+    };
+    if inner.is_empty() {
+        return None
+    }
+    Some(NonEmpty { inner })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::serde_json::{Map, Value};
+    use super::prune_empty;
+    use super::super::{IsEmpty, TryNonEmpty};
+
+    #[test]
+    fn variants() {
+        assert!(Value::Null.is_empty());
+        assert!(Value::from("").is_empty());
+        assert!(Value::Array(vec![]).is_empty());
+        assert!(Value::Object(Map::new()).is_empty());
+        assert!(Map::new().is_empty());
+
+        assert!(!Value::from(0).is_empty());
+        assert!(!Value::from(0.0).is_empty());
+        assert!(!Value::from(false).is_empty());
+        assert!(!Value::from("a").is_empty());
+        assert!(!Value::Array(vec![Value::Null]).is_empty());
+        assert!(<&Value as IsEmpty>::is_empty(&&Value::Null));
+
+        assert!(Value::Null.try_non_empty().is_none());
+        assert!(Value::from(1).try_non_empty().is_some());
+    }
+
+    #[test]
+    fn prune_nested() {
+        let v = super::serde_json::json!({
+            "name": "x",
+            "empty": "",
+            "none": null,
+            "zero": 0,
+            "tags": ["", "a", [], [null]],
+            "nested": {"a": {"b": {}}, "c": [{"d": null}]},
+            "kept": {"a": {"b": true}},
+        });
+        let expected = super::serde_json::json!({
+            "name": "x",
+            "zero": 0,
+            "tags": ["a"],
+            "kept": {"a": {"b": true}},
+        });
+        assert_eq!(expected, *prune_empty(v).unwrap());
+    }
+
+    #[test]
+    fn prune_to_nothing() {
+        assert!(prune_empty(Value::Null).is_none());
+        assert!(prune_empty(super::serde_json::json!([null, "", {"a": []}])).is_none());
+        assert_eq!(Value::from(false), *prune_empty(Value::from(false)).unwrap());
+    }
+}
@@ -0,0 +1,95 @@
+extern crate smallvec;
+
+use std::cmp::Ordering;
+use std::num::NonZeroUsize;
+use std::slice;
+
+use self::smallvec::{Array, SmallVec};
+
+use super::{IsEmpty, NonEmpty, NonEmptyIter};
+
+/// Non-empty `SmallVec`.
+pub type SmallVecNE<A> = NonEmpty<SmallVec<A>>;
+
+impl<A: Array> IsEmpty for SmallVec<A> {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        SmallVec::is_empty(self)
+    }
+}
+
+impl<A: Array> From<NonEmpty<SmallVec<A>>> for SmallVec<A> {
+    #[inline]
+    fn from(v: NonEmpty<SmallVec<A>>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl<T, A: Array<Item = T>> NonEmpty<SmallVec<A>> {
+    slice_methods!();
+
+    /// Returns true if the elements are stored inline, see `SmallVec::spilled`.
+    #[inline]
+    pub fn is_inline(&self) -> bool {
+        !self.inner.spilled()
+    }
+}
+
+/// Moves the elements inline if they fit, without re-validation.
+impl<A: Array> From<NonEmpty<Vec<A::Item>>> for NonEmpty<SmallVec<A>> {
+    #[inline]
+    fn from(v: NonEmpty<Vec<A::Item>>) -> Self {
+        NonEmpty { inner: SmallVec::from_vec(v.inner) }
+    }
+}
+
+impl<A: Array> From<NonEmpty<SmallVec<A>>> for NonEmpty<Vec<A::Item>> {
+    #[inline]
+    fn from(v: NonEmpty<SmallVec<A>>) -> Self {
+        NonEmpty { inner: v.inner.into_vec() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::smallvec::SmallVec;
+    use super::SmallVecNE;
+    use super::super::{TryNonEmpty, VecNE};
+
+    #[test]
+    fn empty_rejection() {
+        assert!(SmallVec::<[u8; 4]>::new().try_non_empty().is_none());
+        assert!(SmallVec::<[u8; 4]>::from_slice(&[1]).try_non_empty().is_some());
+    }
+
+    #[test]
+    fn inline_and_spilled() {
+        let v: SmallVecNE<[i32; 4]> = SmallVec::from_slice(&[3, 1, 2]).try_non_empty().unwrap();
+        assert!(v.is_inline());
+        assert_eq!(3, *v.max());
+        assert_eq!(1, *v.min());
+        assert_eq!(3, *v.iter_ne().first());
+
+        let v: SmallVecNE<[i32; 2]> = SmallVec::from_slice(&[3, 1, 2]).try_non_empty().unwrap();
+        assert!(!v.is_inline());
+        assert_eq!(3, *v.max());
+        let inner: SmallVec<[i32; 2]> = v.into();
+        assert_eq!(&[3, 1, 2], &inner[..]);
+    }
+
+    #[test]
+    fn vec_conversions() {
+        let v: VecNE<i32> = vec![1, 2].try_non_empty().unwrap();
+        let s: SmallVecNE<[i32; 4]> = v.into();
+        assert!(s.is_inline());
+        assert_eq!(&[1, 2], &s[..]);
+
+        let v: VecNE<i32> = s.into();
+        assert_eq!(vec![1, 2], *v);
+
+        let big: VecNE<i32> = (0..10).collect::<Vec<_>>().try_non_empty().unwrap();
+        let s: SmallVecNE<[i32; 4]> = big.into();
+        assert!(!s.is_inline());
+        assert_eq!(10, s.len());
+    }
+}
@@ -0,0 +1,137 @@
+extern crate sqlx;
+
+use self::sqlx::encode::IsNull;
+use self::sqlx::error::BoxDynError;
+use self::sqlx::{Database, Decode, Encode, Type, TypeInfo, ValueRef};
+
+use super::{IsEmpty, NonEmpty};
+
+/////////////////////////////////////////////////////////////////////////
+// sqlx column types, encoded transparently and checked when decoded
+/////////////////////////////////////////////////////////////////////////
+
+/// Uses the database type of the inner value.
+impl<DB: Database, T: Type<DB>> Type<DB> for NonEmpty<T> {
+    #[inline]
+    fn type_info() -> DB::TypeInfo {
+        T::type_info()
+    }
+
+    #[inline]
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        T::compatible(ty)
+    }
+}
+
+/// Encodes the inner value.
+impl<'q, DB: Database, T: Encode<'q, DB>> Encode<'q, DB> for NonEmpty<T> {
+    #[inline]
+    fn encode(self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+        self.inner.encode(buf)
+    }
+
+    #[inline]
+    fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+        self.inner.encode_by_ref(buf)
+    }
+
+    #[inline]
+    fn produces(&self) -> Option<DB::TypeInfo> {
+        self.inner.produces()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> usize {
+        self.inner.size_hint()
+    }
+}
+
+/// Decodes the inner value and fails if it is empty,
+/// e.g. an empty `TEXT` or a zero `INT4`.
+impl<'r, DB: Database, T> Decode<'r, DB> for NonEmpty<T>
+    where T: Decode<'r, DB> + IsEmpty,
+{
+    fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let type_info = value.type_info().into_owned();
+        let inner = T::decode(value)?;
+        non_empty_column(inner, type_info.name())
+    }
+}
+
+/// Rejects an empty decoded value, naming the column type in the error.
+fn non_empty_column<T: IsEmpty>(inner: T, type_name: &str) -> Result<NonEmpty<T>, BoxDynError> {
+    if inner.is_empty() {
+        return Err(format!("decoded an empty value from a {} column into NonEmpty", type_name).into())
+    }
+    Ok(NonEmpty { inner })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::non_empty_column;
+    use super::sqlx::any::{Any, AnyArguments, AnyValue};
+    use super::sqlx::{Arguments, Decode, Encode, Type, Value};
+    use super::super::{StringNE, i32NE};
+
+    #[test]
+    fn empty_column_error() {
+        assert_eq!("a", *non_empty_column("a".to_string(), "TEXT").unwrap());
+        let err = non_empty_column(String::new(), "TEXT").unwrap_err();
+        assert_eq!("decoded an empty value from a TEXT column into NonEmpty", err.to_string());
+        assert!(non_empty_column(0_i32, "INT4").unwrap_err().to_string().contains("INT4"));
+    }
+
+    /// Builds a column value by encoding `v`, so decoding needs no database.
+    fn column<T: Encode<'static, Any> + Type<Any> + 'static>(v: T) -> AnyValue {
+        let mut args = AnyArguments::default();
+        args.add(v).unwrap();
+        AnyValue { kind: args.values.0.pop().unwrap() }
+    }
+
+    fn decode<'r, T: Decode<'r, Any>>(v: &'r AnyValue) -> Result<T, String> {
+        T::decode(v.as_ref()).map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn decode_rejects_empty_columns() {
+        let empty = column(String::new());
+        assert_eq!(Err("decoded an empty value from a TEXT column into NonEmpty".to_string()),
+                   decode::<StringNE>(&empty).map(|_| ()));
+        let zero = column(0_i32);
+        assert!(decode::<i32NE>(&zero).unwrap_err().contains("INTEGER"));
+
+        let name = column("name".to_string());
+        assert_eq!("name", *decode::<StringNE>(&name).unwrap());
+        let seven = column(7_i32);
+        assert_eq!(7, *decode::<i32NE>(&seven).unwrap());
+
+        // errors of the inner decoder are passed on
+        assert!(decode::<StringNE>(&seven).is_err());
+    }
+
+    #[cfg(feature = "sqlx-postgres")]
+    #[test]
+    fn postgres() {
+        use super::sqlx::postgres::{PgArgumentBuffer, PgTypeInfo, Postgres};
+        use super::sqlx::{Encode, Type};
+        use super::super::{TryNonEmpty, VecNE};
+
+        assert_eq!(PgTypeInfo::with_name("TEXT"), <StringNE as Type<Postgres>>::type_info());
+        assert_eq!(PgTypeInfo::with_name("BYTEA"), <VecNE<u8> as Type<Postgres>>::type_info());
+        assert_eq!(PgTypeInfo::with_name("INT4"), <i32NE as Type<Postgres>>::type_info());
+        assert!(<StringNE as Type<Postgres>>::compatible(&PgTypeInfo::with_name("VARCHAR")));
+
+        // encodes exactly like the inner value
+        let s: StringNE = "name".to_string().try_non_empty().unwrap();
+        let mut buf = PgArgumentBuffer::default();
+        let mut inner_buf = PgArgumentBuffer::default();
+        assert!(!Encode::<Postgres>::encode_by_ref(&s, &mut buf).unwrap().is_null());
+        assert!(!Encode::<Postgres>::encode_by_ref(&*s, &mut inner_buf).unwrap().is_null());
+        assert_eq!(&**inner_buf, &**buf);
+
+        let n: i32NE = 7.try_non_empty().unwrap();
+        let mut buf = PgArgumentBuffer::default();
+        assert!(!Encode::<Postgres>::encode(n, &mut buf).unwrap().is_null());
+        assert_eq!(&7_i32.to_be_bytes()[..], &**buf);
+    }
+}
@@ -0,0 +1,692 @@
+use std::borrow::Cow;
+use std::num::NonZeroUsize;
+use std::str::{self, Bytes, CharIndices, Chars, FromStr, Lines, Split, Utf8Error};
+use std::string::FromUtf8Error;
+
+use super::{IsEmpty, NonEmpty, NonEmptyIter, ParseNeError};
+
+/////////////////////////////////////////////////////////////////////////
+// Methods shared by NonEmpty<String> and NonEmpty<&str>
+/////////////////////////////////////////////////////////////////////////
+
+macro_rules! str_methods {
+    () => {
+        /// Returns the first `char` of the string.
+        ///
+        /// # Examples
+        /// ```
+        /// use non_empty::TryNonEmpty;
+        ///
+        /// let s = "héllo".try_non_empty().unwrap();
+        /// assert_eq!('h', s.first_char());
+        /// assert_eq!('o', s.last_char());
+        /// ```
+        pub fn first_char(&self) -> char {
+            self.inner.chars().next().expect("NonEmpty is never empty")
+        }
+
+        /// Returns the last `char` of the string.
+        pub fn last_char(&self) -> char {
+            self.inner.chars().next_back().expect("NonEmpty is never empty")
+        }
+
+        /// Returns the number of `char`s in the string, not the number of bytes.
+        pub fn char_len_ne(&self) -> NonZeroUsize {
+            NonZeroUsize::new(self.inner.chars().count()).expect("NonEmpty is never empty")
+        }
+
+        /// Returns an iterator over the `char`s, guaranteed to yield at least one item.
+        ///
+        /// # Examples
+        /// ```
+        /// use non_empty::TryNonEmpty;
+        ///
+        /// let s = "hello".try_non_empty().unwrap();
+        /// assert_eq!('o', s.chars_ne().max());
+        /// ```
+        pub fn chars_ne(&self) -> NonEmptyIter<Chars<'_>> {
+            NonEmptyIter::new_unchecked(self.inner.chars())
+        }
+
+        /// Returns an iterator over the bytes, guaranteed to yield at least one item.
+        pub fn bytes_ne(&self) -> NonEmptyIter<Bytes<'_>> {
+            NonEmptyIter::new_unchecked(self.inner.bytes())
+        }
+
+        /// Returns an iterator over the `char`s and their byte positions,
+        /// guaranteed to yield at least one item.
+        pub fn char_indices_ne(&self) -> NonEmptyIter<CharIndices<'_>> {
+            NonEmptyIter::new_unchecked(self.inner.char_indices())
+        }
+
+        /// Returns an iterator over the lines, guaranteed to yield at least one item.
+        ///
+        /// The lines themselves are not wrapped in `NonEmpty`, because they
+        /// can be empty, e.g. `"\n"` yields one empty line.
+        pub fn lines_ne(&self) -> NonEmptyIter<Lines<'_>> {
+            NonEmptyIter::new_unchecked(self.inner.lines())
+        }
+
+        /// Returns an iterator over the pieces separated by `sep`,
+        /// guaranteed to yield at least one item.
+        ///
+        /// The pieces themselves are not wrapped in `NonEmpty`, because they
+        /// can be empty, e.g. when `sep` is at the start or end of the string.
+        ///
+        /// # Examples
+        /// ```
+        /// use non_empty::TryNonEmpty;
+        ///
+        /// let s = "key=value".try_non_empty().unwrap();
+        /// assert_eq!("key", s.split_ne('=').first());
+        /// ```
+        pub fn split_ne(&self, sep: char) -> NonEmptyIter<Split<'_, char>> {
+            NonEmptyIter::new_unchecked(self.inner.split(sep))
+        }
+
+        /// Like [split_ne](#method.split_ne), but separated by a string slice.
+        pub fn split_str_ne<'b>(&self, sep: &'b str) -> NonEmptyIter<Split<'_, &'b str>> {
+            NonEmptyIter::new_unchecked(self.inner.split(sep))
+        }
+
+        /// Returns the first line, which can be empty.
+        pub fn first_line(&self) -> &str {
+            self.lines_ne().first()
+        }
+
+        /// Splits the string at the first occurrence of `sep`.
+        ///
+        /// Returns the whole string and `None` if `sep` is not found.
+        pub fn split_once_ne(&self, sep: char) -> (&str, Option<&str>) {
+            match self.inner.split_once(sep) {
+                Some((head, tail)) => (head, Some(tail)),
+                None => (&self.inner, None),
+            }
+        }
+
+        /// Returns the uppercase equivalent of the string.
+        ///
+        /// Case conversion never produces an empty string, every `char`
+        /// maps to at least one `char`, so no check is needed.
+        pub fn to_uppercase_ne(&self) -> NonEmpty<String> {
+            NonEmpty { inner: self.inner.to_uppercase() }
+        }
+
+        /// Returns the lowercase equivalent of the string.
+        ///
+        /// Case conversion never produces an empty string, every `char`
+        /// maps to at least one `char`, so no check is needed.
+        pub fn to_lowercase_ne(&self) -> NonEmpty<String> {
+            NonEmpty { inner: self.inner.to_lowercase() }
+        }
+
+        /// Returns the string with leading and trailing whitespace removed.
+        ///
+        /// Unlike case conversion, trimming can empty a string, so `None`
+        /// is returned for a whitespace-only string.
+        ///
+        /// # Examples
+        /// ```
+        /// use non_empty::TryNonEmpty;
+        ///
+        /// assert_eq!("a", *" a ".try_non_empty().unwrap().trim_ne().unwrap());
+        /// assert!("  ".try_non_empty().unwrap().trim_ne().is_none());
+        /// ```
+        pub fn trim_ne(&self) -> Option<NonEmpty<&str>> {
+            let inner = self.inner.trim();
+            if inner.is_empty() {
+                return None
+            }
+            Some(NonEmpty { inner })
+        }
+
+        /// Repeats the string `n` times.
+        ///
+        /// Unlike `str::repeat`, a count of zero can't be passed,
+        /// so the result is never empty.
+        pub fn repeat_ne(&self, n: NonZeroUsize) -> NonEmpty<String> {
+            NonEmpty { inner: self.inner.repeat(n.get()) }
+        }
+
+        /// Borrows the UTF-8 bytes of the string as a `NonEmpty<&[u8]>`.
+        #[inline]
+        pub fn as_bytes_ne(&self) -> NonEmpty<&[u8]> {
+            NonEmpty { inner: self.inner.as_bytes() }
+        }
+
+        /// Parses the string into a non-empty value, like `str::parse`.
+        ///
+        /// # Errors
+        /// Returns `ParseNeError::Parse` if the string can't be parsed and
+        /// `ParseNeError::Empty` if the parsed value is empty, e.g. zero.
+        ///
+        /// # Examples
+        /// ```
+        /// use non_empty::{ParseNeError, TryNonEmpty, u16NE};
+        ///
+        /// let port: u16NE = "8080".try_non_empty().unwrap().parse_ne().unwrap();
+        /// assert_eq!(8080, *port);
+        ///
+        /// let port = "0".try_non_empty().unwrap().parse_ne::<u16>();
+        /// assert_eq!(Err(ParseNeError::Empty), port);
+        /// ```
+        pub fn parse_ne<F>(&self) -> Result<NonEmpty<F>, ParseNeError<F::Err>>
+            where F: FromStr + IsEmpty,
+        {
+            let inner: F = self.inner.parse().map_err(ParseNeError::Parse)?;
+            if inner.is_empty() {
+                return Err(ParseNeError::Empty)
+            }
+            Ok(NonEmpty { inner })
+        }
+    };
+}
+
+/// Joins the `parts` with `sep` into a new `String`.
+fn join<'a, I>(parts: I, sep: &str) -> String
+    where I: Iterator<Item = &'a str> + Clone,
+{
+    let mut len = 0;
+    for (i, part) in parts.clone().enumerate() {
+        if i > 0 {
+            len += sep.len();
+        }
+        len += part.len();
+    }
+    let mut s = String::with_capacity(len);
+    for (i, part) in parts.enumerate() {
+        if i > 0 {
+            s.push_str(sep);
+        }
+        s.push_str(part);
+    }
+    s
+}
+
+impl NonEmpty<String> {
+    str_methods!();
+    capacity_methods!(exact);
+
+    /// Borrows the string as a `NonEmpty<&str>`.
+    ///
+    /// # Examples
+    /// ```
+    /// use non_empty::{NonEmpty, StringNE, TryNonEmpty};
+    ///
+    /// fn greet(name: NonEmpty<&str>) -> String {
+    ///     format!("hello {}", *name)
+    /// }
+    ///
+    /// let name: StringNE = "bob".to_string().try_non_empty().unwrap();
+    /// assert_eq!("hello bob", greet(name.as_str_ne()));
+    /// ```
+    #[inline]
+    pub fn as_str_ne(&self) -> NonEmpty<&str> {
+        NonEmpty { inner: self.inner.as_str() }
+    }
+
+    /// Converts the string into a `NonEmpty<Box<str>>`, dropping any excess capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use non_empty::{StringNE, TryNonEmpty};
+    ///
+    /// let mut s = String::with_capacity(64);
+    /// s.push_str("hello");
+    /// let s: StringNE = s.try_non_empty().unwrap();
+    /// let boxed = s.into_boxed_str_ne();
+    /// assert_eq!("hello", &**boxed);
+    /// assert_eq!(5, boxed.into_string_ne().capacity());
+    /// ```
+    #[inline]
+    pub fn into_boxed_str_ne(self) -> NonEmpty<Box<str>> {
+        NonEmpty { inner: self.inner.into_boxed_str() }
+    }
+
+    /// Converts the string into its non-empty UTF-8 bytes.
+    #[inline]
+    pub fn into_bytes(self) -> NonEmpty<Vec<u8>> {
+        NonEmpty { inner: self.inner.into_bytes() }
+    }
+
+    /// Appends the `char` `c` to the end of the string.
+    #[inline]
+    pub fn push(&mut self, c: char) {
+        self.inner.push(c)
+    }
+
+    /// Appends the string slice `s` to the end of the string.
+    ///
+    /// # Examples
+    /// ```
+    /// use non_empty::{StringNE, TryNonEmpty};
+    ///
+    /// let mut path: StringNE = "usr".to_string().try_non_empty().unwrap();
+    /// path.push('/');
+    /// path.push_str("bin");
+    /// assert_eq!("usr/bin", *path);
+    /// ```
+    #[inline]
+    pub fn push_str(&mut self, s: &str) {
+        self.inner.push_str(s)
+    }
+
+    /// Inserts the string slice `s` at the byte position `idx`.
+    ///
+    /// # Panics
+    /// Panics if `idx` is larger than the length or doesn't lie on a
+    /// `char` boundary, like `String::insert_str`.
+    #[inline]
+    pub fn insert_str(&mut self, idx: usize, s: &str) {
+        self.inner.insert_str(idx, s)
+    }
+}
+
+impl NonEmpty<str> {
+    str_methods!();
+}
+
+impl NonEmpty<&str> {
+    str_methods!();
+}
+
+impl NonEmpty<Box<str>> {
+    str_methods!();
+
+    /// Converts the boxed string back into a `NonEmpty<String>`, without copying.
+    #[inline]
+    pub fn into_string_ne(self) -> NonEmpty<String> {
+        NonEmpty { inner: self.inner.into_string() }
+    }
+}
+
+impl NonEmpty<Vec<u8>> {
+    /// Converts the bytes into a `NonEmpty<String>`,
+    /// only failing if the bytes are not valid UTF-8.
+    ///
+    /// The original bytes can be recovered with `FromUtf8Error::into_bytes`.
+    ///
+    /// # Examples
+    /// ```
+    /// use non_empty::{StringNE, TryNonEmpty};
+    ///
+    /// let s: StringNE = "hello".to_string().try_non_empty().unwrap();
+    /// let s = s.into_bytes().into_string_ne().unwrap();
+    /// assert_eq!("hello", *s);
+    /// ```
+    pub fn into_string_ne(self) -> Result<NonEmpty<String>, FromUtf8Error> {
+        String::from_utf8(self.inner).map(|inner| NonEmpty { inner })
+    }
+
+    /// Borrows the bytes as a `NonEmpty<&str>`, like `str::from_utf8`.
+    ///
+    /// # Errors
+    /// Returns the `Utf8Error` of `str::from_utf8` if the bytes are not valid UTF-8.
+    #[inline]
+    pub fn as_str_ne(&self) -> Result<NonEmpty<&str>, Utf8Error> {
+        str::from_utf8(&self.inner).map(|inner| NonEmpty { inner })
+    }
+
+    /// Converts the bytes to a string, replacing invalid UTF-8 with
+    /// `U+FFFD REPLACEMENT CHARACTER`, like `String::from_utf8_lossy`.
+    ///
+    /// The result is never empty, invalid bytes are replaced
+    /// by at least one replacement character.
+    #[inline]
+    pub fn to_str_lossy_ne(&self) -> NonEmpty<Cow<'_, str>> {
+        NonEmpty { inner: String::from_utf8_lossy(&self.inner) }
+    }
+}
+
+impl<'a> NonEmpty<&'a [u8]> {
+    /// Converts the bytes to a `NonEmpty<&str>` without copying, like `str::from_utf8`.
+    ///
+    /// # Errors
+    /// Returns the `Utf8Error` of `str::from_utf8` if the bytes are not valid UTF-8.
+    ///
+    /// # Examples
+    /// ```
+    /// use non_empty::TryNonEmpty;
+    ///
+    /// let frame = &b"ping"[..];
+    /// let s = frame.try_non_empty().unwrap().as_str_ne().unwrap();
+    /// assert_eq!("ping", *s);
+    /// ```
+    #[inline]
+    pub fn as_str_ne(&self) -> Result<NonEmpty<&'a str>, Utf8Error> {
+        str::from_utf8(self.inner).map(|inner| NonEmpty { inner })
+    }
+
+    /// Converts the bytes to a string, replacing invalid UTF-8 with
+    /// `U+FFFD REPLACEMENT CHARACTER`, like `String::from_utf8_lossy`.
+    ///
+    /// The result is never empty, invalid bytes are replaced
+    /// by at least one replacement character.
+    #[inline]
+    pub fn to_str_lossy_ne(&self) -> NonEmpty<Cow<'a, str>> {
+        NonEmpty { inner: String::from_utf8_lossy(self.inner) }
+    }
+}
+
+impl NonEmpty<Vec<NonEmpty<String>>> {
+    /// Joins the strings with the separator `sep`,
+    /// the result is never empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use non_empty::{StringNE, TryNonEmpty, VecNE};
+    ///
+    /// let values: VecNE<StringNE> = vec![
+    ///     "gzip".to_string().try_non_empty().unwrap(),
+    ///     "deflate".to_string().try_non_empty().unwrap(),
+    /// ].try_non_empty().unwrap();
+    ///
+    /// let header: StringNE = values.join_ne(", ");
+    /// assert_eq!("gzip, deflate", *header);
+    /// ```
+    pub fn join_ne(&self, sep: &str) -> NonEmpty<String> {
+        NonEmpty { inner: join(self.inner.iter().map(|s| s.inner.as_str()), sep) }
+    }
+}
+
+impl NonEmpty<Vec<NonEmpty<&str>>> {
+    /// Joins the strings with the separator `sep`,
+    /// the result is never empty.
+    pub fn join_ne(&self, sep: &str) -> NonEmpty<String> {
+        NonEmpty { inner: join(self.inner.iter().map(|s| s.inner), sep) }
+    }
+}
+
+impl NonEmpty<Vec<String>> {
+    /// Joins the strings with the separator `sep`.
+    ///
+    /// Returns `None` if the result is empty, which happens when all
+    /// strings and the separator are empty.
+    pub fn join(&self, sep: &str) -> Option<NonEmpty<String>> {
+        let inner = join(self.inner.iter().map(|s| s.as_str()), sep);
+        if inner.is_empty() {
+            return None
+        }
+        Some(NonEmpty { inner })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use super::super::{NonEmpty, StringNE, TryNonEmpty, VecNE, ne};
+
+    #[test]
+    fn as_str_ne() {
+        let s = ne("hello".to_string());
+        let borrowed: NonEmpty<&str> = s.as_str_ne();
+        assert_eq!("hello", borrowed.into_inner());
+        assert_eq!(s.as_ptr(), s.as_str_ne().as_ptr());
+    }
+
+    #[test]
+    fn first_and_last_char() {
+        let s = ne("abc".to_string());
+        assert_eq!('a', s.first_char());
+        assert_eq!('c', s.last_char());
+        assert_eq!(3, s.char_len_ne().get());
+
+        let s = ne("x");
+        assert_eq!('x', s.first_char());
+        assert_eq!('x', s.last_char());
+        assert_eq!(1, s.char_len_ne().get());
+    }
+
+    #[test]
+    fn first_and_last_char_multi_byte() {
+        // 'e' followed by a combining acute accent
+        let s = ne("cafe\u{301}");
+        assert_eq!('c', s.first_char());
+        assert_eq!('\u{301}', s.last_char());
+        assert_eq!(5, s.char_len_ne().get());
+        assert_eq!(6, s.len());
+
+        let s = ne("🦀 rust 🎉".to_string());
+        assert_eq!('🦀', s.first_char());
+        assert_eq!('🎉', s.last_char());
+        assert_eq!(8, s.char_len_ne().get());
+    }
+
+    #[test]
+    fn push() {
+        let mut s = ne("usr".to_string());
+        for part in &["local", "bin"] {
+            s.push('/');
+            s.push_str(part);
+        }
+        s.push_str("");
+        s.insert_str(0, "/");
+        assert_eq!("/usr/local/bin", *s);
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_str_not_on_char_boundary() {
+        let mut s = ne("é".to_string());
+        s.insert_str(1, "x");
+    }
+
+    #[test]
+    fn char_iterators() {
+        let s = ne("héllo".to_string());
+        assert_eq!('h', s.chars_ne().first());
+        assert_eq!(b'o', s.bytes_ne().last());
+        assert_eq!(0xc3, s.bytes_ne().max());
+        assert_eq!((5, 'o'), s.char_indices_ne().last());
+
+        let s = ne("a🦀b");
+        assert_eq!(s.chars().collect::<Vec<_>>(), s.chars_ne().into_iter().collect::<Vec<_>>());
+        assert_eq!(s.bytes().collect::<Vec<_>>(), s.bytes_ne().into_iter().collect::<Vec<_>>());
+        assert_eq!(s.char_indices().collect::<Vec<_>>(), s.char_indices_ne().into_iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn lines_ne() {
+        let s = ne("a\nb\n".to_string());
+        assert_eq!(vec!["a", "b"], s.lines_ne().into_iter().collect::<Vec<_>>());
+        assert_eq!("a", s.first_line());
+
+        let s = ne("\n");
+        assert_eq!(vec![""], s.lines_ne().into_iter().collect::<Vec<_>>());
+        assert_eq!("", s.first_line());
+
+        assert_eq!("abc", ne("abc").first_line());
+        assert_eq!("abc", ne("abc\r\n").first_line());
+    }
+
+    #[test]
+    fn split_ne() {
+        let s = ne(",a,,b,".to_string());
+        assert_eq!(vec!["", "a", "", "b", ""], s.split_ne(',').into_iter().collect::<Vec<_>>());
+        assert_eq!("", s.split_ne(',').first());
+
+        let s = ne("abc");
+        assert_eq!("abc", s.split_ne(',').first());
+        assert_eq!(1, s.split_ne(',').into_iter().count());
+
+        let s = ne("a::b::");
+        assert_eq!(vec!["a", "b", ""], s.split_str_ne("::").into_iter().collect::<Vec<_>>());
+        assert_eq!("a::b::", s.split_str_ne(",").first());
+    }
+
+    #[test]
+    fn split_once_ne() {
+        assert_eq!(("key", Some("value")), ne("key=value").split_once_ne('='));
+        assert_eq!(("key", Some("a=b")), ne("key=a=b".to_string()).split_once_ne('='));
+        assert_eq!(("", Some("")), ne("=").split_once_ne('='));
+        assert_eq!(("key", None), ne("key").split_once_ne('='));
+    }
+
+    #[test]
+    fn case_conversion() {
+        assert_eq!("HELLO", *ne("Hello").to_uppercase_ne());
+        assert_eq!("hello", *ne("Hello".to_string()).to_lowercase_ne());
+
+        // same length in both directions
+        assert_eq!("ÄB", *ne("äb").to_uppercase_ne());
+        assert_eq!("äb", *ne("ÄB").to_lowercase_ne());
+
+        // case mappings which change the length
+        assert_eq!("SS", *ne("ß").to_uppercase_ne());
+        assert_eq!("i\u{307}", *ne("İ".to_string()).to_lowercase_ne());
+
+        // chars without case stay as they are
+        assert_eq!("1", *ne("1").to_lowercase_ne());
+    }
+
+    #[test]
+    fn trim_ne() {
+        assert_eq!("a b", *ne(" a b\n").trim_ne().unwrap());
+        assert_eq!("a", *ne("a".to_string()).trim_ne().unwrap());
+        assert!(ne(" ").trim_ne().is_none());
+        assert!(ne(" \t\n\u{3000}".to_string()).trim_ne().is_none());
+    }
+
+    #[test]
+    fn repeat_ne() {
+        let n = |n| NonZeroUsize::new(n).unwrap();
+        assert_eq!("ab", *ne("ab").repeat_ne(n(1)));
+        assert_eq!("ababab", *ne("ab".to_string()).repeat_ne(n(3)));
+        assert_eq!("ü".repeat(100), *ne("ü").repeat_ne(n(100)));
+    }
+
+    #[test]
+    fn utf8_round_trip() {
+        let s = ne("grüße 🦀".to_string());
+        let bytes: NonEmpty<Vec<u8>> = s.clone().into_bytes();
+        assert_eq!(s.as_bytes(), &bytes[..]);
+        assert_eq!(s, bytes.into_string_ne().unwrap());
+
+        assert_eq!(&[0xc3, 0xbc][..], ne("ü").as_bytes_ne().into_inner());
+        assert_eq!(s.as_bytes(), s.as_bytes_ne().into_inner());
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let bytes = ne(vec![b'a', 0xff, b'b']);
+        let err = bytes.into_string_ne().unwrap_err();
+        assert_eq!(1, err.utf8_error().valid_up_to());
+        assert_eq!(vec![b'a', 0xff, b'b'], err.into_bytes());
+    }
+
+    #[test]
+    fn join_ne() {
+        let v: VecNE<StringNE> = ne(vec![ne("a".to_string()), ne("b".to_string()), ne("c".to_string())]);
+        assert_eq!("a, b, c", *v.join_ne(", "));
+        assert_eq!("abc", *v.join_ne(""));
+
+        let v: VecNE<NonEmpty<&str>> = ne(vec![ne("a"), ne("b")]);
+        assert_eq!("a-b", *v.join_ne("-"));
+        assert_eq!("ab", *v.join_ne(""));
+    }
+
+    #[test]
+    fn join_ne_single_element() {
+        let v: VecNE<StringNE> = ne(vec![ne("a".to_string())]);
+        assert_eq!("a", *v.join_ne(", "));
+
+        let v: VecNE<NonEmpty<&str>> = ne(vec![ne("a")]);
+        assert_eq!("a", *v.join_ne(", "));
+    }
+
+    #[test]
+    fn join() {
+        let v: VecNE<String> = ne(vec!["a".to_string(), "".to_string(), "c".to_string()]);
+        assert_eq!("a,,c", *v.join(",").unwrap());
+        assert_eq!("ac", *v.join("").unwrap());
+
+        let v: VecNE<String> = ne(vec!["".to_string()]);
+        assert!(v.join(",").is_none());
+
+        let v: VecNE<String> = ne(vec!["".to_string(), "".to_string()]);
+        assert_eq!(",", *v.join(",").unwrap());
+        assert!(v.join("").is_none());
+    }
+
+    #[test]
+    fn bytes_as_str_ne() {
+        let v: VecNE<u8> = ne("héllo".as_bytes().to_vec());
+        assert_eq!("héllo", *v.as_str_ne().unwrap());
+        assert_eq!("héllo", *v.to_str_lossy_ne());
+
+        let b = ne(&b"abc"[..]);
+        assert_eq!("abc", *b.as_str_ne().unwrap());
+
+        let start = ne(&b"\xffabc"[..]);
+        assert_eq!(0, start.as_str_ne().unwrap_err().valid_up_to());
+        assert_eq!("\u{fffd}abc", *start.to_str_lossy_ne());
+
+        let middle: VecNE<u8> = ne(b"ab\xc3cd".to_vec());
+        assert_eq!(2, middle.as_str_ne().unwrap_err().valid_up_to());
+        assert_eq!("ab\u{fffd}cd", *middle.to_str_lossy_ne());
+
+        // a single invalid byte still results in a non-empty string
+        assert_eq!("\u{fffd}", *ne(&[0x80_u8][..]).to_str_lossy_ne());
+    }
+
+    #[test]
+    fn parse_ne() {
+        use std::error::Error;
+        use std::str::FromStr;
+
+        use super::super::{u32NE, f64NE, IsEmpty, ParseNeError};
+
+        let n: u32NE = ne("42").parse_ne().unwrap();
+        assert_eq!(42, *n);
+        assert_eq!(Err(ParseNeError::Empty), ne("0").parse_ne::<u32>());
+        let err = ne("abc".to_string()).parse_ne::<u32>().unwrap_err();
+        assert!(matches!(err, ParseNeError::Parse(_)));
+        assert_eq!("failed to parse value", err.to_string());
+        assert_eq!("abc".parse::<u32>().unwrap_err().to_string(), err.source().unwrap().to_string());
+        assert_eq!("parsed value is empty", ParseNeError::<std::num::ParseIntError>::Empty.to_string());
+
+        let f: f64NE = ne("-0.5").parse_ne().unwrap();
+        assert_eq!(-0.5, *f);
+        assert_eq!(Err(ParseNeError::Empty), ne("0.0").parse_ne::<f64>());
+
+        /// Comma separated tags.
+        #[derive(Debug, PartialEq)]
+        struct Tags(Vec<String>);
+
+        impl FromStr for Tags {
+            type Err = ();
+
+            fn from_str(s: &str) -> Result<Tags, ()> {
+                if s.contains(';') {
+                    return Err(())
+                }
+                Ok(Tags(s.split(',').filter(|t| !t.is_empty()).map(str::to_string).collect()))
+            }
+        }
+
+        impl IsEmpty for Tags {
+            fn is_empty(&self) -> bool {
+                self.0.is_empty()
+            }
+        }
+
+        let tags = ne("a,,b".to_string()).parse_ne::<Tags>().unwrap();
+        assert_eq!(Tags(vec!["a".to_string(), "b".to_string()]), *tags);
+        assert_eq!(Err(ParseNeError::Empty), ne(",,").parse_ne::<Tags>());
+        assert_eq!(Err(ParseNeError::Parse(())), ne("a;b").parse_ne::<Tags>());
+    }
+
+    #[test]
+    fn capacity() {
+        let mut s: StringNE = "abc".to_string().try_non_empty().unwrap();
+        s.reserve(100);
+        assert!(s.capacity() >= 103);
+        s.shrink_to_fit();
+        assert!(s.capacity() < 103);
+        s.reserve_exact(5);
+        s.shrink_to(4);
+        assert!(s.capacity() >= 4);
+        assert_eq!("abc", *s);
+    }
+}
@@ -0,0 +1,63 @@
+extern crate time;
+
+use std::time as std_time;
+
+use self::time::Duration;
+
+use super::{IsEmpty, NonEmpty};
+
+/// Non-empty `time::Duration`, duration != 0
+pub type TimeDurationNE = NonEmpty<Duration>;
+
+/// Only a zero duration is empty.
+///
+/// Negative durations are not empty, they are meaningful offsets.
+impl IsEmpty for Duration {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.is_zero()
+    }
+}
+
+impl From<NonEmpty<Duration>> for Duration {
+    #[inline]
+    fn from(v: NonEmpty<Duration>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl NonEmpty<Duration> {
+    /// Returns the absolute value as a non-empty std `Duration`.
+    ///
+    /// The conversion is lossless, even for `Duration::MIN`.
+    #[inline]
+    pub fn abs_ne(&self) -> NonEmpty<std_time::Duration> {
+        NonEmpty { inner: self.inner.unsigned_abs() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time as std_time;
+
+    use super::time::Duration;
+    use super::TimeDurationNE;
+    use super::super::{IsEmpty, TryNonEmpty};
+
+    #[test]
+    fn zero_positive_negative() {
+        assert!(Duration::ZERO.is_empty());
+        assert!(Duration::ZERO.try_non_empty().is_none());
+
+        let pos: TimeDurationNE = Duration::milliseconds(1500).try_non_empty().unwrap();
+        assert_eq!(std_time::Duration::from_millis(1500), *pos.abs_ne());
+
+        let neg: TimeDurationNE = Duration::nanoseconds(-7).try_non_empty().unwrap();
+        assert_eq!(std_time::Duration::from_nanos(7), *neg.abs_ne());
+        let inner: Duration = neg.into();
+        assert_eq!(Duration::nanoseconds(-7), inner);
+
+        let min: TimeDurationNE = Duration::MIN.try_non_empty().unwrap();
+        assert_eq!(std_time::Duration::new(i64::MAX as u64 + 1, 999_999_999), *min.abs_ne());
+    }
+}
@@ -0,0 +1,141 @@
+extern crate tinyvec;
+
+use std::cmp::Ordering;
+use std::num::NonZeroUsize;
+use std::slice;
+
+use self::tinyvec::{Array, ArrayVec, TinyVec};
+
+use super::{IsEmpty, NonEmpty, NonEmptyIter};
+
+/// Non-empty `TinyVec`.
+pub type TinyVecNE<A> = NonEmpty<TinyVec<A>>;
+
+/// Non-empty `tinyvec::ArrayVec`.
+pub type TinyArrayVecNE<A> = NonEmpty<ArrayVec<A>>;
+
+impl<A: Array> IsEmpty for TinyVec<A> {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        TinyVec::is_empty(self)
+    }
+}
+
+impl<A: Array> IsEmpty for ArrayVec<A> {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        ArrayVec::is_empty(self)
+    }
+}
+
+impl<A: Array> From<NonEmpty<TinyVec<A>>> for TinyVec<A> {
+    #[inline]
+    fn from(v: NonEmpty<TinyVec<A>>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl<A: Array> From<NonEmpty<ArrayVec<A>>> for ArrayVec<A> {
+    #[inline]
+    fn from(v: NonEmpty<ArrayVec<A>>) -> Self {
+        v.into_inner()
+    }
+}
+
+impl<T, A: Array<Item = T>> NonEmpty<TinyVec<A>> {
+    slice_methods!();
+
+    /// Returns true if the elements are stored inline, see `TinyVec::is_inline`.
+    #[inline]
+    pub fn is_inline(&self) -> bool {
+        self.inner.is_inline()
+    }
+}
+
+impl<T, A: Array<Item = T>> NonEmpty<ArrayVec<A>> {
+    slice_methods!();
+}
+
+/// Moves the elements inline if they fit, without re-validation.
+impl<A: Array> From<NonEmpty<Vec<A::Item>>> for NonEmpty<TinyVec<A>> {
+    fn from(v: NonEmpty<Vec<A::Item>>) -> Self {
+        let inner = if v.inner.len() <= A::CAPACITY {
+            TinyVec::Inline(v.inner.into_iter().collect())
+        } else {
+            TinyVec::Heap(v.inner)
+        };
+        NonEmpty { inner }
+    }
+}
+
+impl<A: Array> From<NonEmpty<TinyVec<A>>> for NonEmpty<Vec<A::Item>> {
+    #[inline]
+    fn from(v: NonEmpty<TinyVec<A>>) -> Self {
+        NonEmpty { inner: v.inner.into_vec() }
+    }
+}
+
+impl<A: Array> From<NonEmpty<ArrayVec<A>>> for NonEmpty<Vec<A::Item>> {
+    #[inline]
+    fn from(v: NonEmpty<ArrayVec<A>>) -> Self {
+        NonEmpty { inner: v.inner.into_iter().collect() }
+    }
+}
+
+impl<A: Array> From<NonEmpty<ArrayVec<A>>> for NonEmpty<TinyVec<A>> {
+    #[inline]
+    fn from(v: NonEmpty<ArrayVec<A>>) -> Self {
+        NonEmpty { inner: TinyVec::Inline(v.inner) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::tinyvec::{ArrayVec, TinyVec};
+    use super::{TinyArrayVecNE, TinyVecNE};
+    use super::super::{TryNonEmpty, VecNE};
+
+    #[test]
+    fn empty_rejection() {
+        assert!(TinyVec::<[u8; 4]>::new().try_non_empty().is_none());
+        assert!(ArrayVec::<[u8; 4]>::new().try_non_empty().is_none());
+        assert!(TinyVec::<[u8; 4]>::from(&[1][..]).try_non_empty().is_some());
+    }
+
+    #[test]
+    fn inline_and_heap() {
+        let mut t = TinyVec::<[i32; 2]>::from(&[3, 1][..]);
+        assert!(t.is_inline());
+        t.push(2);
+        let v: TinyVecNE<[i32; 2]> = t.try_non_empty().unwrap();
+        assert!(!v.is_inline());
+        assert_eq!(3, *v.max());
+        assert_eq!(1, *v.min());
+        assert_eq!(3, *v.iter_ne().first());
+        let inner: TinyVec<[i32; 2]> = v.into();
+        assert_eq!(&[3, 1, 2], &inner[..]);
+    }
+
+    #[test]
+    fn vec_conversions() {
+        let v: VecNE<i32> = vec![1, 2].try_non_empty().unwrap();
+        let t: TinyVecNE<[i32; 4]> = v.into();
+        assert!(t.is_inline());
+        assert_eq!(&[1, 2], &t[..]);
+
+        let v: VecNE<i32> = t.into();
+        assert_eq!(vec![1, 2], *v);
+
+        let big: VecNE<i32> = (0..10).collect::<Vec<_>>().try_non_empty().unwrap();
+        let t: TinyVecNE<[i32; 4]> = big.into();
+        assert!(!t.is_inline());
+        assert_eq!(10, t.len());
+
+        let a: TinyArrayVecNE<[i32; 4]> = ArrayVec::from_array_len([7, 0, 0, 0], 1).try_non_empty().unwrap();
+        assert_eq!(7, *a.max());
+        let t: TinyVecNE<[i32; 4]> = a.clone().into();
+        assert!(t.is_inline());
+        let v: VecNE<i32> = a.into();
+        assert_eq!(vec![7], *v);
+    }
+}
@@ -0,0 +1,123 @@
+extern crate toml;
+
+use self::toml::value::{Table, Value};
+
+use super::{IsEmpty, NonEmpty};
+
+/// A TOML value which is neither `""`, `[]` nor `{}`.
+pub type TomlValueNE = NonEmpty<Value>;
+
+/// An empty string, an empty array and an empty table are empty.
+///
+/// Numbers, booleans and datetimes are never empty, `0` and `false` are meaningful values.
+impl IsEmpty for Value {
+    fn is_empty(&self) -> bool {
+        match *self {
+            Value::String(ref s) => s.is_empty(),
+            Value::Array(ref a) => a.is_empty(),
+            Value::Table(ref t) => t.is_empty(),
+            Value::Integer(_) | Value::Float(_) | Value::Boolean(_) | Value::Datetime(_) => false,
+        }
+    }
+}
+
+impl IsEmpty for Table {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        Table::is_empty(self)
+    }
+}
+
+/// Recursively removes empty array elements and table members,
+/// returns `None` if the pruned value is empty itself.
+///
+/// Works like [prune_empty](fn.prune_empty.html) for JSON values.
+///
+/// # Examples
+/// ```
+/// extern crate toml;
+/// extern crate non_empty;
+///
+/// use non_empty::prune_empty_toml;
+///
+/// # fn main() {
+/// let v: toml::Value = toml::from_str("a = 0\nb = ''\n[c]\nd = []").unwrap();
+/// let expected: toml::Value = toml::from_str("a = 0").unwrap();
+/// assert_eq!(expected, *prune_empty_toml(v).unwrap());
+/// # }
+/// ```
+pub fn prune_empty_toml(value: Value) -> Option<TomlValueNE> {
+    let inner = match value {
+        Value::Array(a) => Value::Array(a.into_iter()
+            .filter_map(prune_empty_toml)
+            .map(NonEmpty::into_inner)
+            .collect()),
+        Value::Table(t) => Value::Table(t.into_iter()
+            .filter_map(|(k, v)| prune_empty_toml(v).map(|v| (k, v.into_inner())))
+            .collect()),
+        v => v,
+    };
+    if inner.is_empty() {
+        return None
+    }
+    Some(NonEmpty { inner })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::toml::value::{Datetime, Table, Value};
+    use super::prune_empty_toml;
+    use super::super::{IsEmpty, TryNonEmpty};
+
+    fn parse(s: &str) -> Value {
+        super::toml::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn variants() {
+        assert!(Value::from("").is_empty());
+        assert!(Value::Array(vec![]).is_empty());
+        assert!(Value::Table(Table::new()).is_empty());
+        assert!(Table::new().is_empty());
+
+        assert!(!Value::from(0).is_empty());
+        assert!(!Value::from(0.0).is_empty());
+        assert!(!Value::from(false).is_empty());
+        assert!(!Value::Datetime("1979-05-27".parse::<Datetime>().unwrap()).is_empty());
+        assert!(!Value::from("a").is_empty());
+        assert!(!Value::Array(vec![Value::from("")]).is_empty());
+
+        assert!(Value::from("").try_non_empty().is_none());
+        assert!(Value::from(1).try_non_empty().is_some());
+    }
+
+    #[test]
+    fn prune_nested() {
+        let v = parse(r#"
+            name = "x"
+            empty = ""
+            zero = 0
+            tags = ["", "a"]
+            nested = [[], [""]]
+
+            [server]
+            host = ""
+
+            [server.tls]
+            certs = []
+
+            [kept.a]
+            b = true
+        "#);
+        let expected = parse(r#"
+            name = "x"
+            zero = 0
+            tags = ["a"]
+
+            [kept.a]
+            b = true
+        "#);
+        assert_eq!(expected, *prune_empty_toml(v).unwrap());
+        assert!(prune_empty_toml(parse("[a.b]\nc = ''")).is_none());
+    }
+}
@@ -0,0 +1,85 @@
+extern crate unicode_segmentation;
+
+use self::unicode_segmentation::{Graphemes, UnicodeSegmentation, UnicodeWords};
+
+use super::{NonEmpty, NonEmptyIter};
+
+macro_rules! segmentation_methods {
+    () => {
+        /// Returns an iterator over the grapheme clusters, guaranteed to yield at least one item.
+        ///
+        /// Use `extended = true` for extended grapheme clusters, as recommended by UAX #29.
+        ///
+        /// # Examples
+        /// ```
+        /// use non_empty::TryNonEmpty;
+        ///
+        /// let s = "e\u{301}x".try_non_empty().unwrap();
+        /// assert_eq!(3, s.chars().count());
+        /// assert_eq!(2, s.graphemes_ne(true).into_iter().count());
+        /// ```
+        pub fn graphemes_ne(&self, extended: bool) -> NonEmptyIter<Graphemes<'_>> {
+            NonEmptyIter::new_unchecked(self.inner.graphemes(extended))
+        }
+
+        /// Returns the first extended grapheme cluster.
+        pub fn first_grapheme(&self) -> &str {
+            self.inner.graphemes(true).next().expect("NonEmpty is never empty")
+        }
+
+        /// Returns an iterator over the words, like `UnicodeSegmentation::unicode_words`.
+        ///
+        /// The iterator may yield nothing, a string of only punctuation
+        /// or whitespace is non-empty but has no words.
+        pub fn unicode_words(&self) -> UnicodeWords<'_> {
+            self.inner.unicode_words()
+        }
+    };
+}
+
+impl NonEmpty<String> {
+    segmentation_methods!();
+}
+
+impl NonEmpty<&str> {
+    segmentation_methods!();
+}
+
+impl NonEmpty<str> {
+    segmentation_methods!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{NonEmpty, StringNE, TryNonEmpty};
+
+    #[test]
+    fn zwj_sequence_is_one_grapheme() {
+        let family = "👨‍👩‍👧";
+        let s: StringNE = format!("{}!", family).try_non_empty().unwrap();
+        assert_eq!(6, s.chars().count());
+        assert_eq!(vec![family, "!"], s.graphemes_ne(true).into_iter().collect::<Vec<_>>());
+        assert_eq!(family, s.first_grapheme());
+    }
+
+    #[test]
+    fn combining_marks() {
+        let s: NonEmpty<&str> = "a\u{308}o\u{308}".try_non_empty().unwrap();
+        assert_eq!("a\u{308}", s.first_grapheme());
+        assert_eq!("o\u{308}", s.graphemes_ne(false).last());
+        assert_eq!(2, s.graphemes_ne(true).into_iter().count());
+
+        let r: &NonEmpty<str> = NonEmpty::from_ref("\u{308}").unwrap();
+        assert_eq!("\u{308}", r.first_grapheme());
+    }
+
+    #[test]
+    fn unicode_words() {
+        let s: NonEmpty<&str> = "Hello, wörld!".try_non_empty().unwrap();
+        assert_eq!(vec!["Hello", "wörld"], s.unicode_words().collect::<Vec<_>>());
+
+        let punctuation: StringNE = "?! ...".to_string().try_non_empty().unwrap();
+        assert_eq!(None, punctuation.unicode_words().next());
+        assert_eq!("?", punctuation.first_grapheme());
+    }
+}
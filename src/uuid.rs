@@ -0,0 +1,56 @@
+extern crate uuid;
+
+use self::uuid::Uuid;
+
+use super::{IsEmpty, NonEmpty};
+
+/// A UUID which is not the nil UUID.
+pub type UuidNE = NonEmpty<Uuid>;
+
+/// The nil UUID `00000000-0000-0000-0000-000000000000` is empty.
+impl IsEmpty for Uuid {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.is_nil()
+    }
+}
+
+impl From<NonEmpty<Uuid>> for Uuid {
+    #[inline]
+    fn from(v: NonEmpty<Uuid>) -> Self {
+        v.into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::uuid::Uuid;
+    use super::UuidNE;
+    use super::super::TryNonEmpty;
+
+    const V4: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+
+    #[test]
+    fn nil_is_empty() {
+        assert!(Uuid::nil().try_non_empty().is_none());
+
+        let id = Uuid::parse_str(V4).unwrap();
+        assert_eq!(Some(4), id.get_version_num().into());
+        let ne: UuidNE = id.try_non_empty().unwrap();
+        let inner: Uuid = ne.into();
+        assert_eq!(id, inner);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        extern crate serde_json as json;
+
+        let id: UuidNE = json::from_str(&format!("\"{}\"", V4)).unwrap();
+        assert_eq!(V4, id.to_string());
+        assert_eq!(format!("\"{}\"", V4), json::to_string(&id).unwrap());
+
+        let err = json::from_str::<UuidNE>("\"00000000-0000-0000-0000-000000000000\"").unwrap_err();
+        assert!(err.to_string().contains("expected a non-empty value"), "{}", err);
+    }
+}
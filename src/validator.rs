@@ -0,0 +1,64 @@
+extern crate validator;
+
+use self::validator::ValidateLength;
+
+use super::NonEmpty;
+
+/// Lets `#[validate(length(...))]` check a `NonEmpty` field like its inner value,
+/// e.g. `#[validate(length(max = 20))] name: StringNE`.
+///
+/// There's no need for `min = 1`, a `NonEmpty` value is never empty.
+impl<L, T> ValidateLength<L> for NonEmpty<T>
+    where L: PartialEq + PartialOrd,
+          T: ValidateLength<L> + ?Sized,
+{
+    #[inline]
+    fn length(&self) -> Option<L> {
+        self.inner.length()
+    }
+}
+
+// the derive needs the fields to implement `Serialize`
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::validator::Validate;
+    use super::super::{StringNE, TryNonEmpty, VecNE};
+
+    #[derive(Validate)]
+    #[validate(crate = "super::validator")]
+    struct SignUp {
+        #[validate(length(max = 5))]
+        name: StringNE,
+        #[validate(length(min = 2, max = 3))]
+        tags: VecNE<u8>,
+        #[validate(length(max = 5))]
+        nick: Option<StringNE>,
+    }
+
+    fn sign_up(name: &str, tags: Vec<u8>) -> SignUp {
+        SignUp {
+            name: name.to_string().try_non_empty().unwrap(),
+            tags: tags.try_non_empty().unwrap(),
+            nick: None,
+        }
+    }
+
+    #[test]
+    fn derive_length() {
+        assert!(sign_up("bob", vec![1, 2]).validate().is_ok());
+
+        let errors = sign_up("robert", vec![1, 2]).validate().unwrap_err();
+        assert!(errors.field_errors().contains_key("name"));
+        assert!(!errors.field_errors().contains_key("tags"));
+
+        let errors = sign_up("bob", vec![1]).validate().unwrap_err();
+        assert!(errors.field_errors().contains_key("tags"));
+
+        // counts chars, like for String
+        assert!(sign_up("ééééé", vec![1, 2]).validate().is_ok());
+
+        let mut s = sign_up("bob", vec![1, 2]);
+        s.nick = Some("bobby_".to_string().try_non_empty().unwrap());
+        assert!(s.validate().unwrap_err().field_errors().contains_key("nick"));
+    }
+}
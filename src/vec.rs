@@ -0,0 +1,817 @@
+use std::cmp::Ordering;
+use std::num::NonZeroUsize;
+use std::slice;
+
+use super::{NonEmpty, NonEmptyIter, WouldBeEmpty};
+
+/////////////////////////////////////////////////////////////////////////
+// Methods shared by NonEmpty<Vec<T>> and NonEmpty<&[T]>
+/////////////////////////////////////////////////////////////////////////
+
+macro_rules! slice_methods {
+    () => {
+        /// Returns the maximum element.
+        ///
+        /// If several elements are equally maximum, the last one is returned,
+        /// like [Iterator::max](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.max).
+        pub fn max(&self) -> &T
+            where T: Ord,
+        {
+            self.inner.iter().max().expect("NonEmpty is never empty")
+        }
+
+        /// Returns the minimum element.
+        ///
+        /// If several elements are equally minimum, the first one is returned,
+        /// like [Iterator::min](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.min).
+        pub fn min(&self) -> &T
+            where T: Ord,
+        {
+            self.inner.iter().min().expect("NonEmpty is never empty")
+        }
+
+        /// Returns the element that gives the maximum value from the function `f`.
+        ///
+        /// If several elements are equally maximum, the last one is returned.
+        ///
+        /// # Examples
+        /// ```
+        /// use non_empty::{TryNonEmpty, VecNE};
+        ///
+        /// let v: VecNE<String> = vec!["a".to_string(), "abc".to_string(), "de".to_string()]
+        ///     .try_non_empty()
+        ///     .unwrap();
+        ///
+        /// // no Option to unwrap, v is never empty
+        /// let longest: &String = v.max_by_key(|s| s.len());
+        /// assert_eq!("abc", longest);
+        /// ```
+        pub fn max_by_key<K, F>(&self, f: F) -> &T
+            where K: Ord,
+                  F: FnMut(&T) -> K,
+        {
+            let mut f = f;
+            self.inner.iter().max_by_key(|v| f(v)).expect("NonEmpty is never empty")
+        }
+
+        /// Returns the element that gives the minimum value from the function `f`.
+        ///
+        /// If several elements are equally minimum, the first one is returned.
+        pub fn min_by_key<K, F>(&self, f: F) -> &T
+            where K: Ord,
+                  F: FnMut(&T) -> K,
+        {
+            let mut f = f;
+            self.inner.iter().min_by_key(|v| f(v)).expect("NonEmpty is never empty")
+        }
+
+        /// Returns the maximum element with respect to the comparison function `compare`.
+        ///
+        /// If several elements are equally maximum, the last one is returned.
+        pub fn max_by<F>(&self, compare: F) -> &T
+            where F: FnMut(&T, &T) -> Ordering,
+        {
+            let mut compare = compare;
+            self.inner.iter().max_by(|a, b| compare(a, b)).expect("NonEmpty is never empty")
+        }
+
+        /// Returns the minimum element with respect to the comparison function `compare`.
+        ///
+        /// If several elements are equally minimum, the first one is returned.
+        pub fn min_by<F>(&self, compare: F) -> &T
+            where F: FnMut(&T, &T) -> Ordering,
+        {
+            let mut compare = compare;
+            self.inner.iter().min_by(|a, b| compare(a, b)).expect("NonEmpty is never empty")
+        }
+
+        /// Returns an iterator over the elements, guaranteed to yield at least one item.
+        pub fn iter_ne(&self) -> NonEmptyIter<slice::Iter<'_, T>> {
+            NonEmptyIter::new_unchecked(self.inner.iter())
+        }
+
+        /// Returns an iterator over non-empty chunks of `size` elements,
+        /// the last chunk may be shorter.
+        ///
+        /// Unlike `slice::chunks`, a chunk size of zero can't be passed.
+        ///
+        /// # Examples
+        /// ```
+        /// use std::num::NonZeroUsize;
+        /// use non_empty::{TryNonEmpty, VecNE};
+        ///
+        /// let v: VecNE<i32> = vec![1, 2, 3].try_non_empty().unwrap();
+        /// let size = NonZeroUsize::new(2).unwrap();
+        ///
+        /// let chunks: Vec<&[i32]> = v.chunks_ne(size).map(|c| c.into_inner()).collect();
+        /// assert_eq!(vec![&[1, 2][..], &[3][..]], chunks);
+        /// ```
+        pub fn chunks_ne<'a>(&'a self, size: NonZeroUsize) -> impl Iterator<Item = NonEmpty<&'a [T]>>
+            where T: 'a,
+        {
+            self.inner.chunks(size.get()).map(|inner| NonEmpty { inner })
+        }
+
+        /// Returns the runs of consecutive elements with equal keys, like `slice::chunk_by`.
+        ///
+        /// Every run is non-empty and there is at least one run.
+        ///
+        /// # Examples
+        /// ```
+        /// use non_empty::{TryNonEmpty, VecNE};
+        ///
+        /// let v: VecNE<i32> = vec![1, 3, 2, 4, 5].try_non_empty().unwrap();
+        /// let runs = v.chunk_by_ne(|n| n % 2);
+        /// assert_eq!(&[1, 3][..], *runs.first());
+        /// ```
+        pub fn chunk_by_ne<'a, K, F>(&'a self, mut key: F)
+            -> NonEmptyIter<impl Iterator<Item = NonEmpty<&'a [T]>>>
+            where K: PartialEq,
+                  F: FnMut(&T) -> K + 'a,
+                  T: 'a,
+        {
+            let iter = self.inner.chunk_by(move |a, b| key(a) == key(b))
+                .map(|inner| NonEmpty { inner });
+            NonEmptyIter::new_unchecked(iter)
+        }
+
+        /// Repeats the elements `n` times.
+        ///
+        /// Unlike `slice::repeat`, a count of zero can't be passed,
+        /// so the result is never empty, and `T` needs only to be `Clone`.
+        ///
+        /// # Panics
+        /// Panics if the capacity would overflow, like `slice::repeat`.
+        pub fn repeat_ne(&self, n: NonZeroUsize) -> NonEmpty<Vec<T>>
+            where T: Clone,
+        {
+            let capacity = self.inner.len().checked_mul(n.get()).expect("capacity overflow");
+            let mut inner = Vec::with_capacity(capacity);
+            for _ in 0..n.get() {
+                inner.extend_from_slice(&self.inner);
+            }
+            NonEmpty { inner }
+        }
+
+        /// Returns an iterator over all non-empty windows of `size` elements.
+        ///
+        /// Yields nothing if `size` is greater than the length, like `slice::windows`.
+        pub fn windows_ne<'a>(&'a self, size: NonZeroUsize) -> impl Iterator<Item = NonEmpty<&'a [T]>>
+            where T: 'a,
+        {
+            self.inner.windows(size.get()).map(|inner| NonEmpty { inner })
+        }
+    };
+}
+
+/////////////////////////////////////////////////////////////////////////
+// Capacity methods, they never change the content
+/////////////////////////////////////////////////////////////////////////
+
+macro_rules! capacity_methods {
+    () => {
+        /// Reserves capacity for at least `additional` more elements, see `capacity()`.
+        #[inline]
+        pub fn reserve(&mut self, additional: usize) {
+            self.inner.reserve(additional)
+        }
+
+        /// Shrinks the capacity as much as possible.
+        #[inline]
+        pub fn shrink_to_fit(&mut self) {
+            self.inner.shrink_to_fit()
+        }
+
+        /// Shrinks the capacity with a lower bound.
+        #[inline]
+        pub fn shrink_to(&mut self, min_capacity: usize) {
+            self.inner.shrink_to(min_capacity)
+        }
+    };
+    (exact) => {
+        capacity_methods!();
+
+        /// Reserves capacity for exactly `additional` more elements.
+        #[inline]
+        pub fn reserve_exact(&mut self, additional: usize) {
+            self.inner.reserve_exact(additional)
+        }
+    };
+}
+
+/////////////////////////////////////////////////////////////////////////
+// Element-level mutation, which never changes the length
+/////////////////////////////////////////////////////////////////////////
+
+macro_rules! slice_mut_methods {
+    () => {
+        /// Returns a mutable reference to the element at `i`, `None` if it is out of bounds.
+        #[inline]
+        pub fn get_mut_elem(&mut self, i: usize) -> Option<&mut T> {
+            self.inner.get_mut(i)
+        }
+
+        /// Returns a mutable reference to the first element.
+        ///
+        /// # Examples
+        /// ```
+        /// use non_empty::{TryNonEmpty, VecNE};
+        ///
+        /// let mut hits: VecNE<u64> = vec![0, 0].try_non_empty().unwrap();
+        /// *hits.first_mut() += 1;
+        /// *hits.last_mut() += 2;
+        /// assert_eq!(vec![1, 2], *hits);
+        /// ```
+        pub fn first_mut(&mut self) -> &mut T {
+            self.inner.first_mut().expect("NonEmpty is never empty")
+        }
+
+        /// Returns a mutable reference to the last element.
+        pub fn last_mut(&mut self) -> &mut T {
+            self.inner.last_mut().expect("NonEmpty is never empty")
+        }
+
+        /// Returns an iterator over mutable references to the elements.
+        #[inline]
+        pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+            self.inner.iter_mut()
+        }
+
+        /// Swaps the elements at `a` and `b`.
+        ///
+        /// # Panics
+        /// Panics if `a` or `b` are out of bounds, like `slice::swap`.
+        #[inline]
+        pub fn swap(&mut self, a: usize, b: usize) {
+            self.inner.swap(a, b)
+        }
+    };
+}
+
+impl<T> NonEmpty<Vec<T>> {
+    slice_methods!();
+    capacity_methods!(exact);
+    slice_mut_methods!();
+
+    /// Borrows the vector as a mutable non-empty slice.
+    #[inline]
+    pub fn as_mut_slice_ne(&mut self) -> NonEmpty<&mut [T]> {
+        NonEmpty { inner: self.inner.as_mut_slice() }
+    }
+
+    /// Splits the vector into runs of consecutive elements with equal keys.
+    ///
+    /// Consuming variant of [chunk_by_ne](#method.chunk_by_ne), see
+    /// [concat_ne](#method.concat_ne) for the reverse.
+    pub fn into_chunk_by_ne<K, F>(self, mut key: F) -> NonEmpty<Vec<NonEmpty<Vec<T>>>>
+        where K: PartialEq,
+              F: FnMut(&T) -> K,
+    {
+        let mut runs: Vec<NonEmpty<Vec<T>>> = Vec::new();
+        let mut last_key = None;
+        for v in self.inner {
+            let k = key(&v);
+            match runs.last_mut() {
+                Some(run) if last_key.as_ref() == Some(&k) => run.inner.push(v),
+                _ => runs.push(NonEmpty { inner: vec![v] }),
+            }
+            last_key = Some(k);
+        }
+        NonEmpty { inner: runs }
+    }
+
+    /// Creates a non-empty vector from its first element and the remaining elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use non_empty::{NonEmpty, VecNE};
+    ///
+    /// let v: VecNE<i32> = NonEmpty::from_head_tail(1, vec![2, 3]);
+    /// assert_eq!(vec![1, 2, 3], v.into_inner());
+    /// ```
+    pub fn from_head_tail(head: T, tail: Vec<T>) -> NonEmpty<Vec<T>> {
+        let mut inner = Vec::with_capacity(tail.len() + 1);
+        inner.push(head);
+        inner.extend(tail);
+        NonEmpty { inner }
+    }
+
+    /// Splits the vector into its first element and the remaining elements.
+    ///
+    /// This is O(n), the remaining elements are moved to the front of the buffer,
+    /// which is reused for the tail.
+    pub fn into_head_tail(self) -> (T, Vec<T>) {
+        let mut tail = self.inner;
+        let head = tail.remove(0);
+        (head, tail)
+    }
+
+    /// Splits off the elements from `at` onwards, like `Vec::split_off`.
+    ///
+    /// Returns `Err(WouldBeEmpty)` and leaves the vector untouched if `at` is 0,
+    /// the returned tail is empty if `at` equals the length.
+    ///
+    /// # Panics
+    /// Panics if `at > len`, like `Vec::split_off`.
+    ///
+    /// # Examples
+    /// ```
+    /// use non_empty::{TryNonEmpty, WouldBeEmpty};
+    ///
+    /// let mut v = vec![1, 2, 3].try_non_empty().unwrap();
+    /// assert_eq!(Err(WouldBeEmpty), v.split_off(0));
+    /// assert_eq!(Ok(vec![3]), v.split_off(2));
+    /// assert_eq!(vec![1, 2], *v);
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> Result<Vec<T>, WouldBeEmpty> {
+        if at == 0 {
+            return Err(WouldBeEmpty)
+        }
+        Ok(self.inner.split_off(at))
+    }
+
+    /// Splits the vector at `at` into a non-empty head and a possibly empty tail.
+    ///
+    /// # Panics
+    /// Panics if `at > len`, like `Vec::split_off`.
+    pub fn split_at_ne(self, at: NonZeroUsize) -> (NonEmpty<Vec<T>>, Vec<T>) {
+        let mut head = self.inner;
+        let tail = head.split_off(at.get());
+        (NonEmpty { inner: head }, tail)
+    }
+
+    /// Borrows the vector as a non-empty slice.
+    #[inline]
+    pub fn as_slice_ne(&self) -> NonEmpty<&[T]> {
+        NonEmpty { inner: self.inner.as_slice() }
+    }
+
+    /// Converts the vector into a `NonEmpty<Box<[T]>>`, dropping any excess capacity.
+    #[inline]
+    pub fn into_boxed_slice_ne(self) -> NonEmpty<Box<[T]>> {
+        NonEmpty { inner: self.inner.into_boxed_slice() }
+    }
+
+    /// Consumes the vector into an iterator, guaranteed to yield at least one item.
+    pub fn into_iter_ne(self) -> NonEmptyIter<::std::vec::IntoIter<T>> {
+        NonEmptyIter::new_unchecked(self.inner.into_iter())
+    }
+
+    /// Reduces the elements to a single one, by repeatedly applying `f`.
+    ///
+    /// Works like [Iterator::reduce](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.reduce),
+    /// but returns `T` directly. A single element is returned untouched.
+    ///
+    /// # Examples
+    /// ```
+    /// use non_empty::{TryNonEmpty, VecNE};
+    ///
+    /// let v: VecNE<u32> = vec![1, 2, 3].try_non_empty().unwrap();
+    /// assert_eq!(6, v.reduce(|a, b| a + b));
+    /// ```
+    pub fn reduce<F>(self, f: F) -> T
+        where F: FnMut(T, T) -> T,
+    {
+        self.inner.into_iter().reduce(f).expect("NonEmpty is never empty")
+    }
+
+    /// Folds the elements into a single value, using `init` on the first
+    /// element to create the initial accumulator instead of a seed value.
+    ///
+    /// `f` is not called for a single element vector.
+    pub fn fold_first<B, I, F>(self, init: I, f: F) -> B
+        where I: FnOnce(T) -> B,
+              F: FnMut(B, T) -> B,
+    {
+        let mut iter = self.inner.into_iter();
+        let first = iter.next().expect("NonEmpty is never empty");
+        iter.fold(init(first), f)
+    }
+}
+
+impl<T> NonEmpty<[T]> {
+    slice_methods!();
+    slice_mut_methods!();
+}
+
+impl<T> NonEmpty<&[T]> {
+    slice_methods!();
+}
+
+impl<T> NonEmpty<&mut [T]> {
+    slice_methods!();
+    slice_mut_methods!();
+}
+
+impl<T> NonEmpty<Box<[T]>> {
+    slice_methods!();
+    slice_mut_methods!();
+
+    /// Converts the boxed slice back into a `NonEmpty<Vec<T>>`, without copying.
+    #[inline]
+    pub fn into_vec_ne(self) -> NonEmpty<Vec<T>> {
+        NonEmpty { inner: self.inner.into_vec() }
+    }
+}
+
+impl<T> NonEmpty<Vec<NonEmpty<Vec<T>>>> {
+    /// Flattens a non-empty vector of non-empty vectors.
+    ///
+    /// # Examples
+    /// ```
+    /// use non_empty::{NonEmpty, TryNonEmpty, VecNE};
+    ///
+    /// let a: VecNE<i32> = NonEmpty::from_head_tail(1, vec![2]);
+    /// let b: VecNE<i32> = NonEmpty::from_head_tail(3, vec![]);
+    /// let nested: VecNE<VecNE<i32>> = vec![a, b].try_non_empty().unwrap();
+    ///
+    /// assert_eq!(vec![1, 2, 3], nested.concat_ne().into_inner());
+    /// ```
+    pub fn concat_ne(self) -> NonEmpty<Vec<T>> {
+        let len = self.inner.iter().map(|v| v.len()).sum();
+        let mut inner = Vec::with_capacity(len);
+        for v in self.inner {
+            inner.extend(v.inner);
+        }
+        NonEmpty { inner }
+    }
+}
+
+impl<T> NonEmpty<Vec<Vec<T>>> {
+    /// Flattens a non-empty vector of vectors, `None` if all inner vectors are empty.
+    pub fn concat(self) -> Option<NonEmpty<Vec<T>>> {
+        let len = self.inner.iter().map(|v| v.len()).sum();
+        if len == 0 {
+            return None
+        }
+        let mut inner = Vec::with_capacity(len);
+        for v in self.inner {
+            inner.extend(v);
+        }
+        Some(NonEmpty { inner })
+    }
+}
+
+impl<T> From<(T, Vec<T>)> for NonEmpty<Vec<T>> {
+    /// Creates a non-empty vector from a `(head, tail)` pair.
+    #[inline]
+    fn from((head, tail): (T, Vec<T>)) -> Self {
+        NonEmpty::from_head_tail(head, tail)
+    }
+}
+
+/// Like `slice::chunks`, but a chunk size of zero can't be passed.
+///
+/// # Examples
+/// ```
+/// use non_empty::{slice_chunks_ne, TryNonEmpty, usizeNE};
+///
+/// let size: usizeNE = 2.try_non_empty().unwrap();
+/// let chunks: Vec<&[i32]> = slice_chunks_ne(&[1, 2, 3], size).collect();
+/// assert_eq!(vec![&[1, 2][..], &[3][..]], chunks);
+/// ```
+#[inline]
+pub fn slice_chunks_ne<T>(slice: &[T], size: NonEmpty<usize>) -> slice::Chunks<'_, T> {
+    slice.chunks(size.inner)
+}
+
+/// Like `slice::windows`, but a window size of zero can't be passed.
+#[inline]
+pub fn slice_windows_ne<T>(slice: &[T], size: NonEmpty<usize>) -> slice::Windows<'_, T> {
+    slice.windows(size.inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use super::{slice_chunks_ne, slice_windows_ne};
+    use super::super::{NonEmpty, TryNonEmpty, VecNE, WouldBeEmpty};
+
+    #[test]
+    fn max_min_single_element() {
+        let v: VecNE<i32> = vec![7].try_non_empty().unwrap();
+        assert_eq!(7, *v.max());
+        assert_eq!(7, *v.min());
+        assert_eq!(7, *v.max_by_key(|x| -x));
+        assert_eq!(7, *v.min_by(|a, b| b.cmp(a)));
+    }
+
+    #[test]
+    fn max_min() {
+        let v: VecNE<i32> = vec![3, -8, 5, 1].try_non_empty().unwrap();
+        assert_eq!(5, *v.max());
+        assert_eq!(-8, *v.min());
+        assert_eq!(-8, *v.max_by_key(|x| x.abs()));
+        assert_eq!(1, *v.min_by_key(|x| x.abs()));
+        assert_eq!(-8, *v.max_by(|a, b| b.cmp(a)));
+        assert_eq!(5, *v.min_by(|a, b| b.cmp(a)));
+    }
+
+    #[test]
+    fn ties_match_std() {
+        let v: VecNE<(u8, char)> = vec![(1, 'a'), (2, 'b'), (2, 'c'), (1, 'd')]
+            .try_non_empty()
+            .unwrap();
+        // max returns the last, min the first of several equal elements
+        assert_eq!((2, 'c'), *v.max_by_key(|p| p.0));
+        assert_eq!((1, 'a'), *v.min_by_key(|p| p.0));
+        assert_eq!((2, 'c'), *v.max_by(|a, b| a.0.cmp(&b.0)));
+        assert_eq!((1, 'a'), *v.min_by(|a, b| a.0.cmp(&b.0)));
+        assert_eq!(v.iter().max_by_key(|p| p.0), Some(v.max_by_key(|p| p.0)));
+        assert_eq!(v.iter().min_by_key(|p| p.0), Some(v.min_by_key(|p| p.0)));
+    }
+
+    #[test]
+    fn reduce() {
+        let v: VecNE<i32> = vec![1, 2, 3, 4].try_non_empty().unwrap();
+        assert_eq!(10, v.reduce(|a, b| a + b));
+
+        let v: VecNE<String> = vec!["a".to_string(), "b".to_string(), "c".to_string()]
+            .try_non_empty()
+            .unwrap();
+        assert_eq!("abc", v.reduce(|a, b| a + &b));
+    }
+
+    #[test]
+    fn reduce_single_element() {
+        let mut calls = 0;
+        let v: VecNE<i32> = vec![5].try_non_empty().unwrap();
+        assert_eq!(5, v.reduce(|a, b| {
+            calls += 1;
+            a + b
+        }));
+        assert_eq!(0, calls);
+    }
+
+    #[test]
+    fn fold_first() {
+        let v: VecNE<&str> = vec!["usr", "local", "bin"].try_non_empty().unwrap();
+        let path = v.fold_first(|s| s.to_string(), |acc, s| acc + "/" + s);
+        assert_eq!("usr/local/bin", path);
+
+        let mut calls = 0;
+        let v: VecNE<i32> = vec![5].try_non_empty().unwrap();
+        let sum = v.fold_first(|x| x as i64, |acc, x| {
+            calls += 1;
+            acc + x as i64
+        });
+        assert_eq!(5, sum);
+        assert_eq!(0, calls);
+    }
+
+    #[test]
+    fn head_tail() {
+        let v: VecNE<&str> = NonEmpty::from_head_tail("a", vec!["b", "c", "d"]);
+        assert_eq!(vec!["a", "b", "c", "d"], *v);
+        assert_eq!(("a", vec!["b", "c", "d"]), v.into_head_tail());
+
+        let v: VecNE<u8> = (1, vec![]).into();
+        assert_eq!(vec![1], *v);
+        assert_eq!((1, vec![]), v.into_head_tail());
+    }
+
+    #[test]
+    fn head_tail_round_trip() {
+        let v: VecNE<i32> = vec![9, 8, 7].try_non_empty().unwrap();
+        let v2 = VecNE::from(v.clone().into_head_tail());
+        assert_eq!(v, v2);
+    }
+
+    #[test]
+    fn concat_ne() {
+        let nested: VecNE<VecNE<i32>> = vec![
+            NonEmpty::from_head_tail(1, vec![2, 3]),
+            NonEmpty::from_head_tail(4, vec![]),
+            NonEmpty::from_head_tail(5, vec![6]),
+        ].try_non_empty().unwrap();
+        assert_eq!(vec![1, 2, 3, 4, 5, 6], *nested.concat_ne());
+
+        let nested: VecNE<VecNE<i32>> = vec![NonEmpty::from_head_tail(1, vec![])]
+            .try_non_empty()
+            .unwrap();
+        assert_eq!(vec![1], *nested.concat_ne());
+    }
+
+    #[test]
+    fn concat() {
+        let nested: VecNE<Vec<i32>> = vec![vec![], vec![1, 2], vec![], vec![3]]
+            .try_non_empty()
+            .unwrap();
+        assert_eq!(vec![1, 2, 3], *nested.concat().unwrap());
+
+        let nested: VecNE<Vec<i32>> = vec![vec![], vec![]].try_non_empty().unwrap();
+        assert!(nested.concat().is_none());
+    }
+
+    fn size(n: usize) -> NonZeroUsize {
+        NonZeroUsize::new(n).unwrap()
+    }
+
+    #[test]
+    fn chunks_ne() {
+        let v: VecNE<i32> = vec![1, 2, 3, 4, 5, 6].try_non_empty().unwrap();
+        let chunks = v.chunks_ne(size(3)).map(|c| c.into_inner()).collect::<Vec<_>>();
+        assert_eq!(vec![&[1, 2, 3][..], &[4, 5, 6][..]], chunks);
+
+        let chunks = v.chunks_ne(size(4)).map(|c| c.into_inner()).collect::<Vec<_>>();
+        assert_eq!(vec![&[1, 2, 3, 4][..], &[5, 6][..]], chunks);
+
+        let chunks = v.chunks_ne(size(10)).map(|c| c.into_inner()).collect::<Vec<_>>();
+        assert_eq!(vec![&[1, 2, 3, 4, 5, 6][..]], chunks);
+
+        let data = [1, 2, 3];
+        let s = (&data[..]).try_non_empty().unwrap();
+        assert_eq!(3, s.chunks_ne(size(1)).count());
+    }
+
+    #[test]
+    fn windows_ne() {
+        let v: VecNE<i32> = vec![1, 2, 3].try_non_empty().unwrap();
+        let windows = v.windows_ne(size(2)).map(|w| w.into_inner()).collect::<Vec<_>>();
+        assert_eq!(vec![&[1, 2][..], &[2, 3][..]], windows);
+
+        let windows = v.windows_ne(size(3)).map(|w| w.into_inner()).collect::<Vec<_>>();
+        assert_eq!(vec![&[1, 2, 3][..]], windows);
+
+        assert_eq!(0, v.windows_ne(size(4)).count());
+
+        let data = [1, 2, 3];
+        let s = (&data[..]).try_non_empty().unwrap();
+        assert_eq!(3, s.windows_ne(size(1)).count());
+    }
+
+    #[test]
+    fn as_slice_ne() {
+        let v: VecNE<i32> = vec![1, 2].try_non_empty().unwrap();
+        let s: NonEmpty<&[i32]> = v.as_slice_ne();
+        assert_eq!(&[1, 2][..], s.into_inner());
+        assert_eq!(v.as_ptr(), v.as_slice_ne().as_ptr());
+    }
+
+    #[test]
+    fn repeat_ne() {
+        let v: VecNE<String> = vec!["a".to_string(), "b".to_string()].try_non_empty().unwrap();
+        assert_eq!(*v, *v.repeat_ne(size(1)));
+        assert_eq!(vec!["a", "b", "a", "b", "a", "b"], *v.repeat_ne(size(3)));
+
+        let data = [1, 2];
+        let s = (&data[..]).try_non_empty().unwrap();
+        assert_eq!(vec![1, 2, 1, 2], *s.repeat_ne(size(2)));
+
+        let err = std::panic::catch_unwind(|| v.repeat_ne(size(usize::MAX))).unwrap_err();
+        assert_eq!(Some("capacity overflow"), err.downcast_ref::<String>().map(String::as_str));
+    }
+
+    #[test]
+    fn slice() {
+        let data = [4, 9, 2];
+        let s = (&data[..]).try_non_empty().unwrap();
+        assert_eq!(9, *s.max());
+        assert_eq!(2, *s.min());
+        assert!((&[] as &[i32]).try_non_empty().is_none());
+    }
+
+    #[test]
+    fn slice_chunks_windows_ne() {
+        let empty: &[i32] = &[];
+        let two = 2.try_non_empty().unwrap();
+        assert_eq!(0, slice_chunks_ne(empty, two.clone()).count());
+        assert_eq!(0, slice_windows_ne(empty, two.clone()).count());
+        assert_eq!(vec![&[1, 2][..], &[3][..]], slice_chunks_ne(&[1, 2, 3], two.clone()).collect::<Vec<_>>());
+        assert_eq!(vec![&[1, 2][..], &[2, 3][..]], slice_windows_ne(&[1, 2, 3], two).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn capacity() {
+        let mut v: VecNE<i32> = vec![1, 2].try_non_empty().unwrap();
+        v.reserve(100);
+        assert!(v.capacity() >= 102);
+        v.shrink_to(10);
+        assert!(v.capacity() >= 10 && v.capacity() < 102);
+        v.shrink_to_fit();
+        v.reserve_exact(3);
+        assert!(v.capacity() >= 5);
+        v.shrink_to_fit();
+        assert_eq!(vec![1, 2], *v);
+        assert!(v.capacity() >= 2);
+    }
+
+    #[test]
+    fn chunk_by_ne() {
+        let v: VecNE<i32> = vec![1, 1, 1].try_non_empty().unwrap();
+        let runs: Vec<&[i32]> = v.chunk_by_ne(|n| *n).into_iter().map(NonEmpty::into_inner).collect();
+        assert_eq!(vec![&[1, 1, 1][..]], runs);
+
+        let v: VecNE<i32> = vec![1, 2, 3, 4].try_non_empty().unwrap();
+        assert_eq!(4, v.chunk_by_ne(|n| n % 2).into_iter().count());
+        assert_eq!(&[4][..], *v.chunk_by_ne(|n| n % 2).last());
+
+        let words = ["apple", "avocado", "banana", "blueberry", "cherry"];
+        let s: NonEmpty<&[&str]> = (&words[..]).try_non_empty().unwrap();
+        let first_letters: Vec<String> = s.chunk_by_ne(|w| w[..1].to_string())
+            .map(|run| run.join("+"))
+            .into_iter()
+            .collect();
+        assert_eq!(vec!["apple+avocado", "banana+blueberry", "cherry"], first_letters);
+    }
+
+    #[test]
+    fn into_chunk_by_ne() {
+        let v: VecNE<String> = vec!["a", "ab", "b", "a"].into_iter().map(String::from)
+            .collect::<Vec<_>>().try_non_empty().unwrap();
+        let runs = v.clone().into_chunk_by_ne(|s| s[..1].to_string());
+        assert_eq!(3, runs.len());
+        assert_eq!(vec!["a", "ab"], *runs[0]);
+        assert_eq!(v, runs.concat_ne());
+
+        let one: VecNE<i32> = vec![7].try_non_empty().unwrap();
+        assert_eq!(vec![7], *one.into_chunk_by_ne(|_| ()).inner[0]);
+    }
+
+    #[test]
+    fn split_off() {
+        let mut v: VecNE<i32> = vec![1, 2, 3, 4].try_non_empty().unwrap();
+        assert_eq!(Err(WouldBeEmpty), v.split_off(0));
+        assert_eq!(4, v.len());
+        assert_eq!(Ok(vec![]), v.split_off(4));
+        assert_eq!(Ok(vec![3, 4]), v.split_off(2));
+        assert_eq!(vec![1, 2], *v);
+        assert_eq!(Ok(vec![2]), v.split_off(1));
+        assert_eq!(Err(WouldBeEmpty), v.split_off(0));
+        assert_eq!(vec![1], *v);
+    }
+
+    #[test]
+    fn split_at_ne() {
+        let v: VecNE<i32> = vec![1, 2, 3].try_non_empty().unwrap();
+        let (head, tail) = v.clone().split_at_ne(NonZeroUsize::new(1).unwrap());
+        assert_eq!((vec![1], vec![2, 3]), (head.into_inner(), tail));
+        let (head, tail) = v.split_at_ne(NonZeroUsize::new(3).unwrap());
+        assert_eq!((vec![1, 2, 3], vec![]), (head.into_inner(), tail));
+    }
+
+    #[test]
+    fn split_out_of_bounds_panics_like_vec() {
+        use std::panic::catch_unwind;
+
+        assert!(catch_unwind(|| vec![1, 2].split_off(3)).is_err());
+        let v: VecNE<i32> = vec![1, 2].try_non_empty().unwrap();
+        assert!(catch_unwind(|| v.clone().split_off(3)).is_err());
+        assert!(catch_unwind(|| v.split_at_ne(NonZeroUsize::new(3).unwrap())).is_err());
+    }
+
+    #[test]
+    fn element_mutation() {
+        let mut v: VecNE<u64> = vec![1, 2, 3, 4].try_non_empty().unwrap();
+        *v.first_mut() += 10;
+        *v.last_mut() += 10;
+        *v.get_mut_elem(1).unwrap() = 0;
+        assert_eq!(vec![11, 0, 3, 14], *v);
+
+        for x in v.iter_mut() {
+            *x *= 2;
+        }
+        assert_eq!(vec![22, 0, 6, 28], *v);
+
+        v.swap(0, 3);
+        assert_eq!(vec![28, 0, 6, 22], *v);
+        assert_eq!(None, v.get_mut_elem(4));
+
+        let mut one: VecNE<u64> = vec![5].try_non_empty().unwrap();
+        *one.first_mut() += 1;
+        *one.last_mut() += 1;
+        assert_eq!(vec![7], *one);
+    }
+
+    #[test]
+    fn mut_slice() {
+        let mut v: VecNE<i32> = vec![3, 1, 2].try_non_empty().unwrap();
+        {
+            let mut s = v.as_mut_slice_ne();
+            s.swap(0, 2);
+            *s.last_mut() *= 10;
+            s.iter_mut().for_each(|x| *x += 1);
+            assert_eq!(31, *s.max());
+        }
+        assert_eq!(vec![3, 2, 31], *v);
+
+        let mut b = v.into_boxed_slice_ne();
+        *b.first_mut() = 0;
+        assert_eq!(&[0, 2, 31][..], &**b);
+    }
+
+    #[test]
+    fn element_mutation_out_of_bounds_like_std() {
+        use std::panic::catch_unwind;
+
+        let mut std = [1, 2];
+        assert_eq!(None, std.get_mut(2));
+        assert!(catch_unwind(|| [1, 2].swap(0, 2)).is_err());
+
+        let v: VecNE<i32> = vec![1, 2].try_non_empty().unwrap();
+        assert_eq!(None, v.clone().get_mut_elem(2));
+        assert!(catch_unwind(|| v.clone().swap(0, 2)).is_err());
+        assert!(catch_unwind(|| v.clone().swap(2, 0)).is_err());
+    }
+}
@@ -0,0 +1,152 @@
+use std::collections::VecDeque;
+
+use super::{NonEmpty, WouldBeEmpty};
+
+impl<T> NonEmpty<VecDeque<T>> {
+    capacity_methods!(exact);
+
+    /// Returns the first element.
+    pub fn front(&self) -> &T {
+        self.inner.front().expect("NonEmpty is never empty")
+    }
+
+    /// Returns the last element.
+    pub fn back(&self) -> &T {
+        self.inner.back().expect("NonEmpty is never empty")
+    }
+
+    /// Prepends an element to the deque.
+    #[inline]
+    pub fn push_front(&mut self, value: T) {
+        self.inner.push_front(value)
+    }
+
+    /// Appends an element to the back of the deque.
+    #[inline]
+    pub fn push_back(&mut self, value: T) {
+        self.inner.push_back(value)
+    }
+
+    /// Removes and returns the first element.
+    ///
+    /// Returns `Err(WouldBeEmpty)` and leaves the deque untouched
+    /// if it's the only element.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::VecDeque;
+    /// use non_empty::{TryNonEmpty, WouldBeEmpty};
+    ///
+    /// // a work queue which always keeps a fallback job
+    /// let mut jobs = VecDeque::from(vec!["fallback"]).try_non_empty().unwrap();
+    /// jobs.push_front("urgent");
+    ///
+    /// assert_eq!(Ok("urgent"), jobs.pop_front());
+    /// assert_eq!(Err(WouldBeEmpty), jobs.pop_front());
+    /// assert_eq!("fallback", *jobs.front());
+    /// ```
+    pub fn pop_front(&mut self) -> Result<T, WouldBeEmpty> {
+        if self.inner.len() == 1 {
+            return Err(WouldBeEmpty)
+        }
+        Ok(self.inner.pop_front().expect("NonEmpty is never empty"))
+    }
+
+    /// Removes and returns the last element.
+    ///
+    /// Returns `Err(WouldBeEmpty)` and leaves the deque untouched
+    /// if it's the only element.
+    pub fn pop_back(&mut self) -> Result<T, WouldBeEmpty> {
+        if self.inner.len() == 1 {
+            return Err(WouldBeEmpty)
+        }
+        Ok(self.inner.pop_back().expect("NonEmpty is never empty"))
+    }
+
+    /// Rotates the deque `n` places to the left, like `VecDeque::rotate_left`.
+    ///
+    /// # Panics
+    /// Panics if `n` is greater than the length.
+    #[inline]
+    pub fn rotate_left(&mut self, n: usize) {
+        self.inner.rotate_left(n)
+    }
+
+    /// Rotates the deque `n` places to the right, like `VecDeque::rotate_right`.
+    ///
+    /// # Panics
+    /// Panics if `n` is greater than the length.
+    #[inline]
+    pub fn rotate_right(&mut self, n: usize) {
+        self.inner.rotate_right(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use super::super::{TryNonEmpty, VecDequeNE, WouldBeEmpty};
+
+    fn deque(v: Vec<i32>) -> VecDequeNE<i32> {
+        VecDeque::from(v).try_non_empty().unwrap()
+    }
+
+    #[test]
+    fn single_element() {
+        let mut d = deque(vec![1]);
+        assert_eq!(1, *d.front());
+        assert_eq!(1, *d.back());
+        assert_eq!(Err(WouldBeEmpty), d.pop_front());
+        assert_eq!(Err(WouldBeEmpty), d.pop_back());
+        d.rotate_left(1);
+        d.rotate_right(0);
+        assert_eq!(VecDeque::from(vec![1]), *d);
+
+        d.push_front(0);
+        assert_eq!(0, *d.front());
+        let mut d = deque(vec![1]);
+        d.push_back(2);
+        assert_eq!(2, *d.back());
+    }
+
+    #[test]
+    fn fifo() {
+        let mut d = deque(vec![1]);
+        d.push_back(2);
+        d.push_back(3);
+        assert_eq!(Ok(1), d.pop_front());
+        assert_eq!(Ok(2), d.pop_front());
+        assert_eq!(Err(WouldBeEmpty), d.pop_front());
+        assert_eq!(3, *d.front());
+    }
+
+    #[test]
+    fn rotate() {
+        let mut d = deque(vec![1, 2, 3]);
+        d.rotate_left(1);
+        assert_eq!(VecDeque::from(vec![2, 3, 1]), *d);
+        d.rotate_right(2);
+        assert_eq!(VecDeque::from(vec![3, 1, 2]), *d);
+        assert_eq!(Ok(2), d.pop_back());
+    }
+
+    #[test]
+    #[should_panic]
+    fn rotate_out_of_bounds() {
+        deque(vec![1]).rotate_left(2);
+    }
+
+    #[test]
+    fn capacity() {
+        let mut d: VecDequeNE<i32> = vec![1, 2].into_iter().collect::<VecDeque<_>>().try_non_empty().unwrap();
+        d.reserve_exact(50);
+        assert!(d.capacity() >= 52);
+        d.shrink_to_fit();
+        assert!(d.capacity() < 52);
+        d.reserve(10);
+        d.shrink_to(3);
+        assert!(d.capacity() >= 3);
+        assert_eq!(vec![1, 2], d.iter().cloned().collect::<Vec<_>>());
+    }
+}
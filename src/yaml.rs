@@ -0,0 +1,132 @@
+extern crate serde_yaml;
+
+use self::serde_yaml::value::{Mapping, TaggedValue, Value};
+
+use super::{IsEmpty, NonEmpty};
+
+/// A YAML value which is neither `null`, `""`, `[]` nor `{}`.
+pub type YamlValueNE = NonEmpty<Value>;
+
+/// `Null`, an empty string, an empty sequence and an empty mapping are empty,
+/// a tagged value is empty if its value is.
+///
+/// Numbers and booleans are never empty, `0` and `false` are meaningful values.
+impl IsEmpty for Value {
+    fn is_empty(&self) -> bool {
+        match *self {
+            Value::Null => true,
+            Value::String(ref s) => s.is_empty(),
+            Value::Sequence(ref s) => s.is_empty(),
+            Value::Mapping(ref m) => m.is_empty(),
+            Value::Tagged(ref t) => t.value.is_empty(),
+            Value::Bool(_) | Value::Number(_) => false,
+        }
+    }
+}
+
+impl IsEmpty for Mapping {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        Mapping::is_empty(self)
+    }
+}
+
+/// Recursively removes empty sequence elements and mapping values,
+/// returns `None` if the pruned value is empty itself.
+///
+/// Works like [prune_empty](fn.prune_empty.html) for JSON values,
+/// mapping keys are kept as they are, tags are kept on non-empty values.
+///
+/// # Examples
+/// ```
+/// extern crate serde_yaml;
+/// extern crate non_empty;
+///
+/// use non_empty::prune_empty_yaml;
+///
+/// # fn main() {
+/// let v: serde_yaml::Value = serde_yaml::from_str("a: 0\nb: ~\nc: [{}]").unwrap();
+/// let expected: serde_yaml::Value = serde_yaml::from_str("a: 0").unwrap();
+/// assert_eq!(expected, *prune_empty_yaml(v).unwrap());
+/// # }
+/// ```
+pub fn prune_empty_yaml(value: Value) -> Option<YamlValueNE> {
+    let inner = match value {
+        Value::Sequence(s) => Value::Sequence(s.into_iter()
+            .filter_map(prune_empty_yaml)
+            .map(NonEmpty::into_inner)
+            .collect()),
+        Value::Mapping(m) => Value::Mapping(m.into_iter()
+            .filter_map(|(k, v)| prune_empty_yaml(v).map(|v| (k, v.into_inner())))
+            .collect()),
+        Value::Tagged(t) => {
+            let TaggedValue { tag, value } = *t;
+            let value = prune_empty_yaml(value)?.into_inner();
+            Value::Tagged(Box::new(TaggedValue { tag, value }))
+        }
+        v => v,
+    };
+    if inner.is_empty() {
+        return None
+    }
+    Some(NonEmpty { inner })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::serde_yaml::value::{Mapping, Tag, TaggedValue, Value};
+    use super::prune_empty_yaml;
+    use super::super::{IsEmpty, TryNonEmpty};
+
+    fn parse(s: &str) -> Value {
+        super::serde_yaml::from_str(s).unwrap()
+    }
+
+    fn tagged(value: Value) -> Value {
+        Value::Tagged(Box::new(TaggedValue { tag: Tag::new("secret"), value }))
+    }
+
+    #[test]
+    fn variants() {
+        assert!(Value::Null.is_empty());
+        assert!(Value::from("").is_empty());
+        assert!(Value::Sequence(vec![]).is_empty());
+        assert!(Value::Mapping(Mapping::new()).is_empty());
+        assert!(Mapping::new().is_empty());
+        assert!(tagged(Value::Null).is_empty());
+
+        assert!(!Value::from(0).is_empty());
+        assert!(!Value::from(0.0).is_empty());
+        assert!(!Value::from(false).is_empty());
+        assert!(!Value::from("a").is_empty());
+        assert!(!Value::Sequence(vec![Value::Null]).is_empty());
+        assert!(!tagged(Value::from("a")).is_empty());
+
+        assert!(Value::Null.try_non_empty().is_none());
+        assert!(Value::from(1).try_non_empty().is_some());
+    }
+
+    #[test]
+    fn prune_nested() {
+        let v = parse("
+            name: x
+            empty: ''
+            none: ~
+            zero: 0
+            tags: ['', a, [], [~]]
+            nested: {a: {b: {}}, c: [{d: ~}]}
+            kept: {a: {b: true}}
+            token: !secret ''
+            key: !secret abc
+        ");
+        let expected = parse("
+            name: x
+            zero: 0
+            tags: [a]
+            kept: {a: {b: true}}
+            key: !secret abc
+        ");
+        assert_eq!(expected, *prune_empty_yaml(v).unwrap());
+        assert!(prune_empty_yaml(parse("a: [[~]]")).is_none());
+    }
+}
@@ -0,0 +1,110 @@
+extern crate zeroize;
+
+use self::zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+use super::{IsEmpty, NonEmpty};
+
+/// `Zeroizing` is transparent, it's empty if the wrapped value is empty.
+///
+/// A `NonEmpty<Zeroizing<String>>` checks that a secret was configured
+/// and clears it on drop.
+impl<T: IsEmpty + Zeroize> IsEmpty for Zeroizing<T> {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        (**self).is_empty()
+    }
+}
+
+/// Dropping a `NonEmpty` drops the inner value, which zeroizes itself.
+impl<T: ZeroizeOnDrop> ZeroizeOnDrop for NonEmpty<T> {}
+
+impl<T: Zeroize> NonEmpty<T> {
+    /// Zeroizes the inner value and drops it.
+    ///
+    /// Zeroizing empties most values, e.g. a `String` is cleared,
+    /// so `NonEmpty` doesn't implement `Zeroize`, which would leave
+    /// an empty value behind a `&mut` reference. Consuming `self` makes
+    /// sure the emptied value can't be observed.
+    ///
+    /// # Examples
+    /// ```
+    /// use non_empty::{StringNE, TryNonEmpty};
+    ///
+    /// let token: StringNE = "secret".to_string().try_non_empty().unwrap();
+    /// token.zeroize_and_drop();
+    /// ```
+    #[inline]
+    pub fn zeroize_and_drop(self) {
+        let mut inner = self.inner;
+        inner.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::zeroize::{Zeroize, Zeroizing};
+    use super::super::{IsEmpty, NonEmpty, TryNonEmpty};
+
+    /// Records its content when dropped.
+    #[derive(Clone)]
+    struct Recorder {
+        data: Vec<u8>,
+        on_drop: Rc<RefCell<Option<Vec<u8>>>>,
+    }
+
+    impl IsEmpty for Recorder {
+        fn is_empty(&self) -> bool {
+            self.data.is_empty()
+        }
+    }
+
+    impl Zeroize for Recorder {
+        fn zeroize(&mut self) {
+            self.data.zeroize();
+        }
+    }
+
+    impl Drop for Recorder {
+        fn drop(&mut self) {
+            *self.on_drop.borrow_mut() = Some(self.data.clone());
+        }
+    }
+
+    fn recorder(data: &[u8]) -> (Recorder, Rc<RefCell<Option<Vec<u8>>>>) {
+        let on_drop = Rc::new(RefCell::new(None));
+        (Recorder { data: data.to_vec(), on_drop: on_drop.clone() }, on_drop)
+    }
+
+    #[test]
+    fn zeroize_and_drop() {
+        let (r, on_drop) = recorder(b"token");
+        let v: NonEmpty<Recorder> = r.try_non_empty().unwrap();
+        assert_eq!(b"token", &v.data[..]);
+        v.zeroize_and_drop();
+        assert_eq!(Some(vec![]), *on_drop.borrow());
+    }
+
+    #[test]
+    fn zeroizing_on_drop() {
+        let (r, on_drop) = recorder(b"token");
+        let v: NonEmpty<Zeroizing<Recorder>> = Zeroizing::new(r).try_non_empty().unwrap();
+        assert_eq!(b"token", &v.data[..]);
+        drop(v);
+        assert_eq!(Some(vec![]), *on_drop.borrow());
+
+        // without zeroizing, the content is still there on drop
+        let (r, on_drop) = recorder(b"token");
+        drop(r.try_non_empty().unwrap());
+        assert_eq!(Some(b"token".to_vec()), *on_drop.borrow());
+    }
+
+    #[test]
+    fn empty_rejection() {
+        assert!(Zeroizing::new(String::new()).try_non_empty().is_none());
+        let s: NonEmpty<Zeroizing<String>> = Zeroizing::new("a".to_string()).try_non_empty().unwrap();
+        assert_eq!("a", s.as_str());
+    }
+}